@@ -13,6 +13,7 @@ pub fn handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Send + Syn
         crate::commands::clusters::disconnect_cluster,
         crate::commands::clusters::get_connection_status,
         crate::commands::clusters::check_connection_health,
+        crate::commands::clusters::list_connected_contexts,
         crate::commands::clusters::has_kubeconfig,
         crate::commands::cluster_settings::get_cluster_settings,
         crate::commands::cluster_settings::set_cluster_accessible_namespaces,
@@ -72,8 +73,23 @@ pub fn handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Send + Syn
         crate::commands::resources::delete_pod,
         crate::commands::resources::get_resource_yaml,
         crate::commands::resources::apply_resource_yaml,
+        crate::commands::apply::dry_run_resource_yaml,
+        crate::commands::apply::apply_manifests,
         crate::commands::resources::delete_resource,
         crate::commands::resources::scale_deployment,
+        crate::commands::bulk::bulk_delete_resources,
+        crate::commands::bulk::bulk_scale_resources,
+        crate::commands::bulk::bulk_set_container_image,
+        crate::commands::rollout::rollout_restart,
+        crate::commands::rollout::rollout_pause,
+        crate::commands::rollout::rollout_resume,
+        crate::commands::rollout::rollout_history,
+        crate::commands::rollout::rollout_undo,
+        crate::commands::rollout::watch_rollout_status,
+        crate::commands::drain::cordon_node,
+        crate::commands::drain::uncordon_node,
+        crate::commands::drain::evict_pod,
+        crate::commands::drain::drain_node,
         crate::commands::resources::set_container_image,
         crate::commands::resources::trigger_cronjob,
         crate::commands::resources::get_cronjob_job_yaml,
@@ -86,12 +102,20 @@ pub fn handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Send + Syn
         crate::commands::watch::watch_daemonsets,
         crate::commands::watch::watch_replicasets,
         crate::commands::watch::watch_namespaces,
+        crate::commands::watch::watch_resources,
         crate::commands::watch::stop_watch,
+        crate::commands::watch::get_cache_status,
         crate::commands::logs::get_pod_logs,
         crate::commands::logs::stream_pod_logs,
+        crate::commands::logs::stream_aggregated_logs,
         crate::commands::logs::stop_log_stream,
         crate::commands::logs::get_pod_containers,
         crate::commands::logs::download_pod_logs,
+        crate::commands::logs::search_log_archive,
+        crate::commands::logs::list_log_archive_sources,
+        crate::commands::logs::get_log_archive_stats,
+        crate::commands::logs::set_log_archive_max_size,
+        crate::commands::logs::clear_log_archive,
         crate::commands::shell::shell_start,
         crate::commands::shell::shell_send_input,
         crate::commands::shell::shell_resize,
@@ -99,23 +123,37 @@ pub fn handler() -> impl Fn(tauri::ipc::Invoke<tauri::Wry>) -> bool + Send + Syn
         crate::commands::shell::shell_list_sessions,
         crate::commands::shell::node_shell_start,
         crate::commands::shell::node_shell_cleanup,
+        crate::commands::shell::debug_container_start,
+        crate::commands::file_copy::copy_to_container,
+        crate::commands::file_copy::copy_from_container,
         crate::commands::portforward::portforward_start,
         crate::commands::portforward::portforward_stop,
         crate::commands::portforward::portforward_list,
         crate::commands::portforward::portforward_get,
         crate::commands::portforward::portforward_check_port,
+        crate::commands::portforward_profiles::portforward_profiles_list,
+        crate::commands::portforward_profiles::portforward_profile_save,
+        crate::commands::portforward_profiles::portforward_profile_delete,
+        crate::commands::portforward_profiles::portforward_profile_start,
+        crate::commands::portforward_profiles::portforward_profile_start_group,
         crate::commands::metrics::get_node_metrics,
         crate::commands::metrics::get_pod_metrics,
         crate::commands::metrics::get_pod_metrics_direct,
         crate::commands::metrics::get_cluster_metrics_summary,
         crate::commands::metrics::check_metrics_server,
         crate::commands::graph::generate_resource_graph,
+        crate::commands::graph::watch_resource_graph,
+        crate::commands::graph_export::export_resource_graph,
         crate::commands::helm::list_helm_releases,
         crate::commands::helm::get_helm_release,
         crate::commands::helm::get_helm_release_history,
         crate::commands::helm::get_helm_release_values,
         crate::commands::helm::get_helm_release_manifest,
         crate::commands::helm::uninstall_helm_release,
+        crate::commands::helm::rollback_helm_release,
+        crate::commands::helm::diff_helm_revisions,
+        crate::commands::helm::install_helm_chart,
+        crate::commands::helm::upgrade_helm_release,
         crate::oidc::commands::oidc_start_auth,
         crate::oidc::commands::oidc_handle_callback,
         crate::oidc::commands::oidc_get_token_status,
@@ -5,6 +5,7 @@ use crate::app::state;
 
 pub fn configure(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     state::initialize_ai_session_store(app);
+    state::initialize_log_archive(app);
 
     // fs scope is statically limited to ~/.kube; re-grant user-added
     // kubeconfig sources so the frontend watcher keeps working.
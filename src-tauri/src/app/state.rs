@@ -2,7 +2,7 @@ use crate::ai::agent_manager::AgentManager;
 use crate::ai::commands::AIConfigState;
 use crate::ai::session_store::create_session_store;
 use crate::app::setup::deep_links::StartupDeepLinks;
-use crate::commands::logs::LogStreamManager;
+use crate::commands::logs::{create_log_archive, LogStreamManager};
 use crate::commands::portforward::{PortForwardManager, PortForwardWatchManager};
 use crate::commands::shell::ShellSessionManager;
 use crate::commands::watch::WatchManager;
@@ -55,3 +55,19 @@ pub fn initialize_ai_session_store(app: &mut tauri::App) {
     };
     app.manage(session_store);
 }
+
+pub fn initialize_log_archive(app: &mut tauri::App) {
+    // The archive is opt-in per stream; without it, recording streams are
+    // refused but everything else keeps working.
+    let db_path = match app.path().app_data_dir() {
+        Ok(dir) => dir.join("log_archive.db"),
+        Err(e) => {
+            tracing::error!("Failed to get app data directory: {e}; log archive disabled");
+            return;
+        }
+    };
+    match create_log_archive(db_path.clone()) {
+        Ok(archive) => app.state::<Arc<LogStreamManager>>().set_archive(archive),
+        Err(e) => tracing::error!("Failed to open log archive at {:?}: {e}", db_path),
+    }
+}
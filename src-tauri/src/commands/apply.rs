@@ -0,0 +1,1051 @@
+use crate::ai::cli_detector::get_extended_path;
+use crate::commands::resources::{discover_api_resource, strip_managed_fields};
+use crate::error::{field_conflicts, FieldConflict, KubeliError};
+use crate::k8s::AppState;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::api::{Api, DeleteParams, DynamicObject, ListParams, Patch, PatchParams};
+use kube::runtime::wait::{await_condition, conditions};
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tauri::{command, State};
+use tokio::process::Command;
+
+/// Field manager Kubeli applies as
+pub(crate) const FIELD_MANAGER: &str = "kubeli";
+
+/// Server-maintained metadata that differs on every write (or is invented
+/// by a dry-run create) and would only clutter a diff
+const IGNORED_PATHS: &[&str] = &[
+    "metadata.resourceVersion",
+    "metadata.generation",
+    "metadata.uid",
+    "metadata.creationTimestamp",
+];
+
+/// A manifest and the API it is applied through
+pub(crate) struct ManifestTarget {
+    pub api: Api<DynamicObject>,
+    /// API group, empty for the core group
+    pub group: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    /// Scope from discovery; None when discovery failed
+    pub namespaced: Option<bool>,
+    pub object: DynamicObject,
+}
+
+/// Resolve a parsed manifest to its API with discovery. Namespaced kinds
+/// without `metadata.namespace` go to "default", as with kubectl.
+pub(crate) async fn resolve_manifest(
+    client: &kube::Client,
+    value: &Value,
+) -> Result<ManifestTarget, KubeliError> {
+    resolve_manifest_in(client, value, "default").await
+}
+
+/// `resolve_manifest` with namespaced kinds that lack a namespace going to
+/// `default_namespace`. Only kinds discovery reports as namespaced get one.
+pub(crate) async fn resolve_manifest_in(
+    client: &kube::Client,
+    value: &Value,
+    default_namespace: &str,
+) -> Result<ManifestTarget, KubeliError> {
+    let api_version = value["apiVersion"].as_str().ok_or("Missing apiVersion")?;
+    let kind = value["kind"].as_str().ok_or("Missing kind")?;
+    let name = value["metadata"]["name"]
+        .as_str()
+        .ok_or("Missing metadata.name")?;
+
+    let (group, version) = api_version.split_once('/').unwrap_or(("", api_version));
+    let (ar, namespaced) = discover_api_resource(client, group, version, kind).await;
+
+    let namespace = match (value["metadata"]["namespace"].as_str(), namespaced) {
+        (_, Some(false)) => None,
+        (Some(ns), _) => Some(ns.to_string()),
+        (None, Some(true)) => Some(default_namespace.to_string()),
+        (None, None) => None,
+    };
+    let api = match &namespace {
+        Some(ns) => Api::namespaced_with(client.clone(), ns, &ar),
+        None => Api::all_with(client.clone(), &ar),
+    };
+
+    Ok(ManifestTarget {
+        api,
+        group: group.to_string(),
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace,
+        namespaced,
+        object: serde_json::from_value(value.clone())?,
+    })
+}
+
+/// Object as JSON without managedFields
+fn clean_value(obj: &DynamicObject) -> Result<Value, KubeliError> {
+    let mut value = serde_json::to_value(obj)?;
+    strip_managed_fields(&mut value);
+    Ok(value)
+}
+
+/// What an apply does when other field managers own fields it sets
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictResolution {
+    /// Fail with a Conflict error listing the fields and their managers
+    #[default]
+    Abort,
+    /// Take ownership of the conflicting fields
+    Force,
+    /// Remove the conflicting fields from the manifest and leave them to
+    /// their current managers
+    Drop,
+}
+
+/// Server-side apply as Kubeli's field manager, resolving conflicts with
+/// other managers (Helm, Argo CD, Flux...) as the user chose
+pub(crate) async fn server_side_apply(
+    api: &Api<DynamicObject>,
+    name: &str,
+    object: &DynamicObject,
+    resolution: ConflictResolution,
+) -> Result<DynamicObject, KubeliError> {
+    let params = PatchParams::apply(FIELD_MANAGER);
+    if resolution == ConflictResolution::Force {
+        return Ok(api
+            .patch(name, &params.force(), &Patch::Apply(object))
+            .await?);
+    }
+
+    let err = match api.patch(name, &params, &Patch::Apply(object)).await {
+        Ok(applied) => return Ok(applied),
+        Err(err) => err,
+    };
+    let conflicts = match (resolution, field_conflicts(&err)) {
+        (ConflictResolution::Drop, Some(conflicts)) => conflicts,
+        _ => return Err(err.into()),
+    };
+
+    let mut value = serde_json::to_value(object)?;
+    for conflict in &conflicts {
+        if !remove_field(&mut value, &conflict.field) {
+            let mut kubeli = KubeliError::from(err);
+            kubeli.detail = Some(format!(
+                "Could not drop {} from the manifest",
+                conflict.field
+            ));
+            return Err(kubeli);
+        }
+    }
+    tracing::info!(
+        "Dropped {} conflicting fields from {} before applying",
+        conflicts.len(),
+        name
+    );
+    let dropped: DynamicObject = serde_json::from_value(value)?;
+    Ok(api.patch(name, &params, &Patch::Apply(&dropped)).await?)
+}
+
+/// Split `[...]` off a field path, returning the selector inside and the
+/// rest of the path. Brackets inside quoted values are skipped.
+fn split_selector(path: &str) -> Option<(&str, &str)> {
+    let inner = path.strip_prefix('[')?;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in inner.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = quoted,
+            '"' => quoted = !quoted,
+            ']' if !quoted => return Some((&inner[..i], &inner[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split `k=v,k2=v2` on commas outside quoted values
+fn selector_pairs(selector: &str) -> Vec<&str> {
+    let mut pairs = Vec::new();
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in selector.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = quoted,
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                pairs.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    pairs.push(&selector[start..]);
+    pairs
+}
+
+/// Whether a list item is the one a field path selector names: `[name="web"]`
+/// (keys of an associative list), `[="value"]` (set member) or `[0]` (index).
+/// A key the item leaves out matches, since the server may have defaulted it
+/// (e.g. `protocol="TCP"` of a container port).
+fn selector_matches(index: usize, item: &Value, selector: &str) -> bool {
+    if let Ok(i) = selector.parse::<usize>() {
+        return i == index;
+    }
+    if let Some(value) = selector.strip_prefix('=') {
+        return serde_json::from_str::<Value>(value).is_ok_and(|value| &value == item);
+    }
+    selector_pairs(selector).into_iter().all(|pair| {
+        let Some((key, value)) = pair.split_once('=') else {
+            return false;
+        };
+        match (item.get(key), serde_json::from_str::<Value>(value)) {
+            (None, _) => true,
+            (Some(actual), Ok(expected)) => actual == &expected,
+            (Some(_), Err(_)) => false,
+        }
+    })
+}
+
+/// Remove the field a server-side apply path (`.spec.template.spec
+/// .containers[name="web"].image`) points at. False when the manifest has
+/// no such field.
+fn remove_field(value: &mut Value, path: &str) -> bool {
+    if let Some(rest) = path.strip_prefix('.') {
+        let Value::Object(map) = value else {
+            return false;
+        };
+        // Keys may contain dots (`app.kubernetes.io/name`), so take the
+        // longest key the path continues with
+        let key = map
+            .keys()
+            .filter(|key| {
+                rest.strip_prefix(key.as_str())
+                    .is_some_and(|after| after.is_empty() || after.starts_with(['.', '[']))
+            })
+            .max_by_key(|key| key.len())
+            .cloned();
+        let Some(key) = key else {
+            return false;
+        };
+        let rest = &rest[key.len()..];
+        if rest.is_empty() {
+            return map.remove(&key).is_some();
+        }
+        return map
+            .get_mut(&key)
+            .is_some_and(|child| remove_field(child, rest));
+    }
+
+    let Some((selector, rest)) = split_selector(path) else {
+        return false;
+    };
+    let Value::Array(items) = value else {
+        return false;
+    };
+    let Some(index) = items
+        .iter()
+        .enumerate()
+        .position(|(i, item)| selector_matches(i, item, selector))
+    else {
+        return false;
+    };
+    if rest.is_empty() {
+        items.remove(index);
+        true
+    } else {
+        remove_field(&mut items[index], rest)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// One field that differs between the live object and the apply result
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    /// e.g. `spec.template.spec.containers[name=web].image`
+    pub path: String,
+    pub change: ChangeKind,
+    pub live: Option<Value>,
+    pub result: Option<Value>,
+    /// Set by the submitted YAML. Other changes come from defaulting,
+    /// admission webhooks or fields Kubeli no longer applies.
+    pub from_manifest: bool,
+}
+
+/// Dry-run outcome of applying one manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyPreview {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    /// False when the apply would create the object
+    pub exists: bool,
+    /// Live object as YAML, managedFields stripped
+    pub live: Option<String>,
+    pub submitted: String,
+    /// What the server would store, managedFields stripped
+    pub result: String,
+    pub changes: Vec<FieldChange>,
+    /// Fields other managers own. The preview shows the forced result;
+    /// a plain apply would be rejected.
+    pub conflicts: Vec<FieldConflict>,
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Items of a list keyed by `name` (containers, env, ports, volumes...),
+/// so a diff follows the item instead of its position
+fn named_items(items: &[Value]) -> Option<BTreeMap<&str, &Value>> {
+    if items.is_empty() {
+        return None;
+    }
+    items
+        .iter()
+        .map(|item| Some((item.get("name")?.as_str()?, item)))
+        .collect()
+}
+
+fn diff_values(
+    path: &str,
+    live: Option<&Value>,
+    result: Option<&Value>,
+    out: &mut Vec<FieldChange>,
+) {
+    if live == result || IGNORED_PATHS.contains(&path) {
+        return;
+    }
+    match (live, result) {
+        (Some(Value::Object(_)) | None, Some(Value::Object(_)) | None) => {
+            let empty = serde_json::Map::new();
+            let live = live.and_then(Value::as_object).unwrap_or(&empty);
+            let result = result.and_then(Value::as_object).unwrap_or(&empty);
+            let keys: BTreeSet<&String> = live.keys().chain(result.keys()).collect();
+            for key in keys {
+                diff_values(&child_path(path, key), live.get(key), result.get(key), out);
+            }
+        }
+        (Some(Value::Array(live_items)), Some(Value::Array(result_items))) => {
+            if let (Some(live_named), Some(result_named)) =
+                (named_items(live_items), named_items(result_items))
+            {
+                let names: BTreeSet<&str> = live_named
+                    .keys()
+                    .chain(result_named.keys())
+                    .copied()
+                    .collect();
+                for name in names {
+                    diff_values(
+                        &format!("{}[name={}]", path, name),
+                        live_named.get(name).copied(),
+                        result_named.get(name).copied(),
+                        out,
+                    );
+                }
+            } else if live_items.len() == result_items.len() {
+                for (i, (l, r)) in live_items.iter().zip(result_items).enumerate() {
+                    diff_values(&format!("{}[{}]", path, i), Some(l), Some(r), out);
+                }
+            } else {
+                out.push(leaf_change(path, live, result));
+            }
+        }
+        _ => out.push(leaf_change(path, live, result)),
+    }
+}
+
+fn leaf_change(path: &str, live: Option<&Value>, result: Option<&Value>) -> FieldChange {
+    let change = match (live, result) {
+        (None, _) => ChangeKind::Added,
+        (_, None) => ChangeKind::Removed,
+        _ => ChangeKind::Changed,
+    };
+    FieldChange {
+        path: path.to_string(),
+        change,
+        live: live.cloned(),
+        result: result.cloned(),
+        from_manifest: false,
+    }
+}
+
+/// Leaf paths a manifest sets, in the same notation as `diff_values`
+fn manifest_paths(path: &str, value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                manifest_paths(&child_path(path, key), child, out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => match named_items(items) {
+            Some(named) => {
+                for (name, item) in named {
+                    manifest_paths(&format!("{}[name={}]", path, name), item, out);
+                }
+            }
+            None => {
+                for (i, item) in items.iter().enumerate() {
+                    manifest_paths(&format!("{}[{}]", path, i), item, out);
+                }
+            }
+        },
+        _ => out.push(path.to_string()),
+    }
+}
+
+fn is_under(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.starts_with('.') || rest.starts_with('['))
+}
+
+/// Leaf changes from `old` to `new`, with `from_manifest` unset. Named list
+/// items are matched by name; server-maintained metadata is ignored.
+pub(crate) fn diff_json(old: Option<&Value>, new: &Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_values("", old, Some(new), &mut changes);
+    changes
+}
+
+/// Field changes from the live object (if any) to the dry-run result, each
+/// marked with whether the submitted manifest asked for it
+fn diff_manifest(live: Option<&Value>, result: &Value, submitted: &Value) -> Vec<FieldChange> {
+    let mut changes = diff_json(live, result);
+
+    let mut submitted_paths = Vec::new();
+    manifest_paths("", submitted, &mut submitted_paths);
+    for change in &mut changes {
+        change.from_manifest = submitted_paths
+            .iter()
+            .any(|p| p == &change.path || is_under(p, &change.path) || is_under(&change.path, p));
+    }
+    changes
+}
+
+/// Dry-run a server-side apply (`dryRun=All`) and return what it would
+/// change. Field-manager conflicts are reported rather than forced.
+#[command]
+pub async fn dry_run_resource_yaml(
+    state: State<'_, AppState>,
+    yaml_content: String,
+    context: Option<String>,
+) -> Result<ApplyPreview, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+
+    let submitted: Value = serde_yaml::from_str(&yaml_content)?;
+    let target = resolve_manifest(&client, &submitted).await?;
+
+    let live = match target.api.get_opt(&target.name).await? {
+        Some(obj) => Some(clean_value(&obj)?),
+        None => None,
+    };
+
+    let params = PatchParams::apply(FIELD_MANAGER).dry_run();
+    let patch = Patch::Apply(&target.object);
+    let (result, conflicts) = match target.api.patch(&target.name, &params, &patch).await {
+        Ok(result) => (result, Vec::new()),
+        Err(err) => match field_conflicts(&err) {
+            // Still show the outcome, as it would be with force
+            Some(conflicts) => {
+                let forced = params.clone().force();
+                (
+                    target.api.patch(&target.name, &forced, &patch).await?,
+                    conflicts,
+                )
+            }
+            None => return Err(err.into()),
+        },
+    };
+    let result = clean_value(&result)?;
+    let changes = diff_manifest(live.as_ref(), &result, &submitted);
+
+    tracing::info!(
+        "Dry-run apply of {} {}: {} changes, {} conflicts",
+        target.kind,
+        target.name,
+        changes.len(),
+        conflicts.len()
+    );
+
+    Ok(ApplyPreview {
+        kind: target.kind,
+        name: target.name,
+        namespace: target.namespace,
+        exists: live.is_some(),
+        live: live.as_ref().map(serde_yaml::to_string).transpose()?,
+        submitted: serde_yaml::to_string(&submitted)?,
+        result: serde_yaml::to_string(&result)?,
+        changes,
+        conflicts,
+    })
+}
+
+/// How long to wait for a newly applied CRD before applying the rest
+const CRD_ESTABLISH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Files that make a directory a kustomization
+const KUSTOMIZATION_FILES: &[&str] = &["kustomization.yaml", "kustomization.yml", "Kustomization"];
+
+/// Where `apply_manifests` reads manifests from
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ManifestSource {
+    /// YAML text, possibly several `---`-separated documents
+    Yaml { content: String },
+    /// A manifest file, a directory of manifests, or a kustomization
+    /// directory (rendered with `kubectl kustomize`)
+    Path {
+        path: String,
+        #[serde(default)]
+        recursive: bool,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ApplyOutcome {
+    Created,
+    Configured,
+    Unchanged,
+    Pruned,
+    Failed,
+}
+
+/// Outcome of one object of `apply_manifests`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedObject {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub outcome: ApplyOutcome,
+    pub error: Option<KubeliError>,
+}
+
+/// Split YAML into objects. Empty documents are skipped and `kind: List`
+/// documents are expanded into their items.
+pub(crate) fn parse_manifests(content: &str) -> Result<Vec<Value>, KubeliError> {
+    let mut manifests = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let value = Value::deserialize(document)?;
+        match value {
+            Value::Null => {}
+            Value::Object(_) if value["kind"] == "List" => manifests.extend(
+                value["items"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|item| !item.is_null())
+                    .cloned(),
+            ),
+            Value::Object(_) => manifests.push(value),
+            other => {
+                return Err(format!("Expected a Kubernetes object, got: {}", other).into());
+            }
+        }
+    }
+    Ok(manifests)
+}
+
+/// Namespaces first, then CRDs, then everything else
+pub(crate) fn apply_rank(manifest: &Value) -> u8 {
+    match manifest["kind"].as_str() {
+        Some("Namespace") => 0,
+        Some("CustomResourceDefinition") => 1,
+        _ => 2,
+    }
+}
+
+async fn read_manifest_file(path: &Path) -> Result<Vec<Value>, KubeliError> {
+    let content = tokio::fs::read_to_string(path).await?;
+    parse_manifests(&content)
+        .map_err(|e| KubeliError::unknown(format!("{}: {}", path.display(), e.message)))
+}
+
+/// YAML and JSON files of a directory in path order, like `kubectl apply -f`
+async fn manifest_files(dir: &Path, recursive: bool) -> Result<Vec<PathBuf>, KubeliError> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() {
+                if recursive {
+                    dirs.push(path);
+                }
+            } else if matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("yaml" | "yml" | "json")
+            ) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+async fn render_kustomization(dir: &Path) -> Result<String, KubeliError> {
+    let output = Command::new("kubectl")
+        .arg("kustomize")
+        .arg(dir)
+        .env("PATH", get_extended_path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|e| format!("Failed to run kubectl kustomize: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "kubectl kustomize failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+async fn load_manifests(source: ManifestSource) -> Result<Vec<Value>, KubeliError> {
+    let (path, recursive) = match source {
+        ManifestSource::Yaml { content } => return parse_manifests(&content),
+        ManifestSource::Path { path, recursive } => (PathBuf::from(path), recursive),
+    };
+    if !path.is_dir() {
+        return read_manifest_file(&path).await;
+    }
+    if KUSTOMIZATION_FILES
+        .iter()
+        .any(|file| path.join(file).is_file())
+    {
+        return parse_manifests(&render_kustomization(&path).await?);
+    }
+    let mut manifests = Vec::new();
+    for file in manifest_files(&path, recursive).await? {
+        manifests.extend(read_manifest_file(&file).await?);
+    }
+    Ok(manifests)
+}
+
+/// Wait until newly applied CRDs are served, so the custom resources that
+/// follow them can be discovered
+pub(crate) async fn wait_for_crds(client: &kube::Client, names: &[String]) {
+    let crds: Api<CustomResourceDefinition> = Api::all(client.clone());
+    for name in names {
+        let established = await_condition(crds.clone(), name, conditions::is_crd_established());
+        if tokio::time::timeout(CRD_ESTABLISH_TIMEOUT, established)
+            .await
+            .is_err()
+        {
+            tracing::warn!(
+                "CRD {} not established after {:?}",
+                name,
+                CRD_ESTABLISH_TIMEOUT
+            );
+        }
+    }
+}
+
+pub(crate) async fn apply_object(
+    target: &ManifestTarget,
+    resolution: ConflictResolution,
+) -> Result<ApplyOutcome, KubeliError> {
+    let before = target.api.get_opt(&target.name).await?;
+    let after = server_side_apply(&target.api, &target.name, &target.object, resolution).await?;
+    Ok(match before {
+        None => ApplyOutcome::Created,
+        Some(before) if before.resource_version() == after.resource_version() => {
+            ApplyOutcome::Unchanged
+        }
+        Some(_) => ApplyOutcome::Configured,
+    })
+}
+
+/// (group, kind, namespace)
+type ApplyScope = (String, String, Option<String>);
+
+/// Delete objects matching `selector` that are no longer in the applied
+/// set, within the kinds and namespaces that were applied. Objects with
+/// owners are left to their controllers.
+async fn prune(
+    selector: &str,
+    scopes: BTreeMap<ApplyScope, Api<DynamicObject>>,
+    applied: &HashSet<(ApplyScope, String)>,
+) -> Vec<AppliedObject> {
+    let mut pruned = Vec::new();
+    for (scope, api) in scopes {
+        let (_, kind, namespace) = scope.clone();
+        let list = match api.list(&ListParams::default().labels(selector)).await {
+            Ok(list) => list,
+            Err(err) => {
+                pruned.push(AppliedObject {
+                    kind,
+                    name: String::new(),
+                    namespace,
+                    outcome: ApplyOutcome::Failed,
+                    error: Some(err.into()),
+                });
+                continue;
+            }
+        };
+        for obj in list.items {
+            let name = obj.name_any();
+            if obj.metadata.deletion_timestamp.is_some()
+                || !obj.owner_references().is_empty()
+                || applied.contains(&(scope.clone(), name.clone()))
+            {
+                continue;
+            }
+            let (outcome, error) = match api.delete(&name, &DeleteParams::background()).await {
+                Ok(_) => (ApplyOutcome::Pruned, None),
+                Err(err) => (ApplyOutcome::Failed, Some(err.into())),
+            };
+            pruned.push(AppliedObject {
+                kind: kind.clone(),
+                name,
+                namespace: namespace.clone(),
+                outcome,
+                error,
+            });
+        }
+    }
+    pruned
+}
+
+/// Apply every object of a multi-document YAML, a directory or a
+/// kustomization, Namespaces and CRDs first. A failed object does not stop
+/// the rest. With `prune_selector`, objects carrying that label selector
+/// which are not in the set are deleted afterwards. Conflicts with other
+/// field managers fail the object unless `conflict_resolution` says otherwise.
+#[command]
+pub async fn apply_manifests(
+    state: State<'_, AppState>,
+    source: ManifestSource,
+    prune_selector: Option<String>,
+    conflict_resolution: Option<ConflictResolution>,
+    context: Option<String>,
+) -> Result<Vec<AppliedObject>, KubeliError> {
+    let resolution = conflict_resolution.unwrap_or_default();
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+
+    let mut manifests = load_manifests(source).await?;
+    if manifests.is_empty() {
+        return Err("No manifests found".into());
+    }
+    manifests.sort_by_key(apply_rank);
+
+    let mut objects = Vec::with_capacity(manifests.len());
+    let mut scopes = BTreeMap::new();
+    let mut applied = HashSet::new();
+    let mut new_crds = Vec::new();
+
+    for manifest in &manifests {
+        if apply_rank(manifest) > 1 && !new_crds.is_empty() {
+            wait_for_crds(&client, &std::mem::take(&mut new_crds)).await;
+        }
+
+        let target = match resolve_manifest(&client, manifest).await {
+            Ok(target) => target,
+            Err(err) => {
+                objects.push(AppliedObject {
+                    kind: manifest["kind"].as_str().unwrap_or_default().to_string(),
+                    name: manifest["metadata"]["name"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    namespace: manifest["metadata"]["namespace"].as_str().map(String::from),
+                    outcome: ApplyOutcome::Failed,
+                    error: Some(err),
+                });
+                continue;
+            }
+        };
+
+        let scope = (
+            target.group.clone(),
+            target.kind.clone(),
+            target.namespace.clone(),
+        );
+        // Without a known scope a list could span every namespace
+        if target.namespaced.is_some() {
+            scopes
+                .entry(scope.clone())
+                .or_insert_with(|| target.api.clone());
+        }
+        applied.insert((scope, target.name.clone()));
+
+        let (outcome, error) = match apply_object(&target, resolution).await {
+            Ok(outcome) => {
+                if target.kind == "CustomResourceDefinition" {
+                    new_crds.push(target.name.clone());
+                }
+                (outcome, None)
+            }
+            Err(err) => (ApplyOutcome::Failed, Some(err)),
+        };
+        objects.push(AppliedObject {
+            kind: target.kind,
+            name: target.name,
+            namespace: target.namespace,
+            outcome,
+            error,
+        });
+    }
+
+    if let Some(selector) = prune_selector.filter(|s| !s.trim().is_empty()) {
+        objects.extend(prune(&selector, scopes, &applied).await);
+    }
+
+    let failed = objects
+        .iter()
+        .filter(|obj| obj.outcome == ApplyOutcome::Failed)
+        .count();
+    tracing::info!("Applied {} manifests, {} failed", manifests.len(), failed);
+    Ok(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn deployment(image: &str, replicas: i64) -> Value {
+        json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": {"name": "web", "namespace": "default", "resourceVersion": "1"},
+            "spec": {
+                "replicas": replicas,
+                "template": {"spec": {"containers": [
+                    {"name": "sidecar", "image": "envoy:1"},
+                    {"name": "web", "image": image}
+                ]}}
+            }
+        })
+    }
+
+    #[test]
+    fn diff_follows_named_list_items() {
+        let live = deployment("web:1", 2);
+        let mut result = deployment("web:2", 2);
+        // Reordering a named list is not a change
+        result["spec"]["template"]["spec"]["containers"]
+            .as_array_mut()
+            .unwrap()
+            .reverse();
+        result["metadata"]["resourceVersion"] = json!("2");
+
+        let changes = diff_manifest(Some(&live), &result, &json!({}));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].path,
+            "spec.template.spec.containers[name=web].image"
+        );
+        assert_eq!(changes[0].change, ChangeKind::Changed);
+        assert_eq!(changes[0].live, Some(json!("web:1")));
+        assert_eq!(changes[0].result, Some(json!("web:2")));
+    }
+
+    #[test]
+    fn diff_marks_changes_the_manifest_asked_for() {
+        let live = deployment("web:1", 2);
+        let mut result = deployment("web:1", 3);
+        // Defaulted by the server, not in the manifest
+        result["spec"]["strategy"] = json!({"type": "RollingUpdate"});
+        let submitted = json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": {"name": "web"},
+            "spec": {"replicas": 3}
+        });
+
+        let changes = diff_manifest(Some(&live), &result, &submitted);
+        let replicas = changes.iter().find(|c| c.path == "spec.replicas").unwrap();
+        assert!(replicas.from_manifest);
+        let strategy = changes
+            .iter()
+            .find(|c| c.path == "spec.strategy.type")
+            .unwrap();
+        assert_eq!(strategy.change, ChangeKind::Added);
+        assert!(!strategy.from_manifest);
+    }
+
+    #[test]
+    fn diff_of_a_create_lists_every_field_but_server_metadata() {
+        let mut result = deployment("web:1", 1);
+        result["metadata"]["uid"] = json!("dry-run-uid");
+
+        let changes = diff_manifest(None, &result, &result);
+        assert!(changes.iter().all(|c| c.change == ChangeKind::Added));
+        assert!(changes.iter().all(|c| c.from_manifest));
+        assert!(changes.iter().any(|c| c.path == "metadata.name"));
+        assert!(!changes
+            .iter()
+            .any(|c| c.path == "metadata.uid" || c.path == "metadata.resourceVersion"));
+    }
+
+    #[test]
+    fn unnamed_lists_of_different_length_change_as_a_whole() {
+        let live = json!({"spec": {"args": ["a"]}});
+        let result = json!({"spec": {"args": ["a", "b"]}});
+
+        let changes = diff_manifest(Some(&live), &result, &json!({}));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "spec.args");
+    }
+
+    #[test]
+    fn parse_splits_documents_and_expands_lists() {
+        let manifests = parse_manifests(
+            "---\napiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: a\n---\n# only a comment\n---\napiVersion: v1\nkind: List\nitems:\n- apiVersion: v1\n  kind: Secret\n  metadata:\n    name: b\n- apiVersion: v1\n  kind: Service\n  metadata:\n    name: c\n",
+        )
+        .unwrap();
+        let names: Vec<_> = manifests
+            .iter()
+            .map(|m| m["metadata"]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["a", "b", "c"]);
+
+        assert!(parse_manifests("- just\n- a list\n").is_err());
+    }
+
+    #[test]
+    fn namespaces_and_crds_sort_first_keeping_file_order() {
+        let mut manifests: Vec<Value> = [
+            "Deployment",
+            "CustomResourceDefinition",
+            "Service",
+            "Namespace",
+        ]
+        .iter()
+        .map(|kind| json!({"kind": kind}))
+        .collect();
+        manifests.sort_by_key(apply_rank);
+        let kinds: Vec<_> = manifests
+            .iter()
+            .map(|m| m["kind"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "Namespace",
+                "CustomResourceDefinition",
+                "Deployment",
+                "Service"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn directory_source_reads_manifest_files_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("b.yaml"),
+            "kind: Service\nmetadata:\n  name: b\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("a.yml"),
+            "kind: Service\nmetadata:\n  name: a\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "# not a manifest").unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(
+            dir.path().join("nested/c.json"),
+            r#"{"kind":"Service","metadata":{"name":"c"}}"#,
+        )
+        .unwrap();
+
+        let names = |manifests: Vec<Value>| -> Vec<String> {
+            manifests
+                .iter()
+                .map(|m| m["metadata"]["name"].as_str().unwrap().to_string())
+                .collect()
+        };
+        let path = dir.path().to_string_lossy().to_string();
+        let flat = load_manifests(ManifestSource::Path {
+            path: path.clone(),
+            recursive: false,
+        })
+        .await
+        .unwrap();
+        assert_eq!(names(flat), ["a", "b"]);
+
+        let recursive = load_manifests(ManifestSource::Path {
+            path,
+            recursive: true,
+        })
+        .await
+        .unwrap();
+        assert_eq!(names(recursive), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn remove_field_follows_server_side_apply_paths() {
+        let mut manifest = json!({
+            "metadata": {"labels": {"app.kubernetes.io/name": "web", "tier": "front"}},
+            "spec": {
+                "replicas": 3,
+                "template": {"spec": {"containers": [
+                    {"name": "web", "image": "web:2", "ports": [{"containerPort": 80}]},
+                    {"name": "sidecar", "image": "envoy:1"}
+                ]}},
+                "finalizers": ["a", "b"]
+            }
+        });
+
+        assert!(remove_field(&mut manifest, ".spec.replicas"));
+        assert!(remove_field(
+            &mut manifest,
+            ".metadata.labels.app.kubernetes.io/name"
+        ));
+        assert!(remove_field(
+            &mut manifest,
+            ".spec.template.spec.containers[name=\"web\"].image"
+        ));
+        // protocol is defaulted by the server and absent from the manifest
+        assert!(remove_field(
+            &mut manifest,
+            ".spec.template.spec.containers[name=\"web\"].ports[containerPort=80,protocol=\"TCP\"]"
+        ));
+        assert!(remove_field(&mut manifest, ".spec.finalizers[=\"a\"]"));
+        assert!(!remove_field(&mut manifest, ".spec.paused"));
+        assert!(!remove_field(
+            &mut manifest,
+            ".spec.template.spec.containers[name=\"missing\"]"
+        ));
+
+        assert_eq!(
+            manifest,
+            json!({
+                "metadata": {"labels": {"tier": "front"}},
+                "spec": {
+                    "template": {"spec": {"containers": [
+                        {"name": "web", "ports": []},
+                        {"name": "sidecar", "image": "envoy:1"}
+                    ]}},
+                    "finalizers": ["b"]
+                }
+            })
+        );
+    }
+}
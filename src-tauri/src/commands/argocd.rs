@@ -83,8 +83,9 @@ fn argocd_api_resource() -> ApiResource {
 pub async fn list_argocd_applications(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<ArgoCDApplicationInfo>, String> {
-    let client = match state.k8s.get_client().await {
+    let client = match state.k8s.get_client_for(context.as_deref()).await {
         Ok(c) => c,
         Err(_) => return Ok(Vec::new()),
     };
@@ -241,8 +242,13 @@ pub async fn refresh_argocd_application(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let ar = argocd_api_resource();
     let api: Api<DynamicObject> = Api::namespaced_with(client, &namespace, &ar);
@@ -268,8 +274,13 @@ pub async fn hard_refresh_argocd_application(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let ar = argocd_api_resource();
     let api: Api<DynamicObject> = Api::namespaced_with(client, &namespace, &ar);
@@ -387,8 +398,13 @@ pub async fn sync_argocd_application(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let ar = argocd_api_resource();
     let api: Api<DynamicObject> = Api::namespaced_with(client, &namespace, &ar);
@@ -423,8 +439,13 @@ pub async fn get_argocd_application_history(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<Vec<ArgoCDHistoryEntry>, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let ar = argocd_api_resource();
     let api: Api<DynamicObject> = Api::namespaced_with(client, &namespace, &ar);
@@ -509,8 +530,13 @@ pub async fn rollback_argocd_application(
     name: String,
     namespace: String,
     id: i64,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let ar = argocd_api_resource();
     let api: Api<DynamicObject> = Api::namespaced_with(client, &namespace, &ar);
@@ -547,8 +573,13 @@ pub async fn get_argocd_operation_state(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<ArgoCDOperationState, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let ar = argocd_api_resource();
     let api: Api<DynamicObject> = Api::namespaced_with(client, &namespace, &ar);
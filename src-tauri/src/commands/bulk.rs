@@ -0,0 +1,455 @@
+use crate::commands::resources::{container_image_patch, resolve_api_resource, ImagePatchTarget};
+use crate::error::KubeliError;
+use crate::k8s::AppState;
+use futures::stream::{self, StreamExt};
+use kube::api::{
+    Api, DeleteParams, DynamicObject, ListParams, Patch, PatchParams, PropagationPolicy,
+};
+use kube::discovery::ApiResource;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tauri::{command, AppHandle, Emitter, State};
+
+/// API calls a bulk operation keeps in flight unless the caller asks otherwise
+const DEFAULT_CONCURRENCY: usize = 8;
+const MAX_CONCURRENCY: usize = 32;
+
+/// One object of a bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResourceRef {
+    pub name: String,
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// Objects of one resource type a bulk operation acts on
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum BulkTarget {
+    /// Objects picked in the UI
+    Refs { refs: Vec<ResourceRef> },
+    /// Objects matching a label selector, in one namespace or all of them
+    Selector {
+        namespace: Option<String>,
+        label_selector: String,
+    },
+}
+
+/// What happens to the dependents of a deleted object
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum DeletePropagation {
+    Foreground,
+    Background,
+    Orphan,
+}
+
+impl From<DeletePropagation> for PropagationPolicy {
+    fn from(propagation: DeletePropagation) -> Self {
+        match propagation {
+            DeletePropagation::Foreground => PropagationPolicy::Foreground,
+            DeletePropagation::Background => PropagationPolicy::Background,
+            DeletePropagation::Orphan => PropagationPolicy::Orphan,
+        }
+    }
+}
+
+/// Workload kinds with a `spec.replicas` that `bulk_scale_resources` sets.
+/// Other kinds would accept the patch and silently drop the field.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScaleTarget {
+    Deployment,
+    StatefulSet,
+    ReplicaSet,
+}
+
+impl ScaleTarget {
+    fn resource_type(self) -> &'static str {
+        match self {
+            ScaleTarget::Deployment => "deployment",
+            ScaleTarget::StatefulSet => "statefulset",
+            ScaleTarget::ReplicaSet => "replicaset",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BulkDeleteOptions {
+    /// Overrides each object's terminationGracePeriodSeconds; 0 deletes
+    /// immediately
+    pub grace_period_seconds: Option<u32>,
+    /// Server default (Background for most kinds) when unset
+    pub propagation_policy: Option<DeletePropagation>,
+    /// Validate each delete server-side (`dryRun=All`) without deleting,
+    /// to preview exactly which objects would go
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkItemResult {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub success: bool,
+    pub error: Option<KubeliError>,
+}
+
+/// Progress of a bulk operation, emitted on `bulk-{operation_id}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum BulkEvent {
+    Started {
+        total: usize,
+        dry_run: bool,
+    },
+    Item {
+        result: BulkItemResult,
+        completed: usize,
+        total: usize,
+    },
+    Finished {
+        succeeded: usize,
+        failed: usize,
+    },
+}
+
+fn resolve_type(resource_type: &str) -> Result<(ApiResource, bool), KubeliError> {
+    resolve_api_resource(resource_type)
+        .ok_or_else(|| format!("Unsupported resource type: {}", resource_type).into())
+}
+
+/// Expand a target into object references. A selector must not be empty,
+/// so a bulk operation never silently covers every object of a type.
+async fn resolve_targets(
+    client: &kube::Client,
+    ar: &ApiResource,
+    namespaced: bool,
+    target: BulkTarget,
+) -> Result<Vec<ResourceRef>, KubeliError> {
+    let (namespace, label_selector) = match target {
+        BulkTarget::Refs { refs } => return Ok(refs),
+        BulkTarget::Selector {
+            namespace,
+            label_selector,
+        } => (namespace, label_selector),
+    };
+    if label_selector.trim().is_empty() {
+        return Err("A label selector is required".into());
+    }
+
+    let api: Api<DynamicObject> = match namespace.as_deref() {
+        Some(ns) if namespaced => Api::namespaced_with(client.clone(), ns, ar),
+        _ => Api::all_with(client.clone(), ar),
+    };
+    let list = api
+        .list(&ListParams::default().labels(&label_selector))
+        .await?;
+    Ok(list
+        .items
+        .iter()
+        .map(|obj| ResourceRef {
+            name: obj.name_any(),
+            namespace: obj.namespace(),
+        })
+        .collect())
+}
+
+fn item_api(
+    client: &kube::Client,
+    ar: &ApiResource,
+    namespaced: bool,
+    item: &ResourceRef,
+) -> Result<Api<DynamicObject>, KubeliError> {
+    if !namespaced {
+        return Ok(Api::all_with(client.clone(), ar));
+    }
+    let ns = item
+        .namespace
+        .as_deref()
+        .ok_or_else(|| format!("Namespace required for {}", item.name))?;
+    Ok(Api::namespaced_with(client.clone(), ns, ar))
+}
+
+/// Run `op` for every object, at most `concurrency` at a time. Each result
+/// is emitted as it completes; one failure does not stop the others.
+async fn run_bulk<F, Fut>(
+    app: &AppHandle,
+    operation_id: &str,
+    items: Vec<ResourceRef>,
+    concurrency: Option<usize>,
+    dry_run: bool,
+    op: F,
+) -> Vec<BulkItemResult>
+where
+    F: Fn(ResourceRef) -> Fut,
+    Fut: Future<Output = Result<(), KubeliError>>,
+{
+    let channel = format!("bulk-{}", operation_id);
+    let total = items.len();
+    let _ = app.emit(&channel, BulkEvent::Started { total, dry_run });
+
+    let limit = concurrency
+        .unwrap_or(DEFAULT_CONCURRENCY)
+        .clamp(1, MAX_CONCURRENCY);
+    let mut outcomes = stream::iter(items)
+        .map(|item| {
+            let outcome = op(item.clone());
+            async move { (item, outcome.await) }
+        })
+        .buffer_unordered(limit);
+
+    let mut results = Vec::with_capacity(total);
+    while let Some((item, outcome)) = outcomes.next().await {
+        let result = BulkItemResult {
+            name: item.name,
+            namespace: item.namespace,
+            success: outcome.is_ok(),
+            error: outcome.err(),
+        };
+        results.push(result.clone());
+        let _ = app.emit(
+            &channel,
+            BulkEvent::Item {
+                result,
+                completed: results.len(),
+                total,
+            },
+        );
+    }
+
+    let failed = results.iter().filter(|result| !result.success).count();
+    let _ = app.emit(
+        &channel,
+        BulkEvent::Finished {
+            succeeded: total - failed,
+            failed,
+        },
+    );
+    results
+}
+
+/// Delete several objects of one type
+#[command]
+#[allow(clippy::too_many_arguments)]
+pub async fn bulk_delete_resources(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    operation_id: String,
+    resource_type: String,
+    target: BulkTarget,
+    options: Option<BulkDeleteOptions>,
+    concurrency: Option<usize>,
+    context: Option<String>,
+) -> Result<Vec<BulkItemResult>, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let (ar, namespaced) = resolve_type(&resource_type)?;
+    let options = options.unwrap_or_default();
+    let items = resolve_targets(&client, &ar, namespaced, target).await?;
+
+    let params = DeleteParams {
+        dry_run: options.dry_run,
+        grace_period_seconds: options.grace_period_seconds,
+        propagation_policy: options.propagation_policy.map(Into::into),
+        ..Default::default()
+    };
+    let (client, ar, params) = (&client, &ar, &params);
+    let results = run_bulk(
+        &app,
+        &operation_id,
+        items,
+        concurrency,
+        options.dry_run,
+        |item| async move {
+            item_api(client, ar, namespaced, &item)?
+                .delete(&item.name, params)
+                .await?;
+            Ok::<_, KubeliError>(())
+        },
+    )
+    .await;
+
+    tracing::info!(
+        "{}Deleted {}/{} {}",
+        if options.dry_run { "[dry run] " } else { "" },
+        results.iter().filter(|result| result.success).count(),
+        results.len(),
+        resource_type
+    );
+    Ok(results)
+}
+
+/// Set the replica count of several Deployments, StatefulSets or ReplicaSets
+#[command]
+#[allow(clippy::too_many_arguments)]
+pub async fn bulk_scale_resources(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    operation_id: String,
+    resource_type: ScaleTarget,
+    target: BulkTarget,
+    replicas: i32,
+    dry_run: Option<bool>,
+    concurrency: Option<usize>,
+    context: Option<String>,
+) -> Result<Vec<BulkItemResult>, KubeliError> {
+    if replicas < 0 {
+        return Err("Replicas must not be negative".into());
+    }
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let (ar, namespaced) = resolve_type(resource_type.resource_type())?;
+    let items = resolve_targets(&client, &ar, namespaced, target).await?;
+
+    let dry_run = dry_run.unwrap_or(false);
+    let params = PatchParams {
+        dry_run,
+        ..Default::default()
+    };
+    let patch = serde_json::json!({ "spec": { "replicas": replicas } });
+    let (client, ar, params, patch) = (&client, &ar, &params, &patch);
+    let results = run_bulk(
+        &app,
+        &operation_id,
+        items,
+        concurrency,
+        dry_run,
+        |item| async move {
+            item_api(client, ar, namespaced, &item)?
+                .patch(&item.name, params, &Patch::Merge(patch))
+                .await?;
+            Ok::<_, KubeliError>(())
+        },
+    )
+    .await;
+
+    tracing::info!(
+        "{}Scaled {}/{} {} to {} replicas",
+        if dry_run { "[dry run] " } else { "" },
+        results.iter().filter(|result| result.success).count(),
+        results.len(),
+        resource_type.resource_type(),
+        replicas
+    );
+    Ok(results)
+}
+
+/// Set the image of the same-named container in several workloads
+#[command]
+#[allow(clippy::too_many_arguments)]
+pub async fn bulk_set_container_image(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    operation_id: String,
+    resource_type: ImagePatchTarget,
+    target: BulkTarget,
+    container_name: String,
+    image: String,
+    init_container: bool,
+    dry_run: Option<bool>,
+    concurrency: Option<usize>,
+    context: Option<String>,
+) -> Result<Vec<BulkItemResult>, KubeliError> {
+    let image = image.trim();
+    if image.is_empty() {
+        return Err(KubeliError::unknown("Image must not be empty"));
+    }
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let (ar, namespaced) = resolve_type(match resource_type {
+        ImagePatchTarget::Deployment => "deployment",
+        ImagePatchTarget::StatefulSet => "statefulset",
+        ImagePatchTarget::DaemonSet => "daemonset",
+    })?;
+    let items = resolve_targets(&client, &ar, namespaced, target).await?;
+
+    let dry_run = dry_run.unwrap_or(false);
+    let params = PatchParams {
+        dry_run,
+        ..Default::default()
+    };
+    let patch = container_image_patch(&container_name, image, init_container);
+    let (client, ar, params, patch) = (&client, &ar, &params, &patch);
+    let results = run_bulk(
+        &app,
+        &operation_id,
+        items,
+        concurrency,
+        dry_run,
+        |item| async move {
+            item_api(client, ar, namespaced, &item)?
+                .patch(&item.name, params, &Patch::Strategic(patch))
+                .await?;
+            Ok::<_, KubeliError>(())
+        },
+    )
+    .await;
+
+    tracing::info!(
+        "{}Set image of container {} to {} in {}/{} workloads",
+        if dry_run { "[dry run] " } else { "" },
+        container_name,
+        image,
+        results.iter().filter(|result| result.success).count(),
+        results.len()
+    );
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn targets_and_options_deserialize_from_the_frontend_shape() {
+        let target: BulkTarget = serde_json::from_str(
+            r#"{"type":"refs","refs":[{"name":"a","namespace":"web"},{"name":"node-1"}]}"#,
+        )
+        .unwrap();
+        let BulkTarget::Refs { refs } = target else {
+            panic!("expected refs");
+        };
+        assert_eq!(refs[1].namespace, None);
+
+        let target: BulkTarget = serde_json::from_str(
+            r#"{"type":"selector","namespace":null,"label_selector":"app=web"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            target,
+            BulkTarget::Selector {
+                namespace: None,
+                ..
+            }
+        ));
+
+        let options: BulkDeleteOptions =
+            serde_json::from_str(r#"{"propagation_policy":"Orphan"}"#).unwrap();
+        assert!(!options.dry_run);
+        assert_eq!(options.grace_period_seconds, None);
+        assert!(matches!(
+            options.propagation_policy.map(PropagationPolicy::from),
+            Some(PropagationPolicy::Orphan)
+        ));
+    }
+
+    #[test]
+    fn resource_types_resolve_like_delete_resource() {
+        let (ar, namespaced) = resolve_type("pods").unwrap();
+        assert_eq!(ar.kind, "Pod");
+        assert!(namespaced);
+        let (_, namespaced) = resolve_type("nodes").unwrap();
+        assert!(!namespaced);
+        assert!(resolve_type("no-such-type").is_err());
+    }
+
+    #[test]
+    fn only_workloads_with_replicas_can_be_scaled() {
+        let target: ScaleTarget = serde_json::from_str(r#""statefulset""#).unwrap();
+        assert_eq!(target, ScaleTarget::StatefulSet);
+        let (ar, _) = resolve_type(target.resource_type()).unwrap();
+        assert_eq!(ar.kind, "StatefulSet");
+
+        for unsupported in [r#""pod""#, r#""service""#, r#""configmap""#] {
+            assert!(serde_json::from_str::<ScaleTarget>(unsupported).is_err());
+        }
+    }
+}
@@ -17,25 +17,36 @@ use super::types::{
     ClusterInfo, ConnectionStatus, HealthCheckResult, NamespaceResult, OidcAuthInfo,
 };
 
-/// Stop the sessions that are bound to the cluster we're looking at (watches,
-/// log streams, shells). Called before switching contexts and on disconnect -
-/// otherwise these streams keep running against the old cluster.
+/// Stop the sessions bound to one context (watches, log streams, shells)
+/// and its shared informers, or every session when there is no context name.
+/// Called before that context is reconnected and on its disconnect - sessions
+/// on the other connected clusters are left running, so switching the active
+/// cluster no longer kills them.
 ///
 /// Port-forwards are intentionally NOT torn down here (nor on disconnect): a
 /// forward is its own live tunnel and should survive a cluster switch, which in
 /// this app is a disconnect back to the picker plus connecting elsewhere (#388).
 /// Forwards only end on user-stop, pod death, error, or process exit.
-async fn teardown_active_sessions(app: &AppHandle, state: &AppState) {
-    let old_client = state.k8s.get_client().await.ok();
-
+async fn teardown_context_sessions(app: &AppHandle, state: &AppState, context: Option<&str>) {
+    let old_client = state.k8s.get_client_for(context).await.ok();
     let watches: State<'_, Arc<crate::commands::watch::WatchManager>> = app.state();
-    watches.stop_all().await;
-
     let logs: State<'_, Arc<crate::commands::logs::LogStreamManager>> = app.state();
-    logs.stop_all().await;
-
     let shells: State<'_, Arc<crate::commands::shell::ShellSessionManager>> = app.state();
-    shells.stop_all(old_client).await;
+
+    match context {
+        Some(context) => {
+            watches.stop_context(context).await;
+            logs.stop_context(context).await;
+            shells.stop_context(context, old_client).await;
+            state.cache.stop_context(context);
+        }
+        None => {
+            watches.stop_all().await;
+            logs.stop_all().await;
+            shells.stop_all(old_client).await;
+            state.cache.stop_all();
+        }
+    }
 }
 
 /// List all available clusters from kubeconfig
@@ -158,16 +169,37 @@ pub async fn connect_cluster(
     // init/test and could leave client and context mismatched.
     let _connect_guard = state.k8s.begin_connect().await;
 
-    // Stop a previous connection's OIDC refresh loop before switching; a
-    // non-OIDC target would otherwise keep the old task alive forever.
+    // Switching back to a cluster that is still connected in the background
+    // only makes it active again: its client, watches and streams are kept.
+    if state.k8s.activate(&context).await {
+        let start = std::time::Instant::now();
+        if let Ok(true) = state.k8s.test_connection().await {
+            tracing::info!("Reactivated connected cluster: {}", context);
+            return Ok(ConnectionStatus {
+                connected: true,
+                context: Some(context),
+                error: None,
+                latency_ms: Some(start.elapsed().as_millis() as u64),
+                oidc_auth_required: None,
+            });
+        }
+        tracing::warn!(
+            "Connected cluster {} failed its health check, reconnecting",
+            context
+        );
+    }
+
+    // Stop this context's previous OIDC refresh loop before reconnecting; a
+    // kubeconfig that no longer uses OIDC would otherwise keep the old task
+    // alive forever. Refresh loops of other connected clusters keep running.
     {
         let oidc_state: State<'_, Arc<OidcState>> = app.state();
-        oidc_state.cancel_refresh();
+        oidc_state.cancel_refresh(&context);
     }
 
-    // Tear down all sessions of the previous connection before the client
-    // is replaced.
-    teardown_active_sessions(&app, &state).await;
+    // Tear down the sessions of this context's previous client before it is
+    // replaced. Other connected clusters are left alone.
+    teardown_context_sessions(&app, &state, Some(&context)).await;
 
     // Resolve source_file for this context before building the kubeconfig
     let source_file = load_kubeconfig_from_sources(&app).await.and_then(|cfg| {
@@ -310,7 +342,7 @@ pub async fn connect_cluster(
     match init_result {
         Err(_) => {
             tracing::error!("Connection init timed out for context: {}", context);
-            state.k8s.clear_connection().await;
+            state.k8s.remove_context(&context).await;
             Ok(ConnectionStatus {
                 connected: false,
                 context: Some(context),
@@ -339,12 +371,16 @@ pub async fn connect_cluster(
                         if let (Some(oidc_config), Some(ref user)) = (active_oidc, &user_name) {
                             let oidc_state: State<'_, Arc<OidcState>> = app.state();
                             spawn_oidc_refresh_task(
-                                state.k8s.connection_handle(),
+                                state.k8s.registry_handle(),
                                 Arc::clone(&oidc_state),
                                 oidc_config,
                                 context.clone(),
                             );
                         }
+                        tokio::spawn(crate::commands::portforward_profiles::start_auto_profiles(
+                            app.clone(),
+                            context.clone(),
+                        ));
 
                         Ok(ConnectionStatus {
                             connected: true,
@@ -357,7 +393,7 @@ pub async fn connect_cluster(
                     Ok(false) => {
                         let latency = start.elapsed().as_millis() as u64;
                         tracing::warn!("Connection test failed for context: {}", context);
-                        state.k8s.clear_connection().await;
+                        state.k8s.remove_context(&context).await;
                         Ok(ConnectionStatus {
                             connected: false,
                             context: Some(context),
@@ -370,7 +406,7 @@ pub async fn connect_cluster(
                     }
                     Err(e) => {
                         tracing::error!("Connection test error: {}", e);
-                        state.k8s.clear_connection().await;
+                        state.k8s.remove_context(&context).await;
                         Ok(ConnectionStatus {
                             connected: false,
                             context: Some(context),
@@ -385,7 +421,7 @@ pub async fn connect_cluster(
                 tracing::error!("Failed to connect to cluster: {}", e);
                 // A failed init may have overwritten (or half-replaced) the
                 // previous connection - never keep a client that did not pass.
-                state.k8s.clear_connection().await;
+                state.k8s.remove_context(&context).await;
                 Ok(ConnectionStatus {
                     connected: false,
                     context: Some(context),
@@ -408,16 +444,30 @@ pub async fn switch_context(
     connect_cluster(app, state, context).await
 }
 
-/// Disconnect from current cluster
+/// Disconnect one cluster: `context` when given, otherwise the active one.
+/// Other connected clusters and their sessions are left running.
 #[command]
 pub async fn disconnect_cluster(
     app: AppHandle,
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    tracing::info!("Disconnecting from cluster");
-    let oidc_state: State<'_, Arc<OidcState>> = app.state();
-    oidc_state.cancel_refresh();
-    teardown_active_sessions(&app, &state).await;
+    let target = match context {
+        Some(context) => Some(context),
+        None => state.k8s.get_current_context().await,
+    };
+    tracing::info!(
+        "Disconnecting from cluster: {}",
+        target.as_deref().unwrap_or("(default kubeconfig)")
+    );
+
+    // Without a context name (a default-kubeconfig connection) there is
+    // nothing to scope by, so everything is torn down like a full disconnect.
+    if let Some(target) = &target {
+        let oidc_state: State<'_, Arc<OidcState>> = app.state();
+        oidc_state.cancel_refresh(target);
+    }
+    teardown_context_sessions(&app, &state, target.as_deref()).await;
 
     // Port-forwards are intentionally NOT stopped here. A forward is its own
     // live tunnel and should survive a cluster switch — which in this app is a
@@ -425,22 +475,38 @@ pub async fn disconnect_cluster(
     // Forwards die with the process on quit; until then they keep tunneling and
     // stay visible/controllable via the all-forwards view.
 
-    state.k8s.clear_connection().await;
+    match &target {
+        Some(target) => state.k8s.remove_context(target).await,
+        None => state.k8s.clear_connection().await,
+    }
     Ok(())
 }
 
+/// List the contexts that currently hold a live client, active one included.
+#[command]
+pub async fn list_connected_contexts(
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, KubeliError> {
+    Ok(state.k8s.connected_contexts().await)
+}
+
 /// Get list of namespaces in the current cluster.
 /// Resolution order: configured namespaces → API discovery → fallback to configured on 403.
 #[command]
 pub async fn get_namespaces(
     app: AppHandle,
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<NamespaceResult, KubeliError> {
-    if !state.k8s.is_connected().await {
+    if !state.k8s.is_connected().await && context.is_none() {
         return Err(KubeliError::unknown("Not connected to any cluster"));
     }
 
-    let context = state.k8s.get_current_context().await.unwrap_or_default();
+    let requested = context;
+    let context = match &requested {
+        Some(ctx) => ctx.clone(),
+        None => state.k8s.get_current_context().await.unwrap_or_default(),
+    };
 
     // Check configured namespaces first
     let configured = load_configured_namespaces(&app, &context);
@@ -458,7 +524,7 @@ pub async fn get_namespaces(
     }
 
     // Try API discovery
-    match state.k8s.list_namespaces().await {
+    match state.k8s.list_namespaces(requested.as_deref()).await {
         Ok(namespaces) => Ok(NamespaceResult {
             namespaces,
             source: "auto".to_string(),
@@ -557,19 +623,18 @@ async fn sleep_cancellable(seconds: u64, stop_flag: &std::sync::atomic::AtomicBo
     !stop_flag.load(Ordering::Relaxed)
 }
 
-#[allow(clippy::too_many_arguments)]
 /// Renews the OIDC token ahead of expiry.
 ///
 /// Since the client reads the token per request, this no longer rebuilds or
 /// swaps anything — it exists so no user-facing request has to wait on a
-/// refresh, and it stops once this context is no longer the connected one.
+/// refresh, and it stops once this context is no longer connected.
 fn spawn_oidc_refresh_task(
-    connection: crate::k8s::client::SharedConnection,
+    clients: crate::k8s::client::ClientRegistry,
     oidc_state: Arc<OidcState>,
     oidc_config: crate::oidc::config::OidcExecConfig,
     context_name: String,
 ) {
-    let stop_flag = oidc_state.arm_refresh();
+    let stop_flag = oidc_state.arm_refresh(&context_name);
 
     tokio::spawn(async move {
         while let Some(expires_at) = oidc_state
@@ -589,15 +654,10 @@ fn spawn_oidc_refresh_task(
                 return;
             }
 
-            // Stop if the user switched away from or disconnected this context.
-            let still_current = connection
-                .read()
-                .await
-                .as_ref()
-                .and_then(|(_, ctx)| ctx.as_deref())
-                == Some(context_name.as_str());
-            if !still_current {
-                tracing::debug!("OIDC refresh loop stopping: context changed");
+            // Stop if the user disconnected this context. Switching the active
+            // cluster keeps it connected in the background, so keep refreshing.
+            if !refresh_target_is_current(&*clients.read().await, &context_name) {
+                tracing::debug!("OIDC refresh loop stopping: context disconnected");
                 break;
             }
 
@@ -637,9 +697,9 @@ fn spawn_oidc_refresh_task(
             // on a refresh.
             let _ = new_token;
 
-            // Stop once this context is no longer the connected one, or once a
+            // Stop once this context is no longer connected, or once a
             // reconnect to the same context armed a newer refresh task.
-            let guard = connection.read().await;
+            let guard = clients.read().await;
             if stop_flag.load(std::sync::atomic::Ordering::Relaxed)
                 || !refresh_target_is_current(&guard, &context_name)
             {
@@ -653,17 +713,20 @@ fn spawn_oidc_refresh_task(
     });
 }
 
-/// Whether a freshly refreshed client for `context_name` may still be
-/// installed into the stored connection slot.
+/// Whether the refresh loop for `context_name` should keep running.
 ///
-/// The background OIDC refresh can take seconds (token roundtrip + client
-/// build), during which the user may switch clusters. Evaluate this on the
-/// same write guard that performs the swap so check and commit are atomic: if
-/// the stored context no longer matches, a newer connection owns the slot and
-/// this stale client must be dropped instead of clobbering it. Generic over
-/// the client type so tests need not build a real kube client.
-fn refresh_target_is_current<C>(stored: &Option<(C, Option<String>)>, context_name: &str) -> bool {
-    stored.as_ref().and_then(|(_, ctx)| ctx.as_deref()) == Some(context_name)
+/// The background OIDC refresh can take seconds (token roundtrip), during
+/// which the user may disconnect the cluster. Evaluate this on the registry
+/// guard so check and decision are atomic: once the context has no live
+/// client, the loop must stop instead of refreshing for a dead connection.
+/// Switching the active cluster does not stop it - the context stays
+/// connected in the background. Generic over the client type so tests need
+/// not build a real kube client.
+fn refresh_target_is_current<C>(
+    clients: &std::collections::HashMap<String, C>,
+    context_name: &str,
+) -> bool {
+    clients.contains_key(context_name)
 }
 
 /// Check if kubeconfig exists
@@ -690,24 +753,25 @@ mod refresh_tests {
         assert_eq!(refresh_delay_secs(-120), 5);
     }
 
-    // Regression: a token refresh that completes after the user switched away
-    // must not overwrite the new connection's client. The guard keys off the
-    // context stored alongside the client in the one connection slot.
+    // A refresh loop must stop once its context is disconnected, but keep
+    // running while another cluster is merely active - the context is still
+    // connected in the background and its streams still need the token.
     #[test]
-    fn refresh_only_commits_when_context_still_matches() {
-        let connected: Option<((), Option<String>)> = Some(((), Some("cluster-a".to_string())));
-        assert!(refresh_target_is_current(&connected, "cluster-a"));
+    fn refresh_only_continues_while_context_is_connected() {
+        let mut clients = std::collections::HashMap::new();
+        clients.insert("cluster-a".to_string(), ());
+        assert!(refresh_target_is_current(&clients, "cluster-a"));
 
-        let switched: Option<((), Option<String>)> = Some(((), Some("cluster-b".to_string())));
+        clients.insert("cluster-b".to_string(), ());
         assert!(
-            !refresh_target_is_current(&switched, "cluster-a"),
-            "stale refresh must not commit after a cluster switch"
+            refresh_target_is_current(&clients, "cluster-a"),
+            "switching the active cluster must not stop the refresh"
         );
 
-        let disconnected: Option<((), Option<String>)> = None;
+        clients.remove("cluster-a");
         assert!(
-            !refresh_target_is_current(&disconnected, "cluster-a"),
-            "stale refresh must not commit after disconnect"
+            !refresh_target_is_current(&clients, "cluster-a"),
+            "refresh must stop after disconnect"
         );
     }
 
@@ -0,0 +1,436 @@
+use crate::error::KubeliError;
+use crate::k8s::AppState;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::api::{Api, DeleteParams, EvictParams, ListParams, Patch, PatchParams};
+use kube::core::Status;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, State};
+use tokio::time::Instant;
+
+/// Annotation the kubelet puts on the API mirror of a static pod
+const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+/// How long a drain waits for its pods unless the caller says otherwise
+const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(300);
+/// Pause before retrying an eviction a PodDisruptionBudget refused
+const EVICTION_RETRY: Duration = Duration::from_secs(5);
+/// Pause between checks whether an evicted pod is gone
+const DELETION_POLL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrainOptions {
+    /// Overrides each pod's terminationGracePeriodSeconds
+    pub grace_period_seconds: Option<u32>,
+    /// Give up on pods still running after this long; 300s when unset
+    pub timeout_seconds: Option<u64>,
+    /// Also evict pods no controller will recreate. They are gone for good.
+    pub evict_unmanaged: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DrainSummary {
+    pub evicted: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// Pods still on the node when the timeout hit
+    pub timed_out: usize,
+}
+
+/// Drain progress, emitted on `drain-{drain_id}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum DrainEvent {
+    Cordoned {
+        node: String,
+    },
+    Skipped {
+        namespace: String,
+        pod: String,
+        reason: String,
+    },
+    Evicting {
+        namespace: String,
+        pod: String,
+    },
+    /// A PodDisruptionBudget refused the eviction; it is retried
+    Blocked {
+        namespace: String,
+        pod: String,
+        reason: String,
+    },
+    Evicted {
+        namespace: String,
+        pod: String,
+    },
+    Failed {
+        namespace: String,
+        pod: String,
+        error: KubeliError,
+    },
+    TimedOut {
+        namespace: String,
+        pod: String,
+    },
+    Finished(DrainSummary),
+}
+
+enum PodOutcome {
+    Evicted,
+    Failed,
+    TimedOut,
+}
+
+async fn set_node_unschedulable(
+    state: State<'_, AppState>,
+    name: &str,
+    unschedulable: bool,
+    context: Option<&str>,
+) -> Result<(), KubeliError> {
+    let client = state.k8s.get_client_for(context).await?;
+    cordon(&client, name, unschedulable).await?;
+
+    tracing::info!("Set node {} unschedulable={}", name, unschedulable);
+    Ok(())
+}
+
+async fn cordon(client: &kube::Client, name: &str, unschedulable: bool) -> Result<(), KubeliError> {
+    let nodes: Api<Node> = Api::all(client.clone());
+    let patch = serde_json::json!({ "spec": { "unschedulable": unschedulable } });
+    nodes
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await?;
+    Ok(())
+}
+
+/// Mark a node unschedulable
+#[command]
+pub async fn cordon_node(
+    state: State<'_, AppState>,
+    name: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    set_node_unschedulable(state, &name, true, context.as_deref()).await
+}
+
+#[command]
+pub async fn uncordon_node(
+    state: State<'_, AppState>,
+    name: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    set_node_unschedulable(state, &name, false, context.as_deref()).await
+}
+
+fn evict_params(grace_period_seconds: Option<u32>) -> EvictParams {
+    EvictParams {
+        delete_options: Some(DeleteParams {
+            grace_period_seconds,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Why a drain leaves a pod alone, as `kubectl drain` does for DaemonSet
+/// pods (their controller ignores cordons) and mirror pods (the kubelet
+/// owns them). Unmanaged pods are only evicted when asked, or once finished.
+fn skip_reason(pod: &Pod, evict_unmanaged: bool) -> Option<String> {
+    if pod.annotations().contains_key(MIRROR_POD_ANNOTATION) {
+        return Some("Mirror pod of a static pod".to_string());
+    }
+    let finished = matches!(
+        pod.status
+            .as_ref()
+            .and_then(|status| status.phase.as_deref()),
+        Some("Succeeded" | "Failed")
+    );
+    let controller = pod
+        .owner_references()
+        .iter()
+        .find(|owner| owner.controller == Some(true));
+    match controller {
+        Some(owner) if owner.kind == "DaemonSet" => {
+            Some(format!("Managed by DaemonSet {}", owner.name))
+        }
+        // Finished pods lose nothing by going
+        None if !evict_unmanaged && !finished => {
+            Some("Not managed by a controller; it would not be recreated".to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The reason of a 429 from the Eviction API. Causes name the budget
+/// ("The disruption budget web needs 2 healthy pods and has 2 currently").
+fn eviction_block_reason(status: &Status) -> String {
+    let causes: Vec<&str> = status
+        .details
+        .iter()
+        .flat_map(|details| &details.causes)
+        .map(|cause| cause.message.as_str())
+        .filter(|message| !message.is_empty())
+        .collect();
+    if causes.is_empty() {
+        status.message.clone()
+    } else {
+        causes.join("; ")
+    }
+}
+
+/// Evict one pod, retrying while a PodDisruptionBudget blocks it, then
+/// wait until it is gone
+async fn drain_pod(
+    app: &AppHandle,
+    event_name: &str,
+    client: &kube::Client,
+    pod: &Pod,
+    params: &EvictParams,
+    deadline: Instant,
+) -> PodOutcome {
+    let namespace = pod.namespace().unwrap_or_default();
+    let name = pod.name_any();
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let emit = |event: DrainEvent| {
+        let _ = app.emit(event_name, event);
+    };
+
+    emit(DrainEvent::Evicting {
+        namespace: namespace.clone(),
+        pod: name.clone(),
+    });
+    let mut blocked_by = None;
+    loop {
+        match pods.evict(&name, params).await {
+            Ok(_) => break,
+            Err(kube::Error::Api(status)) if status.code == 404 => break,
+            Err(kube::Error::Api(status)) if status.code == 429 => {
+                let reason = eviction_block_reason(&status);
+                if blocked_by.as_ref() != Some(&reason) {
+                    emit(DrainEvent::Blocked {
+                        namespace: namespace.clone(),
+                        pod: name.clone(),
+                        reason: reason.clone(),
+                    });
+                    blocked_by = Some(reason);
+                }
+                if Instant::now() + EVICTION_RETRY >= deadline {
+                    emit(DrainEvent::TimedOut {
+                        namespace,
+                        pod: name,
+                    });
+                    return PodOutcome::TimedOut;
+                }
+                tokio::time::sleep(EVICTION_RETRY).await;
+            }
+            Err(err) => {
+                emit(DrainEvent::Failed {
+                    namespace,
+                    pod: name,
+                    error: err.into(),
+                });
+                return PodOutcome::Failed;
+            }
+        }
+    }
+
+    // A StatefulSet may recreate the pod under the same name; a new uid
+    // means the evicted one is gone
+    loop {
+        match pods.get_opt(&name).await {
+            Ok(None) => break,
+            Ok(Some(current)) if current.metadata.uid != pod.metadata.uid => break,
+            Ok(Some(_)) => {}
+            Err(e) => tracing::debug!("Checking evicted pod {}/{}: {}", namespace, name, e),
+        }
+        if Instant::now() + DELETION_POLL >= deadline {
+            emit(DrainEvent::TimedOut {
+                namespace,
+                pod: name,
+            });
+            return PodOutcome::TimedOut;
+        }
+        tokio::time::sleep(DELETION_POLL).await;
+    }
+
+    emit(DrainEvent::Evicted {
+        namespace,
+        pod: name,
+    });
+    PodOutcome::Evicted
+}
+
+/// Evict a single pod through the Eviction API, so PodDisruptionBudgets
+/// apply. A budget refusing it comes back as a RateLimited (429) error.
+#[command]
+pub async fn evict_pod(
+    state: State<'_, AppState>,
+    namespace: String,
+    name: String,
+    grace_period_seconds: Option<u32>,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+    pods.evict(&name, &evict_params(grace_period_seconds))
+        .await
+        .map_err(|err| match err {
+            kube::Error::Api(status) if status.code == 429 => {
+                let mut kubeli = KubeliError::from(kube::Error::Api(status.clone()));
+                kubeli.message = eviction_block_reason(&status);
+                kubeli
+            }
+            err => err.into(),
+        })?;
+
+    tracing::info!("Evicted pod {}/{}", namespace, name);
+    Ok(())
+}
+
+/// Cordon a node and evict its pods in parallel, skipping DaemonSet and
+/// mirror pods. Pods a PodDisruptionBudget protects are retried until the
+/// timeout. Progress is emitted on `drain-{drain_id}`.
+#[command]
+pub async fn drain_node(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    drain_id: String,
+    name: String,
+    options: Option<DrainOptions>,
+    context: Option<String>,
+) -> Result<DrainSummary, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let options = options.unwrap_or_default();
+    let event_name = format!("drain-{}", drain_id);
+    let deadline = Instant::now()
+        + options
+            .timeout_seconds
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_DRAIN_TIMEOUT);
+
+    cordon(&client, &name, true).await?;
+    let _ = app.emit(&event_name, DrainEvent::Cordoned { node: name.clone() });
+
+    let pods: Api<Pod> = Api::all(client.clone());
+    let on_node = pods
+        .list(&ListParams::default().fields(&format!("spec.nodeName={}", name)))
+        .await?;
+
+    let mut summary = DrainSummary::default();
+    let mut to_evict = Vec::new();
+    for pod in on_node.items {
+        match skip_reason(&pod, options.evict_unmanaged) {
+            Some(reason) => {
+                summary.skipped += 1;
+                let _ = app.emit(
+                    &event_name,
+                    DrainEvent::Skipped {
+                        namespace: pod.namespace().unwrap_or_default(),
+                        pod: pod.name_any(),
+                        reason,
+                    },
+                );
+            }
+            None => to_evict.push(pod),
+        }
+    }
+
+    let params = evict_params(options.grace_period_seconds);
+    let outcomes = futures::future::join_all(
+        to_evict
+            .iter()
+            .map(|pod| drain_pod(&app, &event_name, &client, pod, &params, deadline)),
+    )
+    .await;
+    for outcome in outcomes {
+        match outcome {
+            PodOutcome::Evicted => summary.evicted += 1,
+            PodOutcome::Failed => summary.failed += 1,
+            PodOutcome::TimedOut => summary.timed_out += 1,
+        }
+    }
+
+    let _ = app.emit(&event_name, DrainEvent::Finished(summary.clone()));
+    tracing::info!(
+        "Drained node {}: {} evicted, {} skipped, {} failed, {} timed out",
+        name,
+        summary.evicted,
+        summary.skipped,
+        summary.failed,
+        summary.timed_out
+    );
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+    use std::collections::BTreeMap;
+
+    fn pod(owner_kind: Option<&str>, annotations: &[(&str, &str)]) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some("p".into()),
+                annotations: Some(
+                    annotations
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect::<BTreeMap<_, _>>(),
+                ),
+                owner_references: owner_kind.map(|kind| {
+                    vec![OwnerReference {
+                        kind: kind.into(),
+                        name: "owner".into(),
+                        controller: Some(true),
+                        ..Default::default()
+                    }]
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn drain_skips_daemonset_mirror_and_unmanaged_pods() {
+        assert_eq!(skip_reason(&pod(Some("ReplicaSet"), &[]), false), None);
+        assert_eq!(
+            skip_reason(&pod(Some("DaemonSet"), &[]), true).as_deref(),
+            Some("Managed by DaemonSet owner")
+        );
+        assert!(skip_reason(&pod(None, &[(MIRROR_POD_ANNOTATION, "abc")]), true).is_some());
+        assert!(skip_reason(&pod(None, &[]), false).is_some());
+        assert_eq!(skip_reason(&pod(None, &[]), true), None);
+    }
+
+    #[test]
+    fn eviction_block_reason_names_the_budget() {
+        let status = Status::failure(
+            "Cannot evict pod as it would violate the pod's disruption budget.",
+            "TooManyRequests",
+        )
+        .with_code(429)
+        .with_details(kube::core::response::StatusDetails {
+            name: String::new(),
+            group: String::new(),
+            kind: String::new(),
+            uid: String::new(),
+            retry_after_seconds: 0,
+            causes: vec![kube::core::response::StatusCause {
+                reason: "DisruptionBudget".into(),
+                message: "The disruption budget web needs 2 healthy pods and has 2 currently"
+                    .into(),
+                field: String::new(),
+            }],
+        });
+        assert_eq!(
+            eviction_block_reason(&status),
+            "The disruption budget web needs 2 healthy pods and has 2 currently"
+        );
+
+        let bare = Status::failure("Cannot evict pod", "TooManyRequests").with_code(429);
+        assert_eq!(eviction_block_reason(&bare), "Cannot evict pod");
+    }
+}
@@ -0,0 +1,733 @@
+use crate::commands::watch::WatchManager;
+use crate::error::{ErrorKind, KubeliError};
+use crate::k8s::AppState;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Status;
+use kube::api::{Api, AttachParams, AttachedProcess};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, State};
+use tokio::io::AsyncReadExt;
+use tokio::task::JoinHandle;
+use tokio_util::io::SyncIoBridge;
+use tokio_util::sync::CancellationToken;
+
+/// How often a running transfer reports its progress
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+/// Bytes of the remote tar's stderr kept for the error message
+const MAX_STDERR: u64 = 4096;
+
+/// A copy between a local path and a path inside a container
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCopyOptions {
+    pub namespace: String,
+    pub pod_name: String,
+    /// The pod's first container when omitted
+    pub container: Option<String>,
+    pub local_path: String,
+    pub remote_path: String,
+    /// Cluster context of the pod; the active cluster when omitted
+    pub context: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum FileCopyEvent {
+    /// `total_bytes` is the size of the local files for an upload. A download
+    /// does not know its size up front.
+    Started {
+        total_bytes: Option<u64>,
+    },
+    Progress {
+        transferred_bytes: u64,
+        files: u64,
+        total_bytes: Option<u64>,
+    },
+    Finished {
+        transferred_bytes: u64,
+        files: u64,
+        destination: String,
+    },
+    Cancelled,
+    Failed(KubeliError),
+}
+
+#[derive(Default)]
+struct TransferProgress {
+    bytes: AtomicU64,
+    files: AtomicU64,
+}
+
+enum TransferEnd {
+    Done,
+    Cancelled,
+}
+
+fn cancelled_error() -> io::Error {
+    // Not `Interrupted`: io::copy retries those forever
+    io::Error::other("transfer cancelled")
+}
+
+/// Counts the bytes read through it and fails once the transfer is cancelled
+struct ProgressReader<R> {
+    inner: R,
+    progress: Arc<TransferProgress>,
+    token: Arc<CancellationToken>,
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.token.is_cancelled() {
+            return Err(cancelled_error());
+        }
+        let n = self.inner.read(buf)?;
+        self.progress.bytes.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+}
+
+/// Split a path inside the container into its parent directory and final
+/// component, ignoring trailing slashes. `None` for `/` and other paths
+/// without a name to copy.
+fn split_remote_path(path: &str) -> Option<(String, String)> {
+    let trimmed = path.trim_end_matches('/');
+    let (dir, name) = match trimmed.rsplit_once('/') {
+        Some(("", name)) => ("/", name),
+        Some((dir, name)) => (dir, name),
+        None => (".", trimmed),
+    };
+    if name.is_empty() || name == "." || name == ".." {
+        return None;
+    }
+    Some((dir.to_string(), name.to_string()))
+}
+
+fn join_remote_path(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+#[derive(Debug, PartialEq)]
+enum LocalEntryKind {
+    Dir,
+    File(u64),
+    Symlink,
+}
+
+#[derive(Debug)]
+struct LocalEntry {
+    path: PathBuf,
+    /// Path inside the archive, `/`-separated on every platform
+    name: String,
+    kind: LocalEntryKind,
+}
+
+/// `root` and everything below it, each directory before its contents and
+/// siblings in name order. Symlinks are archived as links rather than
+/// followed; sockets and fifos are left out.
+fn local_entries(root: &Path, name: &str) -> io::Result<Vec<LocalEntry>> {
+    let mut entries = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), name.to_string())];
+    while let Some((path, name)) = pending.pop() {
+        let meta = fs::symlink_metadata(&path)?;
+        let kind = if meta.file_type().is_symlink() {
+            LocalEntryKind::Symlink
+        } else if meta.is_dir() {
+            let mut children = fs::read_dir(&path)?
+                .map(|child| child.map(|child| child.file_name()))
+                .collect::<io::Result<Vec<_>>>()?;
+            children.sort();
+            // Reversed so the stack pops them in name order
+            for child in children.into_iter().rev() {
+                let child_name = format!("{}/{}", name, child.to_string_lossy());
+                pending.push((path.join(child), child_name));
+            }
+            LocalEntryKind::Dir
+        } else if meta.is_file() {
+            LocalEntryKind::File(meta.len())
+        } else {
+            continue;
+        };
+        entries.push(LocalEntry { path, name, kind });
+    }
+    Ok(entries)
+}
+
+fn write_archive<W: io::Write>(
+    writer: W,
+    entries: &[LocalEntry],
+    progress: &Arc<TransferProgress>,
+    token: &Arc<CancellationToken>,
+) -> io::Result<W> {
+    let mut builder = tar::Builder::new(writer);
+    builder.follow_symlinks(false);
+    for entry in entries {
+        if token.is_cancelled() {
+            return Err(cancelled_error());
+        }
+        match entry.kind {
+            LocalEntryKind::Dir => builder.append_dir(&entry.name, &entry.path)?,
+            LocalEntryKind::Symlink => builder.append_path_with_name(&entry.path, &entry.name)?,
+            LocalEntryKind::File(_) => {
+                let file = File::open(&entry.path)?;
+                let meta = file.metadata()?;
+                let mut header = tar::Header::new_gnu();
+                header.set_metadata(&meta);
+                // Capped at the header's size: a file growing while it is
+                // read must not corrupt the archive
+                let reader = ProgressReader {
+                    inner: file.take(meta.len()),
+                    progress: Arc::clone(progress),
+                    token: Arc::clone(token),
+                };
+                builder.append_data(&mut header, &entry.name, reader)?;
+                progress.files.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+    builder.into_inner()
+}
+
+/// Where an archive entry lands below the staging directory. Only plain
+/// relative paths under `name` qualify: the container controls what its tar
+/// writes, so `..`, absolute paths and stray entries are refused.
+fn archive_entry_path(path: &Path, name: &str) -> Option<PathBuf> {
+    let mut components = path
+        .components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .peekable();
+    if components.peek()?.as_os_str() != name {
+        return None;
+    }
+    let mut relative = PathBuf::new();
+    for component in components {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            _ => return None,
+        }
+    }
+    Some(relative)
+}
+
+/// Unpack the archive of `name` into `staging`, file by file. Links and
+/// special files are skipped.
+fn extract_archive<R: Read>(
+    reader: R,
+    staging: &Path,
+    name: &str,
+    progress: &TransferProgress,
+) -> io::Result<()> {
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let kind = entry.header().entry_type();
+        if !kind.is_file() && !kind.is_dir() {
+            tracing::warn!("Skipping {:?} entry in container archive", kind);
+            continue;
+        }
+        let path = entry.path()?.into_owned();
+        let Some(relative) = archive_entry_path(&path, name) else {
+            tracing::warn!("Skipping unexpected archive entry {}", path.display());
+            continue;
+        };
+        let dest = staging.join(relative);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        entry.unpack(&dest)?;
+        if kind.is_file() {
+            progress.files.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    // Drain the end-of-archive padding so the remote tar can exit
+    io::copy(&mut archive.into_inner(), &mut io::sink())?;
+    Ok(())
+}
+
+async fn target_container(
+    pods: &Api<Pod>,
+    options: &FileCopyOptions,
+) -> Result<String, KubeliError> {
+    if let Some(container) = &options.container {
+        return Ok(container.clone());
+    }
+    let pod = pods.get(&options.pod_name).await?;
+    pod.spec
+        .and_then(|spec| spec.containers.into_iter().next())
+        .map(|c| c.name)
+        .ok_or_else(|| {
+            KubeliError::new(
+                ErrorKind::NotFound,
+                format!("Pod {} has no containers", options.pod_name),
+            )
+        })
+}
+
+async fn remote_is_dir(
+    pods: &Api<Pod>,
+    pod_name: &str,
+    container: &str,
+    path: &str,
+) -> Result<bool, KubeliError> {
+    let params = AttachParams::default().container(container).stdout(false);
+    let mut attached = pods
+        .exec(pod_name, vec!["test", "-d", path], &params)
+        .await?;
+    let status = match attached.take_status() {
+        Some(status) => status.await,
+        None => None,
+    };
+    let _ = attached.join().await;
+    Ok(status.is_some_and(|s| s.status.as_deref() == Some("Success")))
+}
+
+fn remote_tar_result(status: Option<Status>, stderr: &str) -> Result<(), KubeliError> {
+    let status = status.ok_or_else(|| {
+        KubeliError::new(
+            ErrorKind::Network,
+            "Connection to the container closed before tar finished",
+        )
+    })?;
+    if status.status.as_deref() == Some("Success") {
+        return Ok(());
+    }
+    let message = status.message.unwrap_or_default();
+    if message.contains("executable file not found") {
+        let mut err = KubeliError::unknown("The container has no tar binary");
+        err.suggestions = vec![
+            "Start an ephemeral debug container and reach the files under /proc/1/root".to_string(),
+        ];
+        return Err(err);
+    }
+    let mut err = KubeliError::unknown(format!(
+        "tar failed in the container: {}",
+        if stderr.is_empty() {
+            message.as_str()
+        } else {
+            stderr
+        }
+    ));
+    err.detail = Some(message);
+    Err(err)
+}
+
+fn emit_progress(
+    app: &AppHandle,
+    event_name: &str,
+    progress: &TransferProgress,
+    total_bytes: Option<u64>,
+) {
+    let _ = app.emit(
+        event_name,
+        FileCopyEvent::Progress {
+            transferred_bytes: progress.bytes.load(Ordering::Relaxed),
+            files: progress.files.load(Ordering::Relaxed),
+            total_bytes,
+        },
+    );
+}
+
+/// Report progress while `job` moves the archive between disk and the exec
+/// stream, then check how the remote tar exited
+async fn drive_transfer(
+    app: &AppHandle,
+    event_name: &str,
+    mut attached: AttachedProcess,
+    mut job: JoinHandle<io::Result<()>>,
+    progress: &TransferProgress,
+    total_bytes: Option<u64>,
+    token: &CancellationToken,
+) -> Result<TransferEnd, KubeliError> {
+    let status = attached.take_status();
+    let stderr = attached.stderr().map(|mut stderr| {
+        tokio::spawn(async move {
+            let mut buf = Vec::new();
+            let _ = (&mut stderr).take(MAX_STDERR).read_to_end(&mut buf).await;
+            // Keep draining so a chatty tar never blocks on a full buffer
+            let _ = tokio::io::copy(&mut stderr, &mut tokio::io::sink()).await;
+            String::from_utf8_lossy(&buf).trim().to_string()
+        })
+    });
+
+    let mut ticker = tokio::time::interval(PROGRESS_INTERVAL);
+    let joined = loop {
+        tokio::select! {
+            joined = &mut job => break joined,
+            _ = token.cancelled() => {
+                // Dropping the connection fails the job's pending reads and
+                // writes, so it returns promptly
+                attached.abort();
+                let _ = job.await;
+                return Ok(TransferEnd::Cancelled);
+            }
+            _ = ticker.tick() => emit_progress(app, event_name, progress, total_bytes),
+        }
+    };
+    if token.is_cancelled() {
+        attached.abort();
+        return Ok(TransferEnd::Cancelled);
+    }
+
+    let status = match status {
+        Some(status) => status.await,
+        None => None,
+    };
+    let stderr = match stderr {
+        Some(task) => task.await.unwrap_or_default(),
+        None => String::new(),
+    };
+    let _ = attached.join().await;
+
+    // The remote side first: its stderr explains a local failure such as an
+    // archive that ended early
+    remote_tar_result(status, &stderr)?;
+    joined.map_err(|e| KubeliError::unknown(format!("Transfer task failed: {}", e)))??;
+    emit_progress(app, event_name, progress, total_bytes);
+    Ok(TransferEnd::Done)
+}
+
+fn emit_end(
+    app: &AppHandle,
+    event_name: &str,
+    end: Result<TransferEnd, KubeliError>,
+    progress: &TransferProgress,
+    destination: String,
+) {
+    let event = match end {
+        Ok(TransferEnd::Done) => FileCopyEvent::Finished {
+            transferred_bytes: progress.bytes.load(Ordering::Relaxed),
+            files: progress.files.load(Ordering::Relaxed),
+            destination,
+        },
+        Ok(TransferEnd::Cancelled) => FileCopyEvent::Cancelled,
+        Err(e) => FileCopyEvent::Failed(e),
+    };
+    let _ = app.emit(event_name, event);
+}
+
+/// Upload a local file or directory into a container, streamed as a tar
+/// archive into `tar -x` over exec the way `kubectl cp` does. An existing
+/// remote directory receives the local item under its own name; any other
+/// remote path is where it lands. Events arrive on `file-copy-{transfer_id}`
+/// and `stop_watch(transfer_id)` cancels.
+#[command]
+pub async fn copy_to_container(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    watch_manager: State<'_, Arc<WatchManager>>,
+    transfer_id: String,
+    options: FileCopyOptions,
+) -> Result<(), KubeliError> {
+    let local = PathBuf::from(&options.local_path);
+    let local_name = local
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| {
+            KubeliError::unknown(format!("{} has no name to copy", options.local_path))
+        })?;
+    if fs::symlink_metadata(&local).is_err() {
+        return Err(KubeliError::new(
+            ErrorKind::NotFound,
+            format!("{} does not exist", options.local_path),
+        ));
+    }
+
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+    let container = target_container(&pods, &options).await?;
+
+    let (dir, name) =
+        if remote_is_dir(&pods, &options.pod_name, &container, &options.remote_path).await? {
+            (options.remote_path.clone(), local_name)
+        } else {
+            split_remote_path(&options.remote_path).ok_or_else(|| {
+                KubeliError::unknown(format!("Cannot copy to {}", options.remote_path))
+            })?
+        };
+    let destination = join_remote_path(&dir, &name);
+
+    let entries = {
+        let name = name.clone();
+        tokio::task::spawn_blocking(move || local_entries(&local, &name))
+            .await
+            .map_err(|e| KubeliError::unknown(e.to_string()))??
+    };
+    let total_bytes: u64 = entries
+        .iter()
+        .map(|entry| match entry.kind {
+            LocalEntryKind::File(size) => size,
+            _ => 0,
+        })
+        .sum();
+
+    let params = AttachParams::default()
+        .container(&container)
+        .stdin(true)
+        .stdout(false);
+    let mut attached = pods
+        .exec(
+            &options.pod_name,
+            vec!["tar", "-xmf", "-", "-C", dir.as_str()],
+            &params,
+        )
+        .await?;
+    let stdin = attached
+        .stdin()
+        .ok_or_else(|| KubeliError::unknown("Exec did not provide a stdin stream"))?;
+
+    let manager = Arc::clone(watch_manager.inner());
+    let token = manager.add_session(transfer_id.clone(), context).await;
+    let progress = Arc::new(TransferProgress::default());
+    let event_name = format!("file-copy-{}", transfer_id);
+
+    let job = {
+        let progress = Arc::clone(&progress);
+        let token = Arc::clone(&token);
+        let stdin = SyncIoBridge::new(stdin);
+        tokio::task::spawn_blocking(move || {
+            let mut stdin = write_archive(stdin, &entries, &progress, &token)?;
+            // Closing stdin tells the remote tar the archive is complete
+            stdin.shutdown()
+        })
+    };
+
+    let _ = app.emit(
+        &event_name,
+        FileCopyEvent::Started {
+            total_bytes: Some(total_bytes),
+        },
+    );
+    tracing::info!(
+        "Copying {} to {}/{}:{}",
+        options.local_path,
+        options.namespace,
+        options.pod_name,
+        destination
+    );
+
+    tokio::spawn(async move {
+        let end = drive_transfer(
+            &app,
+            &event_name,
+            attached,
+            job,
+            &progress,
+            Some(total_bytes),
+            &token,
+        )
+        .await;
+        emit_end(&app, &event_name, end, &progress, destination);
+        manager.remove_session_if_owned(&transfer_id, &token).await;
+    });
+    Ok(())
+}
+
+/// Download a file or directory from a container, streamed from `tar -c`
+/// over exec and unpacked entry by entry. The result is staged next to the
+/// destination and only moved into place once the remote tar succeeded, so
+/// a failed or cancelled copy leaves nothing behind. An existing local
+/// directory receives the remote item under its own name. Events arrive on
+/// `file-copy-{transfer_id}` and `stop_watch(transfer_id)` cancels.
+#[command]
+pub async fn copy_from_container(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    watch_manager: State<'_, Arc<WatchManager>>,
+    transfer_id: String,
+    options: FileCopyOptions,
+) -> Result<(), KubeliError> {
+    let (dir, name) = split_remote_path(&options.remote_path)
+        .ok_or_else(|| KubeliError::unknown(format!("Cannot copy {}", options.remote_path)))?;
+
+    let local = PathBuf::from(&options.local_path);
+    let target = if local.is_dir() {
+        local.join(&name)
+    } else {
+        local
+    };
+    if target.is_dir() {
+        return Err(KubeliError::new(
+            ErrorKind::Conflict,
+            format!("{} already exists", target.display()),
+        ));
+    }
+    let parent = target
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+        .to_path_buf();
+    if !parent.is_dir() {
+        return Err(KubeliError::new(
+            ErrorKind::NotFound,
+            format!("{} does not exist", parent.display()),
+        ));
+    }
+
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+    let container = target_container(&pods, &options).await?;
+
+    let params = AttachParams::default().container(&container);
+    let mut attached = pods
+        .exec(
+            &options.pod_name,
+            vec!["tar", "cf", "-", "-C", dir.as_str(), name.as_str()],
+            &params,
+        )
+        .await?;
+    let stdout = attached
+        .stdout()
+        .ok_or_else(|| KubeliError::unknown("Exec did not provide a stdout stream"))?;
+
+    let manager = Arc::clone(watch_manager.inner());
+    let token = manager.add_session(transfer_id.clone(), context).await;
+    let progress = Arc::new(TransferProgress::default());
+    let event_name = format!("file-copy-{}", transfer_id);
+    let staging = parent.join(format!(".kubeli-copy-{}", uuid::Uuid::new_v4()));
+
+    let job = {
+        let reader = ProgressReader {
+            inner: SyncIoBridge::new(stdout),
+            progress: Arc::clone(&progress),
+            token: Arc::clone(&token),
+        };
+        let progress = Arc::clone(&progress);
+        let staging = staging.clone();
+        let name = name.clone();
+        tokio::task::spawn_blocking(move || {
+            fs::create_dir(&staging)?;
+            extract_archive(reader, &staging, &name, &progress)
+        })
+    };
+
+    let _ = app.emit(&event_name, FileCopyEvent::Started { total_bytes: None });
+    tracing::info!(
+        "Copying {}/{}:{} to {}",
+        options.namespace,
+        options.pod_name,
+        options.remote_path,
+        target.display()
+    );
+
+    tokio::spawn(async move {
+        let mut end =
+            drive_transfer(&app, &event_name, attached, job, &progress, None, &token).await;
+        if let Ok(TransferEnd::Done) = end {
+            if let Err(e) = tokio::fs::rename(staging.join(&name), &target).await {
+                end = Err(KubeliError::unknown(format!(
+                    "Failed to move download to {}: {}",
+                    target.display(),
+                    e
+                )));
+            }
+        }
+        let _ = tokio::fs::remove_dir_all(&staging).await;
+        emit_end(
+            &app,
+            &event_name,
+            end,
+            &progress,
+            target.display().to_string(),
+        );
+        manager.remove_session_if_owned(&transfer_id, &token).await;
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_remote_paths() {
+        assert_eq!(
+            split_remote_path("/var/log/app/"),
+            Some(("/var/log".to_string(), "app".to_string()))
+        );
+        assert_eq!(
+            split_remote_path("/heap.hprof"),
+            Some(("/".to_string(), "heap.hprof".to_string()))
+        );
+        assert_eq!(
+            split_remote_path("config.yaml"),
+            Some((".".to_string(), "config.yaml".to_string()))
+        );
+        assert_eq!(split_remote_path("/"), None);
+        assert_eq!(split_remote_path("/tmp/.."), None);
+        assert_eq!(join_remote_path("/", "heap.hprof"), "/heap.hprof");
+    }
+
+    #[test]
+    fn refuses_entries_outside_the_copied_path() {
+        assert_eq!(
+            archive_entry_path(Path::new("./logs/app.log"), "logs"),
+            Some(PathBuf::from("logs/app.log"))
+        );
+        assert_eq!(
+            archive_entry_path(Path::new("logs/../../etc"), "logs"),
+            None
+        );
+        assert_eq!(archive_entry_path(Path::new("/logs/app.log"), "logs"), None);
+        assert_eq!(archive_entry_path(Path::new("other/app.log"), "logs"), None);
+    }
+
+    #[test]
+    fn archive_round_trips_a_directory() {
+        let src = tempfile::tempdir().unwrap();
+        let root = src.path().join("conf");
+        fs::create_dir_all(root.join("nested")).unwrap();
+        fs::write(root.join("a.yaml"), "a: 1\n").unwrap();
+        fs::write(root.join("nested/b.yaml"), "b: 22\n").unwrap();
+
+        let entries = local_entries(&root, "settings").unwrap();
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "settings",
+                "settings/a.yaml",
+                "settings/nested",
+                "settings/nested/b.yaml"
+            ]
+        );
+
+        let token = Arc::new(CancellationToken::new());
+        let progress = Arc::new(TransferProgress::default());
+        let archive = write_archive(Vec::new(), &entries, &progress, &token).unwrap();
+        assert_eq!(progress.bytes.load(Ordering::Relaxed), 11);
+        assert_eq!(progress.files.load(Ordering::Relaxed), 2);
+
+        let dest = tempfile::tempdir().unwrap();
+        let unpacked = TransferProgress::default();
+        extract_archive(archive.as_slice(), dest.path(), "settings", &unpacked).unwrap();
+        assert_eq!(
+            fs::read_to_string(dest.path().join("settings/nested/b.yaml")).unwrap(),
+            "b: 22\n"
+        );
+        assert_eq!(unpacked.files.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn cancelled_archive_stops_writing() {
+        let src = tempfile::tempdir().unwrap();
+        fs::write(src.path().join("big.bin"), vec![0u8; 1024]).unwrap();
+        let entries = local_entries(&src.path().join("big.bin"), "big.bin").unwrap();
+
+        let token = Arc::new(CancellationToken::new());
+        token.cancel();
+        let progress = Arc::new(TransferProgress::default());
+        assert!(write_archive(Vec::new(), &entries, &progress, &token).is_err());
+    }
+}
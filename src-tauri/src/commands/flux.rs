@@ -38,8 +38,9 @@ pub struct FluxKustomizationInfo {
 pub async fn list_flux_kustomizations(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<FluxKustomizationInfo>, String> {
-    let client = match state.k8s.get_client().await {
+    let client = match state.k8s.get_client_for(context.as_deref()).await {
         Ok(c) => c,
         Err(_) => return Ok(Vec::new()),
     };
@@ -407,8 +408,13 @@ pub async fn reconcile_flux_kustomization(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let token = chrono::Utc::now().to_rfc3339();
     request_reconcile(client, &kustomization_ar(), &namespace, &name, &token, None).await?;
     Ok(token)
@@ -421,8 +427,13 @@ pub async fn reconcile_flux_kustomization_with_source(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let ar = kustomization_ar();
     let api: Api<DynamicObject> = Api::namespaced_with(client.clone(), &namespace, &ar);
     let obj = api
@@ -454,8 +465,13 @@ pub async fn suspend_flux_kustomization(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     merge_patch(
         client,
         &kustomization_ar(),
@@ -473,8 +489,13 @@ pub async fn resume_flux_kustomization(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     merge_patch(
         client,
         &kustomization_ar(),
@@ -493,8 +514,13 @@ pub async fn reconcile_flux_helmrelease(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let token = chrono::Utc::now().to_rfc3339();
     request_reconcile(client, &helmrelease_ar(), &namespace, &name, &token, None).await?;
     Ok(token)
@@ -506,8 +532,13 @@ pub async fn reconcile_flux_helmrelease_with_source(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let ar = helmrelease_ar();
     let api: Api<DynamicObject> = Api::namespaced_with(client.clone(), &namespace, &ar);
     let obj = api
@@ -550,8 +581,13 @@ pub async fn force_flux_helmrelease(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let token = chrono::Utc::now().to_rfc3339();
     request_reconcile(
         client,
@@ -572,8 +608,13 @@ pub async fn reset_flux_helmrelease(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let token = chrono::Utc::now().to_rfc3339();
     request_reconcile(
         client,
@@ -593,8 +634,13 @@ pub async fn suspend_flux_helmrelease(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     merge_patch(
         client,
         &helmrelease_ar(),
@@ -612,8 +658,13 @@ pub async fn resume_flux_helmrelease(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     merge_patch(
         client,
         &helmrelease_ar(),
@@ -652,8 +703,13 @@ pub async fn wait_flux_reconcile(
     name: String,
     namespace: String,
     token: String,
+    context: Option<String>,
 ) -> Result<FluxReconcileResult, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let ar = match kind.as_str() {
         "kustomization" => kustomization_ar(),
         "helmrelease" => helmrelease_ar(),
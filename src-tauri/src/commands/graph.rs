@@ -1,19 +1,51 @@
+use crate::commands::watch::WatchManager;
+use crate::error::KubeliError;
+use crate::k8s::cache::{CacheSubscription, ResourceCache};
 use crate::k8s::AppState;
-use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
-use k8s_openapi::api::core::v1::{Namespace, Pod};
+use futures::stream::{BoxStream, StreamExt};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, Namespace, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    ServiceAccount,
+};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use kube::api::{Api, ListParams};
-use kube::ResourceExt;
+use kube::core::PartialObjectMeta;
+use kube::runtime::reflector::{self, Store};
+use kube::runtime::watcher::{watcher, Config, Event};
+use kube::runtime::WatchStreamExt;
+use kube::{Resource, ResourceExt};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
-use tauri::{command, State};
-
-/// Node types for the resource diagram (simplified: only core workload hierarchy)
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, State};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+/// Node types for the resource diagram
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum NodeType {
     Namespace,
     Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Job,
+    CronJob,
     Pod,
+    Service,
+    Ingress,
+    EndpointSlice,
+    ConfigMap,
+    Secret,
+    PersistentVolumeClaim,
+    PersistentVolume,
+    ServiceAccount,
 }
 
 /// Node status for visual indicators
@@ -32,10 +64,27 @@ pub enum NodeStatus {
 pub enum EdgeType {
     Owns,     // OwnerReference / parent-child relationship (solid line)
     Contains, // Namespace contains resources
+    Routes,   // Ingress -> Service -> Pod traffic (selector, backend, endpoints)
+    Mounts,   // Pod consumes a ConfigMap/Secret/PVC (volume, env, envFrom)
+    Binds,    // PVC bound to a PersistentVolume
+    Uses,     // ServiceAccount, image pull secret, ingress TLS secret
+}
+
+impl EdgeType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EdgeType::Owns => "owns",
+            EdgeType::Contains => "contains",
+            EdgeType::Routes => "routes",
+            EdgeType::Mounts => "mounts",
+            EdgeType::Binds => "binds",
+            EdgeType::Uses => "uses",
+        }
+    }
 }
 
 /// A node in the resource graph
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GraphNode {
     pub id: String,
     pub uid: String,
@@ -49,12 +98,12 @@ pub struct GraphNode {
     pub ready_status: Option<String>, // e.g., "2/3" for pods
     pub replicas: Option<String>,     // e.g., "3/3" for deployments
     // Sub-flow properties
-    pub is_group: bool, // True for namespace nodes (they contain children)
+    pub is_group: bool, // True for namespaces and the workload controllers grouping their pods
     pub child_count: Option<usize>, // Number of direct children for group sizing
 }
 
 /// An edge connecting two nodes
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GraphEdge {
     pub id: String,
     pub source: String, // Node ID
@@ -77,8 +126,35 @@ fn btree_to_hashmap(btree: Option<BTreeMap<String, String>>) -> HashMap<String,
     btree.map(|b| b.into_iter().collect()).unwrap_or_default()
 }
 
+/// Node id: `<prefix>-<namespace>-<name>`, or `<prefix>-<name>` for
+/// cluster-scoped resources
+pub(crate) fn node_id(node_type: &NodeType, namespace: Option<&str>, name: &str) -> String {
+    let prefix = match node_type {
+        NodeType::Namespace => "ns",
+        NodeType::Deployment => "deploy",
+        NodeType::StatefulSet => "sts",
+        NodeType::DaemonSet => "ds",
+        NodeType::ReplicaSet => "rs",
+        NodeType::Job => "job",
+        NodeType::CronJob => "cronjob",
+        NodeType::Pod => "pod",
+        NodeType::Service => "svc",
+        NodeType::Ingress => "ing",
+        NodeType::EndpointSlice => "eps",
+        NodeType::ConfigMap => "cm",
+        NodeType::Secret => "secret",
+        NodeType::PersistentVolumeClaim => "pvc",
+        NodeType::PersistentVolume => "pv",
+        NodeType::ServiceAccount => "sa",
+    };
+    match namespace {
+        Some(ns) => format!("{}-{}-{}", prefix, ns, name),
+        None => format!("{}-{}", prefix, name),
+    }
+}
+
 /// Determine pod status
-fn get_pod_status(pod: &Pod) -> NodeStatus {
+pub(crate) fn get_pod_status(pod: &Pod) -> NodeStatus {
     let status = pod.status.as_ref();
     let phase = status.and_then(|s| s.phase.as_ref()).map(|s| s.as_str());
 
@@ -102,8 +178,21 @@ fn get_pod_status(pod: &Pod) -> NodeStatus {
     }
 }
 
+/// Status of a replicated workload from its ready vs desired replicas
+fn replica_status(ready: i32, desired: i32) -> NodeStatus {
+    if ready == desired && desired > 0 {
+        NodeStatus::Healthy
+    } else if ready > 0 {
+        NodeStatus::Warning
+    } else if desired > 0 {
+        NodeStatus::Error
+    } else {
+        NodeStatus::Unknown
+    }
+}
+
 /// Determine deployment status
-fn get_deployment_status(deployment: &Deployment) -> NodeStatus {
+pub(crate) fn get_deployment_status(deployment: &Deployment) -> NodeStatus {
     let status = deployment.status.as_ref();
     let ready = status.and_then(|s| s.ready_replicas).unwrap_or(0);
     let desired = deployment
@@ -111,18 +200,657 @@ fn get_deployment_status(deployment: &Deployment) -> NodeStatus {
         .as_ref()
         .and_then(|s| s.replicas)
         .unwrap_or(0);
+    replica_status(ready, desired)
+}
 
-    if ready == desired && desired > 0 {
+/// Determine job status from its terminal conditions, then its pod counts
+fn get_job_status(job: &Job) -> NodeStatus {
+    let Some(status) = job.status.as_ref() else {
+        return NodeStatus::Unknown;
+    };
+    let has_condition = |type_: &str| {
+        status
+            .conditions
+            .iter()
+            .flatten()
+            .any(|c| c.type_ == type_ && c.status == "True")
+    };
+
+    if has_condition("Failed") {
+        NodeStatus::Error
+    } else if has_condition("Complete") {
         NodeStatus::Healthy
-    } else if ready > 0 {
+    } else if status.failed.unwrap_or(0) > 0 {
         NodeStatus::Warning
-    } else if desired > 0 {
-        NodeStatus::Error
+    } else if status.active.unwrap_or(0) > 0 {
+        NodeStatus::Healthy
     } else {
         NodeStatus::Unknown
     }
 }
 
+/// Map a PVC/PV phase to a status
+fn get_volume_status(phase: Option<&str>) -> NodeStatus {
+    match phase {
+        Some("Bound") | Some("Available") => NodeStatus::Healthy,
+        Some("Pending") | Some("Released") => NodeStatus::Warning,
+        Some("Lost") | Some("Failed") => NodeStatus::Error,
+        _ => NodeStatus::Unknown,
+    }
+}
+
+/// Something a pod refers to by name in its own namespace
+struct PodReference {
+    node_type: NodeType,
+    name: String,
+    edge_type: EdgeType,
+    label: &'static str,
+}
+
+fn pod_reference(
+    node_type: NodeType,
+    name: &str,
+    edge_type: EdgeType,
+    label: &'static str,
+) -> PodReference {
+    PodReference {
+        node_type,
+        name: name.to_string(),
+        edge_type,
+        label,
+    }
+}
+
+/// ConfigMaps, Secrets, PVCs and the ServiceAccount a pod depends on
+fn pod_references(pod: &Pod) -> Vec<PodReference> {
+    let mut refs = Vec::new();
+    let Some(spec) = pod.spec.as_ref() else {
+        return refs;
+    };
+
+    for volume in spec.volumes.iter().flatten() {
+        if let Some(cm) = &volume.config_map {
+            refs.push(pod_reference(
+                NodeType::ConfigMap,
+                &cm.name,
+                EdgeType::Mounts,
+                "volume",
+            ));
+        }
+        if let Some(name) = volume
+            .secret
+            .as_ref()
+            .and_then(|s| s.secret_name.as_deref())
+        {
+            refs.push(pod_reference(
+                NodeType::Secret,
+                name,
+                EdgeType::Mounts,
+                "volume",
+            ));
+        }
+        if let Some(pvc) = &volume.persistent_volume_claim {
+            refs.push(pod_reference(
+                NodeType::PersistentVolumeClaim,
+                &pvc.claim_name,
+                EdgeType::Mounts,
+                "volume",
+            ));
+        }
+        for source in volume
+            .projected
+            .iter()
+            .filter_map(|p| p.sources.as_ref())
+            .flatten()
+        {
+            if let Some(cm) = &source.config_map {
+                refs.push(pod_reference(
+                    NodeType::ConfigMap,
+                    &cm.name,
+                    EdgeType::Mounts,
+                    "volume",
+                ));
+            }
+            if let Some(secret) = &source.secret {
+                refs.push(pod_reference(
+                    NodeType::Secret,
+                    &secret.name,
+                    EdgeType::Mounts,
+                    "volume",
+                ));
+            }
+        }
+    }
+
+    for container in spec
+        .init_containers
+        .iter()
+        .flatten()
+        .chain(&spec.containers)
+    {
+        for env_from in container.env_from.iter().flatten() {
+            if let Some(cm) = &env_from.config_map_ref {
+                refs.push(pod_reference(
+                    NodeType::ConfigMap,
+                    &cm.name,
+                    EdgeType::Mounts,
+                    "envFrom",
+                ));
+            }
+            if let Some(secret) = &env_from.secret_ref {
+                refs.push(pod_reference(
+                    NodeType::Secret,
+                    &secret.name,
+                    EdgeType::Mounts,
+                    "envFrom",
+                ));
+            }
+        }
+        for value_from in container
+            .env
+            .iter()
+            .flatten()
+            .filter_map(|e| e.value_from.as_ref())
+        {
+            if let Some(key_ref) = &value_from.config_map_key_ref {
+                refs.push(pod_reference(
+                    NodeType::ConfigMap,
+                    &key_ref.name,
+                    EdgeType::Mounts,
+                    "env",
+                ));
+            }
+            if let Some(key_ref) = &value_from.secret_key_ref {
+                refs.push(pod_reference(
+                    NodeType::Secret,
+                    &key_ref.name,
+                    EdgeType::Mounts,
+                    "env",
+                ));
+            }
+        }
+    }
+
+    for pull_secret in spec.image_pull_secrets.iter().flatten() {
+        refs.push(pod_reference(
+            NodeType::Secret,
+            &pull_secret.name,
+            EdgeType::Uses,
+            "imagePullSecret",
+        ));
+    }
+    if let Some(sa) = spec.service_account_name.as_deref() {
+        refs.push(pod_reference(
+            NodeType::ServiceAccount,
+            sa,
+            EdgeType::Uses,
+            "serviceAccount",
+        ));
+    }
+
+    refs
+}
+
+/// Services an ingress sends traffic to, labelled with host and path
+fn ingress_backends(ingress: &Ingress) -> Vec<(String, Option<String>)> {
+    let mut backends = Vec::new();
+    let Some(spec) = ingress.spec.as_ref() else {
+        return backends;
+    };
+
+    if let Some(service) = spec
+        .default_backend
+        .as_ref()
+        .and_then(|b| b.service.as_ref())
+    {
+        backends.push((service.name.clone(), Some("default".to_string())));
+    }
+    for rule in spec.rules.iter().flatten() {
+        for path in rule.http.iter().flat_map(|http| &http.paths) {
+            if let Some(service) = &path.backend.service {
+                let label = format!(
+                    "{}{}",
+                    rule.host.as_deref().unwrap_or("*"),
+                    path.path.as_deref().unwrap_or("/")
+                );
+                backends.push((service.name.clone(), Some(label)));
+            }
+        }
+    }
+    backends
+}
+
+/// Node for an object, placed inside its namespace group when namespaced
+fn new_node(meta: &ObjectMeta, node_type: NodeType, status: NodeStatus) -> GraphNode {
+    let name = meta.name.clone().unwrap_or_default();
+    let namespace = meta.namespace.clone();
+    GraphNode {
+        id: node_id(&node_type, namespace.as_deref(), &name),
+        uid: meta.uid.clone().unwrap_or_default(),
+        parent_id: namespace
+            .as_deref()
+            .map(|ns| node_id(&NodeType::Namespace, None, ns)),
+        name,
+        namespace,
+        node_type,
+        status,
+        labels: btree_to_hashmap(meta.labels.clone()),
+        ready_status: None,
+        replicas: None,
+        is_group: false,
+        child_count: None,
+    }
+}
+
+/// Collects nodes and edges. Owner edges are resolved once every node is
+/// known, and edges whose other end never made it into the graph (filtered
+/// namespace, RBAC, unreferenced config) are dropped.
+#[derive(Default)]
+struct GraphBuilder {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    edge_ids: HashSet<String>,
+    uid_to_id: HashMap<String, String>,
+    /// (owned node id, owner uid)
+    owned_by: Vec<(String, String)>,
+}
+
+impl GraphBuilder {
+    fn add(&mut self, node: GraphNode, meta: &ObjectMeta) -> String {
+        let id = node.id.clone();
+        if !node.uid.is_empty() {
+            self.uid_to_id.insert(node.uid.clone(), id.clone());
+        }
+        for owner in meta.owner_references.iter().flatten() {
+            self.owned_by.push((id.clone(), owner.uid.clone()));
+        }
+        self.nodes.push(node);
+        id
+    }
+
+    fn edge(&mut self, source: &str, target: &str, edge_type: EdgeType, label: Option<String>) {
+        let id = format!("{}-{}-{}", edge_type.as_str(), source, target);
+        if self.edge_ids.insert(id.clone()) {
+            self.edges.push(GraphEdge {
+                id,
+                source: source.to_string(),
+                target: target.to_string(),
+                edge_type,
+                label,
+            });
+        }
+    }
+
+    fn finish(mut self) -> (Vec<GraphNode>, Vec<GraphEdge>) {
+        for (id, owner_uid) in std::mem::take(&mut self.owned_by) {
+            if let Some(owner_id) = self.uid_to_id.get(&owner_uid).cloned() {
+                self.edge(&owner_id, &id, EdgeType::Owns, None);
+            }
+        }
+
+        let ids: HashSet<&str> = self.nodes.iter().map(|n| n.id.as_str()).collect();
+        self.edges
+            .retain(|e| ids.contains(e.source.as_str()) && ids.contains(e.target.as_str()));
+
+        let mut child_counts: HashMap<String, usize> = HashMap::new();
+        for parent_id in self.nodes.iter().filter_map(|n| n.parent_id.clone()) {
+            *child_counts.entry(parent_id).or_insert(0) += 1;
+        }
+        for node in &mut self.nodes {
+            if node.is_group {
+                node.child_count = Some(child_counts.get(&node.id).copied().unwrap_or(0));
+            }
+        }
+
+        (self.nodes, self.edges)
+    }
+}
+
+/// Everything the resource graph is built from. ConfigMaps, Secrets and
+/// ServiceAccounts are metadata only; their contents never leave the cluster.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct GraphResources {
+    pub namespaces: Vec<Namespace>,
+    pub deployments: Vec<Deployment>,
+    pub statefulsets: Vec<StatefulSet>,
+    pub daemonsets: Vec<DaemonSet>,
+    pub replicasets: Vec<ReplicaSet>,
+    pub jobs: Vec<Job>,
+    pub cronjobs: Vec<CronJob>,
+    pub pods: Vec<Pod>,
+    pub services: Vec<Service>,
+    pub ingresses: Vec<Ingress>,
+    pub endpoint_slices: Vec<EndpointSlice>,
+    pub config_maps: Vec<ObjectMeta>,
+    pub secrets: Vec<ObjectMeta>,
+    pub service_accounts: Vec<ObjectMeta>,
+    pub pvcs: Vec<PersistentVolumeClaim>,
+    pub pvs: Vec<PersistentVolume>,
+}
+
+/// Build the nested graph: namespaces group everything in them, workload
+/// controllers (Deployment, StatefulSet, DaemonSet, Job) group their pods.
+/// ConfigMaps, Secrets, ServiceAccounts and PVs only appear when something
+/// in the graph refers to them.
+pub(crate) fn build_graph(resources: &GraphResources) -> (Vec<GraphNode>, Vec<GraphEdge>) {
+    let mut graph = GraphBuilder::default();
+    // Owner uid -> group node that pods of that owner are placed in
+    let mut pod_groups: HashMap<String, String> = HashMap::new();
+
+    for ns in &resources.namespaces {
+        let mut node = new_node(&ns.metadata, NodeType::Namespace, NodeStatus::Healthy);
+        node.is_group = true;
+        graph.add(node, &ns.metadata);
+    }
+
+    for cronjob in &resources.cronjobs {
+        let suspended = cronjob
+            .spec
+            .as_ref()
+            .and_then(|s| s.suspend)
+            .unwrap_or(false);
+        let status = if suspended {
+            NodeStatus::Unknown
+        } else {
+            NodeStatus::Healthy
+        };
+        let mut node = new_node(&cronjob.metadata, NodeType::CronJob, status);
+        let active = cronjob
+            .status
+            .as_ref()
+            .and_then(|s| s.active.as_ref())
+            .map_or(0, |a| a.len());
+        node.ready_status = Some(format!("{} active", active));
+        graph.add(node, &cronjob.metadata);
+    }
+
+    // Workload controllers are groups containing their pods
+    let mut add_workload = |graph: &mut GraphBuilder,
+                            meta: &ObjectMeta,
+                            node_type: NodeType,
+                            status: NodeStatus,
+                            replicas: String| {
+        let mut node = new_node(meta, node_type, status);
+        node.replicas = Some(replicas);
+        node.is_group = true;
+        let id = graph.add(node, meta);
+        if let Some(uid) = &meta.uid {
+            pod_groups.insert(uid.clone(), id);
+        }
+    };
+    for deployment in &resources.deployments {
+        let ready = deployment
+            .status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0);
+        let desired = deployment
+            .spec
+            .as_ref()
+            .and_then(|s| s.replicas)
+            .unwrap_or(0);
+        add_workload(
+            &mut graph,
+            &deployment.metadata,
+            NodeType::Deployment,
+            get_deployment_status(deployment),
+            format!("{}/{}", ready, desired),
+        );
+    }
+    for sts in &resources.statefulsets {
+        let ready = sts
+            .status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0);
+        let desired = sts.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+        add_workload(
+            &mut graph,
+            &sts.metadata,
+            NodeType::StatefulSet,
+            replica_status(ready, desired),
+            format!("{}/{}", ready, desired),
+        );
+    }
+    for ds in &resources.daemonsets {
+        let (ready, desired) = ds
+            .status
+            .as_ref()
+            .map_or((0, 0), |s| (s.number_ready, s.desired_number_scheduled));
+        add_workload(
+            &mut graph,
+            &ds.metadata,
+            NodeType::DaemonSet,
+            replica_status(ready, desired),
+            format!("{}/{}", ready, desired),
+        );
+    }
+    for job in &resources.jobs {
+        let succeeded = job.status.as_ref().and_then(|s| s.succeeded).unwrap_or(0);
+        let completions = job.spec.as_ref().and_then(|s| s.completions).unwrap_or(1);
+        add_workload(
+            &mut graph,
+            &job.metadata,
+            NodeType::Job,
+            get_job_status(job),
+            format!("{}/{}", succeeded, completions),
+        );
+    }
+
+    // ReplicaSets sit inside their Deployment's group, and their pods join
+    // that group too. Scaled-down ReplicaSets kept around as rollout history
+    // are left out.
+    for rs in &resources.replicasets {
+        let owner_group = rs
+            .metadata
+            .owner_references
+            .iter()
+            .flatten()
+            .find(|o| o.kind == "Deployment")
+            .and_then(|o| pod_groups.get(&o.uid))
+            .cloned();
+        let current = rs.status.as_ref().map_or(0, |s| s.replicas);
+        let desired = rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0);
+        if owner_group.is_some() && current == 0 && desired == 0 {
+            continue;
+        }
+        let ready = rs
+            .status
+            .as_ref()
+            .and_then(|s| s.ready_replicas)
+            .unwrap_or(0);
+
+        let mut node = new_node(
+            &rs.metadata,
+            NodeType::ReplicaSet,
+            replica_status(ready, desired),
+        );
+        node.replicas = Some(format!("{}/{}", ready, desired));
+        if let Some(group) = &owner_group {
+            node.parent_id = Some(group.clone());
+        }
+        graph.add(node, &rs.metadata);
+        if let (Some(uid), Some(group)) = (&rs.metadata.uid, owner_group) {
+            pod_groups.insert(uid.clone(), group);
+        }
+    }
+
+    // Things pods and ingresses refer to; config objects are only shown
+    // when they are in this set
+    let mut referenced: HashSet<String> = HashSet::new();
+    let mut pods_by_ns: HashMap<&str, Vec<(String, &BTreeMap<String, String>)>> = HashMap::new();
+    let no_labels = BTreeMap::new();
+
+    for pod in &resources.pods {
+        let mut node = new_node(&pod.metadata, NodeType::Pod, get_pod_status(pod));
+        node.ready_status = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.container_statuses.as_ref())
+            .map(|statuses| {
+                let ready = statuses.iter().filter(|cs| cs.ready).count();
+                format!("{}/{}", ready, statuses.len())
+            });
+        if let Some(group) = pod
+            .metadata
+            .owner_references
+            .iter()
+            .flatten()
+            .find_map(|o| pod_groups.get(&o.uid))
+        {
+            node.parent_id = Some(group.clone());
+        }
+        let ns = node.namespace.clone().unwrap_or_default();
+        let id = graph.add(node, &pod.metadata);
+
+        for reference in pod_references(pod) {
+            let target = node_id(&reference.node_type, Some(ns.as_str()), &reference.name);
+            graph.edge(
+                &id,
+                &target,
+                reference.edge_type,
+                Some(reference.label.to_string()),
+            );
+            referenced.insert(target);
+        }
+        pods_by_ns
+            .entry(pod.metadata.namespace.as_deref().unwrap_or_default())
+            .or_default()
+            .push((id, pod.metadata.labels.as_ref().unwrap_or(&no_labels)));
+    }
+
+    // Services route to the pods their selector matches
+    for svc in &resources.services {
+        let ns = svc.metadata.namespace.as_deref().unwrap_or_default();
+        let selector = svc
+            .spec
+            .as_ref()
+            .and_then(|s| s.selector.as_ref())
+            .filter(|s| !s.is_empty());
+        let targets: Vec<&String> = match selector {
+            Some(selector) => pods_by_ns
+                .get(ns)
+                .into_iter()
+                .flatten()
+                .filter(|(_, labels)| selector.iter().all(|(k, v)| labels.get(k) == Some(v)))
+                .map(|(id, _)| id)
+                .collect(),
+            None => Vec::new(),
+        };
+        // A selector that matches nothing leaves the service without endpoints
+        let status = if selector.is_some() && targets.is_empty() {
+            NodeStatus::Warning
+        } else {
+            NodeStatus::Healthy
+        };
+
+        let node = new_node(&svc.metadata, NodeType::Service, status);
+        let id = graph.add(node, &svc.metadata);
+        for target in targets {
+            graph.edge(&id, target, EdgeType::Routes, None);
+        }
+    }
+
+    for ingress in &resources.ingresses {
+        let ns = ingress.metadata.namespace.as_deref().unwrap_or_default();
+        let node = new_node(&ingress.metadata, NodeType::Ingress, NodeStatus::Healthy);
+        let id = graph.add(node, &ingress.metadata);
+        for (service, label) in ingress_backends(ingress) {
+            let target = node_id(&NodeType::Service, Some(ns), &service);
+            graph.edge(&id, &target, EdgeType::Routes, label);
+        }
+        for tls in ingress.spec.iter().flat_map(|s| s.tls.iter().flatten()) {
+            if let Some(secret) = &tls.secret_name {
+                let target = node_id(&NodeType::Secret, Some(ns), secret);
+                graph.edge(&id, &target, EdgeType::Uses, Some("tls".to_string()));
+                referenced.insert(target);
+            }
+        }
+    }
+
+    // EndpointSlices are owned by their Service and route to the pods
+    // backing it
+    for slice in &resources.endpoint_slices {
+        let ns = slice.metadata.namespace.as_deref().unwrap_or_default();
+        let total = slice.endpoints.len();
+        let ready = slice
+            .endpoints
+            .iter()
+            .filter(|e| e.conditions.as_ref().and_then(|c| c.ready).unwrap_or(false))
+            .count();
+        let status = if total == 0 || (ready > 0 && ready < total) {
+            NodeStatus::Warning
+        } else if ready == 0 {
+            NodeStatus::Error
+        } else {
+            NodeStatus::Healthy
+        };
+
+        let mut node = new_node(&slice.metadata, NodeType::EndpointSlice, status);
+        node.ready_status = Some(format!("{}/{}", ready, total));
+        let id = graph.add(node, &slice.metadata);
+        for target_ref in slice.endpoints.iter().filter_map(|e| e.target_ref.as_ref()) {
+            if target_ref.kind.as_deref() != Some("Pod") {
+                continue;
+            }
+            if let Some(name) = &target_ref.name {
+                let pod_ns = target_ref.namespace.as_deref().unwrap_or(ns);
+                let target = node_id(&NodeType::Pod, Some(pod_ns), name);
+                graph.edge(&id, &target, EdgeType::Routes, None);
+            }
+        }
+    }
+
+    for (metas, node_type) in [
+        (&resources.config_maps, NodeType::ConfigMap),
+        (&resources.secrets, NodeType::Secret),
+        (&resources.service_accounts, NodeType::ServiceAccount),
+    ] {
+        for meta in metas {
+            let node = new_node(meta, node_type.clone(), NodeStatus::Healthy);
+            if referenced.contains(&node.id) {
+                graph.add(node, meta);
+            }
+        }
+    }
+
+    let mut bound_pvs: HashSet<&str> = HashSet::new();
+    for pvc in &resources.pvcs {
+        let phase = pvc.status.as_ref().and_then(|s| s.phase.as_deref());
+        let node = new_node(
+            &pvc.metadata,
+            NodeType::PersistentVolumeClaim,
+            get_volume_status(phase),
+        );
+        let id = graph.add(node, &pvc.metadata);
+        if let Some(volume) = pvc.spec.as_ref().and_then(|s| s.volume_name.as_deref()) {
+            graph.edge(
+                &id,
+                &node_id(&NodeType::PersistentVolume, None, volume),
+                EdgeType::Binds,
+                None,
+            );
+            bound_pvs.insert(volume);
+        }
+    }
+
+    // PVs are cluster-scoped; only the ones backing a claim in the graph
+    for pv in &resources.pvs {
+        if !bound_pvs.contains(pv.name_any().as_str()) {
+            continue;
+        }
+        let phase = pv.status.as_ref().and_then(|s| s.phase.as_deref());
+        let node = new_node(
+            &pv.metadata,
+            NodeType::PersistentVolume,
+            get_volume_status(phase),
+        );
+        graph.add(node, &pv.metadata);
+    }
+
+    graph.finish()
+}
+
 /// List a namespaced resource either cluster-wide or per selected namespace.
 /// With a namespace filter this queries only those namespaces server-side
 /// instead of listing the whole cluster and filtering in memory.
@@ -160,222 +888,549 @@ where
     }
 }
 
-/// Generate nested sub-flow resource graph (Namespaces -> Deployments -> Pods)
-/// Structure: Namespace (group) contains Deployment (group) contains Pods
+/// Like `list_scoped`, but fetches object metadata only
+async fn list_scoped_metadata<K>(
+    client: &kube::Client,
+    namespaces: &[String],
+    errors: &mut Vec<String>,
+    label: &str,
+) -> Vec<ObjectMeta>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    <K as kube::Resource>::DynamicType: Default,
+{
+    let lp = ListParams::default();
+    if namespaces.is_empty() {
+        match Api::<K>::all(client.clone()).list_metadata(&lp).await {
+            Ok(list) => list.items.into_iter().map(|m| m.metadata).collect(),
+            Err(e) => {
+                errors.push(format!("{label}: {e}"));
+                Vec::new()
+            }
+        }
+    } else {
+        let mut out = Vec::new();
+        for ns in namespaces {
+            match Api::<K>::namespaced(client.clone(), ns)
+                .list_metadata(&lp)
+                .await
+            {
+                Ok(list) => out.extend(list.items.into_iter().map(|m| m.metadata)),
+                Err(e) => errors.push(format!("{label} ({ns}): {e}")),
+            }
+        }
+        out
+    }
+}
+
+/// Fetch everything `build_graph` needs. Failed lists (typically RBAC) are
+/// recorded in `errors` and leave that kind out of the graph.
+pub(crate) async fn fetch_graph_resources(
+    client: &kube::Client,
+    namespaces: &[String],
+    errors: &mut Vec<String>,
+) -> GraphResources {
+    let mut resources = GraphResources::default();
+
+    let ns_set: HashSet<&str> = namespaces.iter().map(|s| s.as_str()).collect();
+    match Api::<Namespace>::all(client.clone())
+        .list(&ListParams::default())
+        .await
+    {
+        Ok(list) => {
+            resources.namespaces = list
+                .items
+                .into_iter()
+                .filter(|ns| ns_set.is_empty() || ns_set.contains(ns.name_any().as_str()))
+                .collect();
+        }
+        Err(e) => errors.push(format!("Namespaces: {e}")),
+    }
+
+    resources.deployments = list_scoped(client, namespaces, errors, "Deployments").await;
+    resources.statefulsets = list_scoped(client, namespaces, errors, "StatefulSets").await;
+    resources.daemonsets = list_scoped(client, namespaces, errors, "DaemonSets").await;
+    resources.replicasets = list_scoped(client, namespaces, errors, "ReplicaSets").await;
+    resources.jobs = list_scoped(client, namespaces, errors, "Jobs").await;
+    resources.cronjobs = list_scoped(client, namespaces, errors, "CronJobs").await;
+    resources.pods = list_scoped(client, namespaces, errors, "Pods").await;
+    resources.services = list_scoped(client, namespaces, errors, "Services").await;
+    resources.ingresses = list_scoped(client, namespaces, errors, "Ingresses").await;
+    resources.endpoint_slices = list_scoped(client, namespaces, errors, "EndpointSlices").await;
+    resources.config_maps =
+        list_scoped_metadata::<ConfigMap>(client, namespaces, errors, "ConfigMaps").await;
+    resources.secrets = list_scoped_metadata::<Secret>(client, namespaces, errors, "Secrets").await;
+    resources.service_accounts =
+        list_scoped_metadata::<ServiceAccount>(client, namespaces, errors, "ServiceAccounts").await;
+    resources.pvcs = list_scoped(client, namespaces, errors, "PersistentVolumeClaims").await;
+
+    // Only needed when some claim is bound
+    if resources.pvcs.iter().any(|pvc| {
+        pvc.spec
+            .as_ref()
+            .and_then(|s| s.volume_name.as_ref())
+            .is_some()
+    }) {
+        match Api::<PersistentVolume>::all(client.clone())
+            .list(&ListParams::default())
+            .await
+        {
+            Ok(list) => resources.pvs = list.items,
+            Err(e) => errors.push(format!("PersistentVolumes: {e}")),
+        }
+    }
+
+    resources
+}
+
+/// Generate the nested sub-flow resource graph with ownership, routing,
+/// mount and binding edges
 #[command]
 pub async fn generate_resource_graph(
     state: State<'_, AppState>,
     namespaces: Vec<String>,
+    context: Option<String>,
 ) -> Result<GraphData, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
-    let mut nodes: Vec<GraphNode> = Vec::new();
-    let edges: Vec<GraphEdge> = Vec::new(); // No edges - nested sub-flows provide visual grouping
     let mut errors: Vec<String> = Vec::new();
+    let resources = fetch_graph_resources(&client, &namespaces, &mut errors).await;
+    let (nodes, edges) = build_graph(&resources);
 
-    // Track child counts for sizing
-    let mut ns_child_count: HashMap<String, usize> = HashMap::new();
-    let mut deploy_child_count: HashMap<String, usize> = HashMap::new();
-
-    let list_params = ListParams::default();
-    let filter_ns = !namespaces.is_empty();
-    let ns_set: std::collections::HashSet<&str> = namespaces.iter().map(|s| s.as_str()).collect();
-
-    // 1. Fetch Namespaces (top-level group nodes)
-    let ns_api: Api<Namespace> = Api::all(client.clone());
-    match ns_api.list(&list_params).await {
-        Ok(ns_list) => {
-            for ns in ns_list.items {
-                let name = ns.name_any();
-                if filter_ns && !ns_set.contains(name.as_str()) {
-                    continue;
-                }
-                let uid = ns.metadata.uid.clone().unwrap_or_default();
-                let id = format!("ns-{}", name);
-
-                ns_child_count.insert(id.clone(), 0);
-
-                nodes.push(GraphNode {
-                    id,
-                    uid,
-                    name,
-                    namespace: None,
-                    node_type: NodeType::Namespace,
-                    status: NodeStatus::Healthy,
-                    labels: btree_to_hashmap(ns.metadata.labels),
-                    parent_id: None,
-                    ready_status: None,
-                    replicas: None,
-                    is_group: true,
-                    child_count: None,
-                });
+    tracing::info!(
+        "Generated nested sub-flow graph with {} nodes and {} edges",
+        nodes.len(),
+        edges.len()
+    );
+
+    Ok(GraphData {
+        nodes,
+        edges,
+        errors,
+    })
+}
+
+/// Change to a live graph. Sent in batches on `graph-watch-{watch_id}`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", content = "data")]
+pub enum GraphEvent {
+    NodeAdded(GraphNode),
+    NodeUpdated(GraphNode),
+    /// Node id
+    NodeRemoved(String),
+    EdgeAdded(GraphEdge),
+    EdgeUpdated(GraphEdge),
+    /// Edge id
+    EdgeRemoved(String),
+    /// A watch failed; the graph keeps the objects it last saw
+    Error(String),
+}
+
+/// The graph as last sent to a live session
+#[derive(Default)]
+struct LiveGraph {
+    nodes: HashMap<String, GraphNode>,
+    edges: HashMap<String, GraphEdge>,
+}
+
+impl LiveGraph {
+    /// Replace the graph and return the changes. Removals come first, leaf
+    /// nodes before groups; additions keep build order, so a group is always
+    /// added before its children.
+    fn update(&mut self, nodes: Vec<GraphNode>, edges: Vec<GraphEdge>) -> Vec<GraphEvent> {
+        let mut events = Vec::new();
+
+        let edge_ids: HashSet<&str> = edges.iter().map(|e| e.id.as_str()).collect();
+        let mut removed_edges: Vec<&String> = self
+            .edges
+            .keys()
+            .filter(|id| !edge_ids.contains(id.as_str()))
+            .collect();
+        removed_edges.sort();
+        events.extend(
+            removed_edges
+                .into_iter()
+                .map(|id| GraphEvent::EdgeRemoved(id.clone())),
+        );
+
+        let node_ids: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+        let mut removed_nodes: Vec<&GraphNode> = self
+            .nodes
+            .values()
+            .filter(|n| !node_ids.contains(n.id.as_str()))
+            .collect();
+        removed_nodes.sort_by_key(|&n| (n.is_group, &n.id));
+        events.extend(
+            removed_nodes
+                .into_iter()
+                .map(|n| GraphEvent::NodeRemoved(n.id.clone())),
+        );
+
+        for node in &nodes {
+            match self.nodes.get(&node.id) {
+                None => events.push(GraphEvent::NodeAdded(node.clone())),
+                Some(prev) if prev != node => events.push(GraphEvent::NodeUpdated(node.clone())),
+                Some(_) => {}
             }
         }
-        Err(e) => errors.push(format!("Namespaces: {e}")),
+        for edge in &edges {
+            match self.edges.get(&edge.id) {
+                None => events.push(GraphEvent::EdgeAdded(edge.clone())),
+                Some(prev) if prev != edge => events.push(GraphEvent::EdgeUpdated(edge.clone())),
+                Some(_) => {}
+            }
+        }
+
+        self.nodes = nodes.into_iter().map(|n| (n.id.clone(), n)).collect();
+        self.edges = edges.into_iter().map(|e| (e.id.clone(), e)).collect();
+        events
     }
+}
 
-    // 2. Fetch Deployments (nested group nodes inside namespaces)
-    for deployment in
-        list_scoped::<Deployment>(&client, &namespaces, &mut errors, "Deployments").await
-    {
-        let ns = deployment.namespace().unwrap_or_default();
-        let name = deployment.name_any();
-        let uid = deployment.metadata.uid.clone().unwrap_or_default();
-        let id = format!("deploy-{}-{}", ns, name);
+/// Quiet period before a live graph is rebuilt, so a rollout's burst of
+/// pod events goes out as one batch
+const GRAPH_DEBOUNCE: Duration = Duration::from_millis(300);
 
-        let status = get_deployment_status(&deployment);
-        let ready = deployment
-            .status
-            .as_ref()
-            .and_then(|s| s.ready_replicas)
-            .unwrap_or(0);
-        let desired = deployment
-            .spec
-            .as_ref()
-            .and_then(|s| s.replicas)
-            .unwrap_or(0);
+/// Longest wait for every watch's initial listing before the first graph is
+/// sent anyway (a kind the user may not list never finishes)
+const GRAPH_INITIAL_SYNC: Duration = Duration::from_secs(5);
 
-        // Initialize child count for this deployment
-        deploy_child_count.insert(id.clone(), 0);
-
-        // Parent is always the namespace group node
-        let ns_id = format!("ns-{}", ns);
-        *ns_child_count.entry(ns_id.clone()).or_insert(0) += 1;
-        let parent_id = Some(ns_id);
-
-        // Deployments are ALSO groups (they contain pods)
-        nodes.push(GraphNode {
-            id,
-            uid,
-            name,
-            namespace: Some(ns),
-            node_type: NodeType::Deployment,
-            status,
-            labels: btree_to_hashmap(deployment.metadata.labels),
-            parent_id,
-            ready_status: None,
-            replicas: Some(format!("{}/{}", ready, desired)),
-            is_group: true, // Deployment is a group containing pods
-            child_count: None,
-        });
-    }
+/// What a graph session's watches report, reduced to what the loop needs
+enum GraphSignal {
+    Changed,
+    Synced,
+    Failed(String),
+}
 
-    // 3. Fetch ReplicaSets (metadata only) to resolve Pod -> Deployment via
-    // owner references instead of label matching (labels can collide; owner
-    // refs are authoritative and O(1) per pod).
-    let mut rs_to_deploy: HashMap<String, String> = HashMap::new();
-    for rs in list_scoped::<ReplicaSet>(&client, &namespaces, &mut errors, "ReplicaSets").await {
-        let ns = rs.namespace().unwrap_or_default();
-        let Some(uid) = rs.metadata.uid.clone() else {
-            continue;
+fn graph_signals<K, E>(
+    index: usize,
+    label: &'static str,
+    stream: impl futures::Stream<Item = Result<Event<K>, E>> + Send + 'static,
+) -> BoxStream<'static, (usize, GraphSignal)>
+where
+    K: Send + 'static,
+    E: std::fmt::Display + Send + 'static,
+{
+    stream
+        .map(move |event| {
+            let signal = match event {
+                Ok(Event::InitDone) => GraphSignal::Synced,
+                Ok(_) => GraphSignal::Changed,
+                Err(e) => GraphSignal::Failed(format!("{label}: {e}")),
+            };
+            (index, signal)
+        })
+        .boxed()
+}
+
+/// The watches behind a live graph and where to read their objects.
+/// Workloads, networking and storage come from the shared informers;
+/// ConfigMaps, Secrets and ServiceAccounts get metadata-only reflectors of
+/// their own so no Secret data is held for the graph.
+struct GraphWatches {
+    client: kube::Client,
+    cache: Arc<ResourceCache>,
+    context: String,
+    /// Watched namespaces, or a single `None` for all
+    scopes: Vec<Option<String>>,
+    namespace_filter: HashSet<String>,
+    signals: Vec<BoxStream<'static, (usize, GraphSignal)>>,
+    subscriptions: Vec<CacheSubscription>,
+    config_maps: Vec<Store<PartialObjectMeta<ConfigMap>>>,
+    secrets: Vec<Store<PartialObjectMeta<Secret>>>,
+    service_accounts: Vec<Store<PartialObjectMeta<ServiceAccount>>>,
+}
+
+impl GraphWatches {
+    fn new(
+        client: kube::Client,
+        cache: Arc<ResourceCache>,
+        context: String,
+        namespaces: Vec<String>,
+    ) -> Self {
+        let scopes = if namespaces.is_empty() {
+            vec![None]
+        } else {
+            namespaces.iter().cloned().map(Some).collect()
         };
-        if let Some(owner) = rs
-            .metadata
-            .owner_references
-            .as_deref()
-            .unwrap_or_default()
-            .iter()
-            .find(|o| o.kind == "Deployment")
-        {
-            rs_to_deploy.insert(uid, format!("deploy-{}-{}", ns, owner.name));
+        Self {
+            client,
+            cache,
+            context,
+            scopes,
+            namespace_filter: namespaces.into_iter().collect(),
+            signals: Vec::new(),
+            subscriptions: Vec::new(),
+            config_maps: Vec::new(),
+            secrets: Vec::new(),
+            service_accounts: Vec::new(),
         }
     }
 
-    // 4. Fetch Pods (leaf nodes inside deployments)
-    for pod in list_scoped::<Pod>(&client, &namespaces, &mut errors, "Pods").await {
-        let ns = pod.namespace().unwrap_or_default();
-        let name = pod.name_any();
-        let uid = pod.metadata.uid.clone().unwrap_or_default();
-        let id = format!("pod-{}-{}", ns, name);
+    /// Subscribe to the shared informers of a namespaced kind
+    fn cached<K>(&mut self, label: &'static str)
+    where
+        K: Resource<DynamicType = (), Scope = k8s_openapi::NamespaceResourceScope>
+            + Clone
+            + std::fmt::Debug
+            + Send
+            + Sync
+            + serde::de::DeserializeOwned
+            + 'static,
+    {
+        for ns in self.scopes.clone() {
+            let api: Api<K> = match &ns {
+                Some(ns) => Api::namespaced(self.client.clone(), ns),
+                None => Api::all(self.client.clone()),
+            };
+            let (stream, subscription) =
+                self.cache
+                    .subscribe(&self.context, api, ns.as_deref(), None);
+            self.signals
+                .push(graph_signals(self.signals.len(), label, stream));
+            self.subscriptions.push(subscription);
+        }
+    }
 
-        let status = get_pod_status(&pod);
-        let labels = btree_to_hashmap(pod.metadata.labels.clone());
+    /// Subscribe to the shared informer of a cluster-scoped kind
+    fn cached_cluster<K>(&mut self, label: &'static str)
+    where
+        K: Resource<DynamicType = (), Scope = k8s_openapi::ClusterResourceScope>
+            + Clone
+            + std::fmt::Debug
+            + Send
+            + Sync
+            + serde::de::DeserializeOwned
+            + 'static,
+    {
+        let api: Api<K> = Api::all(self.client.clone());
+        let (stream, subscription) = self.cache.subscribe(&self.context, api, None, None);
+        self.signals
+            .push(graph_signals(self.signals.len(), label, stream));
+        self.subscriptions.push(subscription);
+    }
 
-        // Calculate ready status
-        let container_statuses = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.container_statuses.as_ref());
-        let ready_status = container_statuses.map(|statuses| {
-            let ready = statuses.iter().filter(|cs| cs.ready).count();
-            format!("{}/{}", ready, statuses.len())
-        });
+    /// Start metadata-only reflectors for a namespaced kind
+    ///
+    /// `metadata_watcher` is deprecated in kube 4; an `Api<PartialObjectMeta<K>>`
+    /// already issues `as=PartialObjectMetadata` list/watch requests, so the
+    /// plain watcher never pulls Secret or ConfigMap payloads.
+    fn metadata<K>(&mut self, label: &'static str) -> Vec<Store<PartialObjectMeta<K>>>
+    where
+        K: Resource<DynamicType = (), Scope = k8s_openapi::NamespaceResourceScope>
+            + Clone
+            + std::fmt::Debug
+            + Send
+            + Sync
+            + serde::de::DeserializeOwned
+            + 'static,
+    {
+        let mut stores = Vec::new();
+        for ns in self.scopes.clone() {
+            let api: Api<PartialObjectMeta<K>> = match &ns {
+                Some(ns) => Api::namespaced(self.client.clone(), ns),
+                None => Api::all(self.client.clone()),
+            };
+            let (reader, writer) = reflector::store();
+            let stream =
+                reflector::reflector(writer, watcher(api, Config::default()).default_backoff());
+            self.signals
+                .push(graph_signals(self.signals.len(), label, stream));
+            stores.push(reader);
+        }
+        stores
+    }
 
-        // Resolve parent deployment via owner references:
-        // Pod -> ReplicaSet (owner uid) -> Deployment
-        let parent_deployment_id = pod
-            .metadata
-            .owner_references
-            .as_deref()
-            .unwrap_or_default()
+    fn snapshot<K>(&self) -> Vec<K>
+    where
+        K: Resource<DynamicType = ()> + Clone + Send + Sync + 'static,
+    {
+        self.scopes
             .iter()
-            .find(|o| o.kind == "ReplicaSet")
-            .and_then(|o| rs_to_deploy.get(&o.uid))
-            // Only attach to deployments that made it into the graph
-            .filter(|deploy_id| deploy_child_count.contains_key(*deploy_id))
-            .cloned();
-        if let Some(deploy_id) = &parent_deployment_id {
-            *deploy_child_count.entry(deploy_id.clone()).or_insert(0) += 1;
-        }
+            .flat_map(|ns| self.cache.snapshot::<K>(&self.context, ns.as_deref()))
+            .collect()
+    }
 
-        // Parent is the deployment if found, otherwise the namespace directly
-        let parent_id = if let Some(deploy_id) = parent_deployment_id {
-            Some(deploy_id)
-        } else {
-            let ns_id = format!("ns-{}", ns);
-            *ns_child_count.entry(ns_id.clone()).or_insert(0) += 1;
-            Some(ns_id)
-        };
+    /// Current objects of every watch, as `build_graph` input
+    fn resources(&self) -> GraphResources {
+        fn metadata<K: Resource<DynamicType = ()> + Clone + 'static>(
+            stores: &[Store<PartialObjectMeta<K>>],
+        ) -> Vec<ObjectMeta> {
+            stores
+                .iter()
+                .flat_map(|store| store.state())
+                .map(|obj| obj.metadata.clone())
+                .collect()
+        }
 
-        nodes.push(GraphNode {
-            id,
-            uid,
-            name,
-            namespace: Some(ns),
-            node_type: NodeType::Pod,
-            status,
-            labels,
-            parent_id,
-            ready_status,
-            replicas: None,
-            is_group: false, // Pods are leaf nodes
-            child_count: None,
-        });
+        let mut namespaces: Vec<Namespace> = self.cache.snapshot(&self.context, None);
+        if !self.namespace_filter.is_empty() {
+            namespaces.retain(|ns| self.namespace_filter.contains(&ns.name_any()));
+        }
+        GraphResources {
+            namespaces,
+            deployments: self.snapshot(),
+            statefulsets: self.snapshot(),
+            daemonsets: self.snapshot(),
+            replicasets: self.snapshot(),
+            jobs: self.snapshot(),
+            cronjobs: self.snapshot(),
+            pods: self.snapshot(),
+            services: self.snapshot(),
+            ingresses: self.snapshot(),
+            endpoint_slices: self.snapshot(),
+            config_maps: metadata(&self.config_maps),
+            secrets: metadata(&self.secrets),
+            service_accounts: metadata(&self.service_accounts),
+            pvcs: self.snapshot(),
+            pvs: self.cache.snapshot(&self.context, None),
+        }
     }
+}
 
-    // Update child counts for namespaces and deployments
-    for node in &mut nodes {
-        match node.node_type {
-            NodeType::Namespace => {
-                let ns_id = format!("ns-{}", node.name);
-                node.child_count = ns_child_count.get(&ns_id).copied();
+/// Rebuild the graph whenever a watch reports a change and emit what
+/// changed, until the stream ends or the session is cancelled
+async fn run_graph_loop(
+    app: AppHandle,
+    manager: Arc<WatchManager>,
+    watch_id: String,
+    mut watches: GraphWatches,
+    token: Arc<CancellationToken>,
+) {
+    let event_name = format!("graph-watch-{watch_id}");
+    let stream_count = watches.signals.len();
+    let mut signals = futures::stream::select_all(std::mem::take(&mut watches.signals));
+    let mut live = LiveGraph::default();
+    // Watches that finished listing or failed, and the last error of each
+    let mut settled: HashSet<usize> = HashSet::new();
+    let mut failures: HashMap<usize, String> = HashMap::new();
+    let mut started = false;
+    let mut rebuild_at = Some(Instant::now() + GRAPH_INITIAL_SYNC);
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => {
+                tracing::info!("Graph watch {} cancelled", watch_id);
+                break;
             }
-            NodeType::Deployment => {
-                node.child_count = deploy_child_count.get(&node.id).copied();
+            signal = signals.next() => {
+                let Some((index, signal)) = signal else { break };
+                match signal {
+                    GraphSignal::Failed(message) => {
+                        settled.insert(index);
+                        // A failing watch retries with backoff; report each
+                        // distinct error once
+                        if failures.get(&index) != Some(&message) {
+                            failures.insert(index, message.clone());
+                            if let Err(e) = app.emit(&event_name, vec![GraphEvent::Error(message)]) {
+                                tracing::error!("Failed to emit graph event: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                    GraphSignal::Synced => {
+                        settled.insert(index);
+                        failures.remove(&index);
+                    }
+                    GraphSignal::Changed => {
+                        failures.remove(&index);
+                    }
+                }
+                if !started && settled.len() == stream_count {
+                    rebuild_at = Some(Instant::now());
+                } else if started && rebuild_at.is_none() {
+                    rebuild_at = Some(Instant::now() + GRAPH_DEBOUNCE);
+                }
+            }
+            _ = tokio::time::sleep_until(rebuild_at.unwrap_or_else(Instant::now)), if rebuild_at.is_some() => {
+                rebuild_at = None;
+                started = true;
+                let (nodes, edges) = build_graph(&watches.resources());
+                let changes = live.update(nodes, edges);
+                if changes.is_empty() {
+                    continue;
+                }
+                if let Err(e) = app.emit(&event_name, &changes) {
+                    tracing::error!("Failed to emit graph event: {}", e);
+                    break;
+                }
             }
-            _ => {}
         }
     }
 
-    tracing::info!(
-        "Generated nested sub-flow graph with {} nodes and {} edges",
-        nodes.len(),
-        edges.len()
-    );
+    manager.remove_session_if_owned(&watch_id, &token).await;
+    drop(watches);
+    tracing::info!("Graph watch {} ended", watch_id);
+}
 
-    Ok(GraphData {
-        nodes,
-        edges,
-        errors,
-    })
+/// Start a live resource graph. The first batch on `graph-watch-{watch_id}`
+/// adds the whole graph; later batches carry only what changed. Stopped
+/// with `stop_watch` like any other watch.
+#[command]
+pub async fn watch_resource_graph(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    watch_manager: State<'_, Arc<WatchManager>>,
+    namespaces: Vec<String>,
+    watch_id: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    let (client, context) = state
+        .k8s
+        .get_connection_for(context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
+    let manager = Arc::clone(watch_manager.inner());
+    let token = manager.add_session(watch_id.clone(), context.clone()).await;
+
+    let mut watches = GraphWatches::new(client, Arc::clone(&state.cache), context, namespaces);
+    watches.cached_cluster::<Namespace>("Namespaces");
+    watches.cached::<Deployment>("Deployments");
+    watches.cached::<StatefulSet>("StatefulSets");
+    watches.cached::<DaemonSet>("DaemonSets");
+    watches.cached::<ReplicaSet>("ReplicaSets");
+    watches.cached::<Job>("Jobs");
+    watches.cached::<CronJob>("CronJobs");
+    watches.cached::<Pod>("Pods");
+    watches.cached::<Service>("Services");
+    watches.cached::<Ingress>("Ingresses");
+    watches.cached::<EndpointSlice>("EndpointSlices");
+    watches.cached::<PersistentVolumeClaim>("PersistentVolumeClaims");
+    watches.cached_cluster::<PersistentVolume>("PersistentVolumes");
+    watches.config_maps = watches.metadata::<ConfigMap>("ConfigMaps");
+    watches.secrets = watches.metadata::<Secret>("Secrets");
+    watches.service_accounts = watches.metadata::<ServiceAccount>("ServiceAccounts");
+
+    let watch_id_clone = watch_id.clone();
+    tokio::spawn(run_graph_loop(app, manager, watch_id_clone, watches, token));
+
+    tracing::info!("Started graph watch: {}", watch_id);
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use k8s_openapi::api::apps::v1::{DeploymentSpec, DeploymentStatus};
-    use k8s_openapi::api::core::v1::{ContainerStatus, PodStatus};
-    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use k8s_openapi::api::apps::v1::{
+        DeploymentSpec, DeploymentStatus, ReplicaSetSpec, ReplicaSetStatus,
+    };
+    use k8s_openapi::api::batch::v1::{JobCondition, JobStatus};
+    use k8s_openapi::api::core::v1::{
+        ConfigMapEnvSource, Container, ContainerStatus, EnvFromSource, PersistentVolumeClaimSpec,
+        PersistentVolumeClaimVolumeSource, PodSpec, PodStatus, ServiceSpec, Volume,
+    };
+    use k8s_openapi::api::networking::v1::{
+        HTTPIngressPath, HTTPIngressRuleValue, IngressBackend, IngressRule, IngressServiceBackend,
+        IngressSpec,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
     use std::collections::BTreeMap;
 
     #[test]
@@ -415,6 +1470,14 @@ mod tests {
             "\"deployment\""
         );
         assert_eq!(serde_json::to_string(&NodeType::Pod).unwrap(), "\"pod\"");
+        assert_eq!(
+            serde_json::to_string(&NodeType::PersistentVolumeClaim).unwrap(),
+            "\"persistentvolumeclaim\""
+        );
+        assert_eq!(
+            serde_json::to_string(&NodeType::EndpointSlice).unwrap(),
+            "\"endpointslice\""
+        );
     }
 
     #[test]
@@ -444,6 +1507,14 @@ mod tests {
             serde_json::to_string(&EdgeType::Contains).unwrap(),
             "\"contains\""
         );
+        assert_eq!(
+            serde_json::to_string(&EdgeType::Routes).unwrap(),
+            "\"routes\""
+        );
+        assert_eq!(
+            serde_json::to_string(&EdgeType::Mounts).unwrap(),
+            "\"mounts\""
+        );
     }
 
     #[test]
@@ -656,4 +1727,373 @@ mod tests {
         assert!(json.contains("\"edge_type\":\"owns\""));
         assert!(json.contains("\"source\":\"deploy-1\""));
     }
+
+    fn meta(ns: Option<&str>, name: &str, uid: &str) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: ns.map(str::to_string),
+            uid: Some(uid.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn owned_by(mut meta: ObjectMeta, kind: &str, name: &str, uid: &str) -> ObjectMeta {
+        meta.owner_references = Some(vec![OwnerReference {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            uid: uid.to_string(),
+            ..Default::default()
+        }]);
+        meta
+    }
+
+    fn has_edge(edges: &[GraphEdge], source: &str, target: &str, edge_type: EdgeType) -> bool {
+        edges
+            .iter()
+            .any(|e| e.source == source && e.target == target && e.edge_type == edge_type)
+    }
+
+    /// ingress -> service -> pod -> configmap, with the pod grouped under
+    /// its deployment through the replicaset
+    fn web_resources() -> GraphResources {
+        let mut pod_meta = owned_by(
+            meta(Some("web"), "api-1", "pod-uid"),
+            "ReplicaSet",
+            "api-rs",
+            "rs-uid",
+        );
+        pod_meta.labels = Some(BTreeMap::from([("app".to_string(), "api".to_string())]));
+
+        GraphResources {
+            namespaces: vec![Namespace {
+                metadata: meta(None, "web", "ns-uid"),
+                ..Default::default()
+            }],
+            deployments: vec![Deployment {
+                metadata: meta(Some("web"), "api", "deploy-uid"),
+                ..Default::default()
+            }],
+            replicasets: vec![
+                ReplicaSet {
+                    metadata: owned_by(
+                        meta(Some("web"), "api-rs", "rs-uid"),
+                        "Deployment",
+                        "api",
+                        "deploy-uid",
+                    ),
+                    spec: Some(ReplicaSetSpec {
+                        replicas: Some(1),
+                        ..Default::default()
+                    }),
+                    status: Some(ReplicaSetStatus {
+                        replicas: 1,
+                        ready_replicas: Some(1),
+                        ..Default::default()
+                    }),
+                },
+                // Old revision, scaled to zero
+                ReplicaSet {
+                    metadata: owned_by(
+                        meta(Some("web"), "api-old", "old-uid"),
+                        "Deployment",
+                        "api",
+                        "deploy-uid",
+                    ),
+                    spec: Some(ReplicaSetSpec {
+                        replicas: Some(0),
+                        ..Default::default()
+                    }),
+                    status: None,
+                },
+            ],
+            pods: vec![Pod {
+                metadata: pod_meta,
+                spec: Some(PodSpec {
+                    containers: vec![Container {
+                        name: "api".to_string(),
+                        env_from: Some(vec![EnvFromSource {
+                            config_map_ref: Some(ConfigMapEnvSource {
+                                name: "api-config".to_string(),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }]),
+                        ..Default::default()
+                    }],
+                    volumes: Some(vec![Volume {
+                        name: "data".to_string(),
+                        persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                            claim_name: "api-data".to_string(),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }),
+                status: None,
+            }],
+            services: vec![Service {
+                metadata: meta(Some("web"), "api", "svc-uid"),
+                spec: Some(ServiceSpec {
+                    selector: Some(BTreeMap::from([("app".to_string(), "api".to_string())])),
+                    ..Default::default()
+                }),
+                status: None,
+            }],
+            ingresses: vec![Ingress {
+                metadata: meta(Some("web"), "public", "ing-uid"),
+                spec: Some(IngressSpec {
+                    rules: Some(vec![IngressRule {
+                        host: Some("example.com".to_string()),
+                        http: Some(HTTPIngressRuleValue {
+                            paths: vec![HTTPIngressPath {
+                                path: Some("/api".to_string()),
+                                path_type: "Prefix".to_string(),
+                                backend: IngressBackend {
+                                    service: Some(IngressServiceBackend {
+                                        name: "api".to_string(),
+                                        port: None,
+                                    }),
+                                    ..Default::default()
+                                },
+                            }],
+                        }),
+                    }]),
+                    ..Default::default()
+                }),
+                status: None,
+            }],
+            config_maps: vec![
+                meta(Some("web"), "api-config", "cm-uid"),
+                meta(Some("web"), "kube-root-ca.crt", "unused-uid"),
+            ],
+            pvcs: vec![PersistentVolumeClaim {
+                metadata: meta(Some("web"), "api-data", "pvc-uid"),
+                spec: Some(PersistentVolumeClaimSpec {
+                    volume_name: Some("pv-1234".to_string()),
+                    ..Default::default()
+                }),
+                status: None,
+            }],
+            pvs: vec![
+                PersistentVolume {
+                    metadata: meta(None, "pv-1234", "pv-uid"),
+                    ..Default::default()
+                },
+                PersistentVolume {
+                    metadata: meta(None, "pv-unrelated", "pv2-uid"),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_graph_traces_ingress_to_configmap() {
+        let (_, edges) = build_graph(&web_resources());
+
+        assert!(has_edge(
+            &edges,
+            "ing-web-public",
+            "svc-web-api",
+            EdgeType::Routes
+        ));
+        assert!(has_edge(
+            &edges,
+            "svc-web-api",
+            "pod-web-api-1",
+            EdgeType::Routes
+        ));
+        assert!(has_edge(
+            &edges,
+            "pod-web-api-1",
+            "cm-web-api-config",
+            EdgeType::Mounts
+        ));
+        assert!(has_edge(
+            &edges,
+            "pod-web-api-1",
+            "pvc-web-api-data",
+            EdgeType::Mounts
+        ));
+        assert!(has_edge(
+            &edges,
+            "pvc-web-api-data",
+            "pv-pv-1234",
+            EdgeType::Binds
+        ));
+
+        let ingress_edge = edges.iter().find(|e| e.source == "ing-web-public").unwrap();
+        assert_eq!(ingress_edge.label.as_deref(), Some("example.com/api"));
+    }
+
+    #[test]
+    fn test_build_graph_owner_edges_and_grouping() {
+        let (nodes, edges) = build_graph(&web_resources());
+
+        assert!(has_edge(
+            &edges,
+            "deploy-web-api",
+            "rs-web-api-rs",
+            EdgeType::Owns
+        ));
+        assert!(has_edge(
+            &edges,
+            "rs-web-api-rs",
+            "pod-web-api-1",
+            EdgeType::Owns
+        ));
+
+        let node = |id: &str| nodes.iter().find(|n| n.id == id);
+        // Pods and their replicaset live in the deployment group
+        assert_eq!(
+            node("pod-web-api-1").unwrap().parent_id.as_deref(),
+            Some("deploy-web-api")
+        );
+        assert_eq!(
+            node("rs-web-api-rs").unwrap().parent_id.as_deref(),
+            Some("deploy-web-api")
+        );
+        assert_eq!(node("deploy-web-api").unwrap().child_count, Some(2));
+        // ns children: deployment, service, ingress, configmap, pvc
+        assert_eq!(node("ns-web").unwrap().child_count, Some(5));
+        assert_eq!(node("pv-pv-1234").unwrap().parent_id, None);
+    }
+
+    #[test]
+    fn test_build_graph_skips_unreferenced_and_history() {
+        let (nodes, edges) = build_graph(&web_resources());
+        let ids: Vec<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+
+        assert!(!ids.contains(&"cm-web-kube-root-ca.crt"));
+        assert!(!ids.contains(&"pv-pv-unrelated"));
+        assert!(!ids.contains(&"rs-web-api-old"));
+        // Every edge connects two nodes of the graph
+        assert!(edges
+            .iter()
+            .all(|e| ids.contains(&e.source.as_str()) && ids.contains(&e.target.as_str())));
+    }
+
+    #[test]
+    fn test_build_graph_service_without_pods_is_warning() {
+        let mut resources = web_resources();
+        resources.pods.clear();
+        let (nodes, edges) = build_graph(&resources);
+
+        let svc = nodes.iter().find(|n| n.id == "svc-web-api").unwrap();
+        assert_eq!(svc.status, NodeStatus::Warning);
+        assert!(!edges.iter().any(|e| e.source == "svc-web-api"));
+        // Nothing references the configmap any more
+        assert!(!nodes.iter().any(|n| n.id == "cm-web-api-config"));
+    }
+
+    #[test]
+    fn test_get_job_status() {
+        let job = |status: JobStatus| Job {
+            status: Some(status),
+            ..Default::default()
+        };
+        let condition = |type_: &str| JobCondition {
+            type_: type_.to_string(),
+            status: "True".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_job_status(&job(JobStatus {
+                conditions: Some(vec![condition("Complete")]),
+                ..Default::default()
+            })),
+            NodeStatus::Healthy
+        );
+        assert_eq!(
+            get_job_status(&job(JobStatus {
+                conditions: Some(vec![condition("Failed")]),
+                ..Default::default()
+            })),
+            NodeStatus::Error
+        );
+        assert_eq!(
+            get_job_status(&job(JobStatus {
+                active: Some(1),
+                failed: Some(1),
+                ..Default::default()
+            })),
+            NodeStatus::Warning
+        );
+        assert_eq!(get_job_status(&Job::default()), NodeStatus::Unknown);
+    }
+
+    #[test]
+    fn test_live_graph_update_sends_changes_only() {
+        let mut live = LiveGraph::default();
+        let (nodes, edges) = build_graph(&web_resources());
+        let node_count = nodes.len();
+
+        let first = live.update(nodes.clone(), edges.clone());
+        assert_eq!(
+            first
+                .iter()
+                .filter(|e| matches!(e, GraphEvent::NodeAdded(_)))
+                .count(),
+            node_count
+        );
+        // Groups arrive before their children
+        let position = |id: &str| {
+            first
+                .iter()
+                .position(|e| matches!(e, GraphEvent::NodeAdded(n) if n.id == id))
+                .unwrap()
+        };
+        assert!(position("ns-web") < position("deploy-web-api"));
+        assert!(position("deploy-web-api") < position("pod-web-api-1"));
+
+        assert!(live.update(nodes, edges).is_empty());
+    }
+
+    #[test]
+    fn test_live_graph_update_status_and_removal() {
+        let mut live = LiveGraph::default();
+        let mut resources = web_resources();
+        let (nodes, edges) = build_graph(&resources);
+        live.update(nodes, edges);
+
+        // Pod starts failing
+        resources.pods[0].status = Some(PodStatus {
+            phase: Some("Failed".to_string()),
+            ..Default::default()
+        });
+        let (nodes, edges) = build_graph(&resources);
+        let changes = live.update(nodes, edges);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            GraphEvent::NodeUpdated(n) if n.id == "pod-web-api-1" && n.status == NodeStatus::Error
+        ));
+
+        // Pod deleted: its edges go first, then the pod and the configmap only
+        // it referenced
+        resources.pods.clear();
+        let (nodes, edges) = build_graph(&resources);
+        let changes = live.update(nodes, edges);
+        let first_node_removal = changes
+            .iter()
+            .position(|e| matches!(e, GraphEvent::NodeRemoved(_)))
+            .unwrap();
+        assert!(changes[..first_node_removal]
+            .iter()
+            .all(|e| matches!(e, GraphEvent::EdgeRemoved(_))));
+        assert!(changes.contains(&GraphEvent::NodeRemoved("pod-web-api-1".to_string())));
+        assert!(changes.contains(&GraphEvent::NodeRemoved("cm-web-api-config".to_string())));
+        assert!(changes.iter().any(
+            |e| matches!(e, GraphEvent::NodeUpdated(n) if n.id == "svc-web-api" && n.status == NodeStatus::Warning)
+        ));
+    }
+
+    #[test]
+    fn test_metadata_watch_api_is_metadata_only() {
+        assert!(PartialObjectMeta::<Secret>::metadata_api());
+        assert!(PartialObjectMeta::<ConfigMap>::metadata_api());
+    }
 }
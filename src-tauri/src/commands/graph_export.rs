@@ -0,0 +1,513 @@
+use crate::commands::graph::{EdgeType, GraphData, GraphEdge, GraphNode, NodeStatus, NodeType};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use tauri::command;
+
+/// Version of the JSON export layout. Bump when a field changes meaning.
+const GRAPH_SCHEMA: &str = "kubeli.graph/v1";
+
+/// Text formats a resource graph can be exported to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphExportFormat {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+fn kind_name(node_type: &NodeType) -> &'static str {
+    match node_type {
+        NodeType::Namespace => "Namespace",
+        NodeType::Deployment => "Deployment",
+        NodeType::StatefulSet => "StatefulSet",
+        NodeType::DaemonSet => "DaemonSet",
+        NodeType::ReplicaSet => "ReplicaSet",
+        NodeType::Job => "Job",
+        NodeType::CronJob => "CronJob",
+        NodeType::Pod => "Pod",
+        NodeType::Service => "Service",
+        NodeType::Ingress => "Ingress",
+        NodeType::EndpointSlice => "EndpointSlice",
+        NodeType::ConfigMap => "ConfigMap",
+        NodeType::Secret => "Secret",
+        NodeType::PersistentVolumeClaim => "PersistentVolumeClaim",
+        NodeType::PersistentVolume => "PersistentVolume",
+        NodeType::ServiceAccount => "ServiceAccount",
+    }
+}
+
+fn status_name(status: &NodeStatus) -> &'static str {
+    match status {
+        NodeStatus::Healthy => "healthy",
+        NodeStatus::Warning => "warning",
+        NodeStatus::Error => "error",
+        NodeStatus::Unknown => "unknown",
+    }
+}
+
+/// (fill, stroke) per status, the same palette in DOT and Mermaid
+fn status_colors(status: &NodeStatus) -> (&'static str, &'static str) {
+    match status {
+        NodeStatus::Healthy => ("#dcfce7", "#16a34a"),
+        NodeStatus::Warning => ("#fef9c3", "#ca8a04"),
+        NodeStatus::Error => ("#fee2e2", "#dc2626"),
+        NodeStatus::Unknown => ("#f3f4f6", "#6b7280"),
+    }
+}
+
+/// Kind, name and the replica or readiness counter, one per line
+fn label_lines(node: &GraphNode) -> Vec<String> {
+    let mut lines = vec![kind_name(&node.node_type).to_string(), node.name.clone()];
+    if let Some(counter) = node.replicas.as_ref().or(node.ready_status.as_ref()) {
+        lines.push(counter.clone());
+    }
+    lines
+}
+
+/// Nodes by parent, in id order, so output does not depend on the order the
+/// graph was built in. Nodes whose parent is not in the graph are roots.
+fn children_by_parent(graph: &GraphData) -> BTreeMap<Option<&str>, Vec<&GraphNode>> {
+    let ids: HashSet<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+    let mut children: BTreeMap<Option<&str>, Vec<&GraphNode>> = BTreeMap::new();
+    for node in &graph.nodes {
+        let parent = node
+            .parent_id
+            .as_deref()
+            .filter(|parent| ids.contains(parent));
+        children.entry(parent).or_default().push(node);
+    }
+    for nodes in children.values_mut() {
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    }
+    children
+}
+
+fn sorted_edges(graph: &GraphData) -> Vec<&GraphEdge> {
+    let mut edges: Vec<&GraphEdge> = graph.edges.iter().collect();
+    edges.sort_by(|a, b| a.id.cmp(&b.id));
+    edges
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn dot_edge_style(edge_type: &EdgeType) -> &'static str {
+    match edge_type {
+        EdgeType::Owns => "style=solid",
+        EdgeType::Contains => "style=dotted, arrowhead=none",
+        EdgeType::Routes => "style=bold, color=\"#2563eb\"",
+        EdgeType::Mounts => "style=dashed",
+        EdgeType::Binds => "style=dotted",
+        EdgeType::Uses => "style=dashed, color=\"#6b7280\"",
+    }
+}
+
+fn write_dot_node(out: &mut String, node: &GraphNode, indent: &str) {
+    let (fill, stroke) = status_colors(&node.status);
+    let _ = writeln!(
+        out,
+        "{indent}\"{}\" [label=\"{}\", fillcolor=\"{fill}\", color=\"{stroke}\"];",
+        dot_escape(&node.id),
+        dot_escape(&label_lines(node).join("\n")).replace('\n', "\\n"),
+    );
+}
+
+/// Groups become clusters. A group that is not a namespace also gets a
+/// node of its own inside the cluster, since edges point at it.
+fn write_dot_level(
+    out: &mut String,
+    children: &BTreeMap<Option<&str>, Vec<&GraphNode>>,
+    parent: Option<&str>,
+    depth: usize,
+) {
+    let indent = "  ".repeat(depth);
+    for node in children.get(&parent).into_iter().flatten() {
+        if !node.is_group {
+            write_dot_node(out, node, &indent);
+            continue;
+        }
+        let (_, stroke) = status_colors(&node.status);
+        let _ = writeln!(
+            out,
+            "{indent}subgraph \"cluster_{}\" {{",
+            dot_escape(&node.id)
+        );
+        let _ = writeln!(
+            out,
+            "{indent}  label=\"{} {}\";",
+            kind_name(&node.node_type),
+            dot_escape(&node.name)
+        );
+        let _ = writeln!(out, "{indent}  style=rounded;");
+        let _ = writeln!(out, "{indent}  color=\"{stroke}\";");
+        if node.node_type != NodeType::Namespace {
+            write_dot_node(out, node, &format!("{indent}  "));
+        }
+        write_dot_level(out, children, Some(node.id.as_str()), depth + 1);
+        let _ = writeln!(out, "{indent}}}");
+    }
+}
+
+/// Graphviz DOT, namespaces and workload groups as clusters
+fn to_dot(graph: &GraphData) -> String {
+    let children = children_by_parent(graph);
+    let mut out = String::new();
+    out.push_str("digraph kubeli {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+    out.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+    write_dot_level(&mut out, &children, None, 1);
+    for edge in sorted_edges(graph) {
+        let label = edge
+            .label
+            .as_deref()
+            .map(|label| format!(", label=\"{}\"", dot_escape(label)))
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "  \"{}\" -> \"{}\" [{}{}];",
+            dot_escape(&edge.source),
+            dot_escape(&edge.target),
+            dot_edge_style(&edge.edge_type),
+            label
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid ids allow only `[A-Za-z0-9_]`; distinct graph ids that sanitize
+/// to the same string get a numeric suffix
+fn mermaid_ids(graph: &GraphData) -> HashMap<&str, String> {
+    let mut sorted: Vec<&str> = graph.nodes.iter().map(|n| n.id.as_str()).collect();
+    sorted.sort_unstable();
+    let mut taken: HashMap<String, usize> = HashMap::new();
+    sorted
+        .into_iter()
+        .map(|id| {
+            let base: String = id
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            let count = taken.entry(base.clone()).or_insert(0);
+            *count += 1;
+            let unique = if *count == 1 {
+                base
+            } else {
+                format!("{}_{}", base, count)
+            };
+            (id, unique)
+        })
+        .collect()
+}
+
+fn mermaid_escape(value: &str) -> String {
+    value
+        .replace('&', "#amp;")
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+fn mermaid_arrow(edge_type: &EdgeType) -> &'static str {
+    match edge_type {
+        EdgeType::Routes => "==>",
+        EdgeType::Mounts | EdgeType::Uses | EdgeType::Binds => "-.->",
+        EdgeType::Owns | EdgeType::Contains => "-->",
+    }
+}
+
+/// Groups become subgraphs; edges may point at a subgraph id directly
+fn write_mermaid_level(
+    out: &mut String,
+    children: &BTreeMap<Option<&str>, Vec<&GraphNode>>,
+    ids: &HashMap<&str, String>,
+    parent: Option<&str>,
+    depth: usize,
+) {
+    let indent = "    ".repeat(depth);
+    for node in children.get(&parent).into_iter().flatten() {
+        let id = &ids[node.id.as_str()];
+        let label = label_lines(node)
+            .iter()
+            .map(String::as_str)
+            .map(mermaid_escape)
+            .collect::<Vec<_>>()
+            .join("<br/>");
+        if node.is_group {
+            let _ = writeln!(out, "{indent}subgraph {id}[\"{label}\"]");
+            write_mermaid_level(out, children, ids, Some(node.id.as_str()), depth + 1);
+            let _ = writeln!(out, "{indent}end");
+        } else {
+            let _ = writeln!(out, "{indent}{id}[\"{label}\"]");
+        }
+    }
+}
+
+/// Mermaid flowchart, groups as nested subgraphs and status as classes
+fn to_mermaid(graph: &GraphData) -> String {
+    let children = children_by_parent(graph);
+    let ids = mermaid_ids(graph);
+    let mut out = String::from("flowchart LR\n");
+    write_mermaid_level(&mut out, &children, &ids, None, 1);
+
+    for edge in sorted_edges(graph) {
+        let (Some(source), Some(target)) =
+            (ids.get(edge.source.as_str()), ids.get(edge.target.as_str()))
+        else {
+            continue;
+        };
+        let arrow = mermaid_arrow(&edge.edge_type);
+        match &edge.label {
+            Some(label) => {
+                let _ = writeln!(
+                    out,
+                    "    {source} {arrow}|\"{}\"| {target}",
+                    mermaid_escape(label)
+                );
+            }
+            None => {
+                let _ = writeln!(out, "    {source} {arrow} {target}");
+            }
+        }
+    }
+
+    for status in [
+        NodeStatus::Healthy,
+        NodeStatus::Warning,
+        NodeStatus::Error,
+        NodeStatus::Unknown,
+    ] {
+        let (fill, stroke) = status_colors(&status);
+        let _ = writeln!(
+            out,
+            "    classDef {} fill:{fill},stroke:{stroke}",
+            status_name(&status)
+        );
+        let mut members: Vec<&str> = graph
+            .nodes
+            .iter()
+            .filter(|n| n.status == status)
+            .map(|n| ids[n.id.as_str()].as_str())
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+        members.sort_unstable();
+        let _ = writeln!(
+            out,
+            "    class {} {}",
+            members.join(","),
+            status_name(&status)
+        );
+    }
+    out
+}
+
+/// Node with labels in key order, so the JSON export is byte-stable
+#[derive(Serialize)]
+struct ExportNode<'a> {
+    id: &'a str,
+    uid: &'a str,
+    name: &'a str,
+    namespace: Option<&'a str>,
+    node_type: &'a NodeType,
+    status: &'a NodeStatus,
+    labels: BTreeMap<&'a str, &'a str>,
+    parent_id: Option<&'a str>,
+    ready_status: Option<&'a str>,
+    replicas: Option<&'a str>,
+    is_group: bool,
+    child_count: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct GraphExport<'a> {
+    schema: &'static str,
+    nodes: Vec<ExportNode<'a>>,
+    edges: Vec<&'a GraphEdge>,
+    errors: &'a [String],
+}
+
+/// Versioned JSON with nodes and edges sorted by id
+fn to_json(graph: &GraphData) -> Result<String, String> {
+    let mut nodes: Vec<ExportNode> = graph
+        .nodes
+        .iter()
+        .map(|node| ExportNode {
+            id: &node.id,
+            uid: &node.uid,
+            name: &node.name,
+            namespace: node.namespace.as_deref(),
+            node_type: &node.node_type,
+            status: &node.status,
+            labels: node
+                .labels
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect(),
+            parent_id: node.parent_id.as_deref(),
+            ready_status: node.ready_status.as_deref(),
+            replicas: node.replicas.as_deref(),
+            is_group: node.is_group,
+            child_count: node.child_count,
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.id.cmp(b.id));
+
+    let export = GraphExport {
+        schema: GRAPH_SCHEMA,
+        nodes,
+        edges: sorted_edges(graph),
+        errors: &graph.errors,
+    };
+    serde_json::to_string_pretty(&export).map_err(|e| format!("Failed to serialize graph: {}", e))
+}
+
+/// Render a graph from `generate_resource_graph` as DOT, Mermaid or JSON
+#[command]
+pub async fn export_resource_graph(
+    graph: GraphData,
+    format: GraphExportFormat,
+) -> Result<String, String> {
+    match format {
+        GraphExportFormat::Dot => Ok(to_dot(&graph)),
+        GraphExportFormat::Mermaid => Ok(to_mermaid(&graph)),
+        GraphExportFormat::Json => to_json(&graph),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, node_type: NodeType, parent: Option<&str>, status: NodeStatus) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            uid: format!("uid-{id}"),
+            name: id.rsplit('-').next().unwrap().to_string(),
+            namespace: Some("web".to_string()),
+            is_group: matches!(node_type, NodeType::Namespace | NodeType::Deployment),
+            node_type,
+            status,
+            labels: HashMap::from([
+                ("tier".to_string(), "backend".to_string()),
+                ("app".to_string(), "api".to_string()),
+            ]),
+            parent_id: parent.map(str::to_string),
+            ready_status: None,
+            replicas: None,
+            child_count: None,
+        }
+    }
+
+    fn sample() -> GraphData {
+        GraphData {
+            nodes: vec![
+                node(
+                    "pod-web-api",
+                    NodeType::Pod,
+                    Some("deploy-web-api"),
+                    NodeStatus::Error,
+                ),
+                node("ns-web", NodeType::Namespace, None, NodeStatus::Healthy),
+                node(
+                    "deploy-web-api",
+                    NodeType::Deployment,
+                    Some("ns-web"),
+                    NodeStatus::Warning,
+                ),
+                node(
+                    "cm-web-say\"hi\"",
+                    NodeType::ConfigMap,
+                    Some("ns-web"),
+                    NodeStatus::Healthy,
+                ),
+            ],
+            edges: vec![
+                GraphEdge {
+                    id: "owns-deploy-web-api-pod-web-api".to_string(),
+                    source: "deploy-web-api".to_string(),
+                    target: "pod-web-api".to_string(),
+                    edge_type: EdgeType::Owns,
+                    label: None,
+                },
+                GraphEdge {
+                    id: "mounts-pod-web-api-cm".to_string(),
+                    source: "pod-web-api".to_string(),
+                    target: "cm-web-say\"hi\"".to_string(),
+                    edge_type: EdgeType::Mounts,
+                    label: Some("envFrom".to_string()),
+                },
+            ],
+            errors: vec![],
+        }
+    }
+
+    #[test]
+    fn test_dot_nests_clusters_and_styles_status() {
+        let dot = to_dot(&sample());
+
+        let ns = dot.find("subgraph \"cluster_ns-web\"").unwrap();
+        let deploy = dot.find("subgraph \"cluster_deploy-web-api\"").unwrap();
+        let pod = dot.find("\"pod-web-api\" [label=").unwrap();
+        assert!(ns < deploy && deploy < pod);
+        assert!(dot.contains("\"pod-web-api\" [label=\"Pod\\napi\", fillcolor=\"#fee2e2\""));
+        assert!(dot.contains("\"cm-web-say\\\"hi\\\"\""));
+        assert!(dot.contains(
+            "\"pod-web-api\" -> \"cm-web-say\\\"hi\\\"\" [style=dashed, label=\"envFrom\"];"
+        ));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_mermaid_subgraphs_and_classes() {
+        let mermaid = to_mermaid(&sample());
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("    subgraph ns_web[\"Namespace<br/>web\"]"));
+        assert!(mermaid.contains("        subgraph deploy_web_api[\"Deployment<br/>api\"]"));
+        assert!(mermaid.contains("            pod_web_api[\"Pod<br/>api\"]"));
+        assert!(mermaid.contains("    deploy_web_api --> pod_web_api"));
+        assert!(mermaid.contains("    pod_web_api -.->|\"envFrom\"| cm_web_say_hi_"));
+        assert!(mermaid.contains("    class pod_web_api error"));
+        assert!(mermaid.contains("    class cm_web_say_hi_,ns_web healthy"));
+    }
+
+    #[test]
+    fn test_mermaid_ids_stay_unique() {
+        let mut graph = sample();
+        graph.nodes.push(node(
+            "pod-web.api",
+            NodeType::Pod,
+            Some("ns-web"),
+            NodeStatus::Healthy,
+        ));
+        let ids = mermaid_ids(&graph);
+        assert_eq!(ids["pod-web-api"], "pod_web_api");
+        assert_eq!(ids["pod-web.api"], "pod_web_api_2");
+    }
+
+    #[test]
+    fn test_json_is_versioned_and_stable() {
+        let json = to_json(&sample()).unwrap();
+        let mut reordered = sample();
+        reordered.nodes.reverse();
+        reordered.edges.reverse();
+        assert_eq!(json, to_json(&reordered).unwrap());
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema"], GRAPH_SCHEMA);
+        assert_eq!(value["nodes"][0]["id"], "cm-web-say\"hi\"");
+        assert_eq!(value["nodes"][0]["node_type"], "configmap");
+        let labels: Vec<&String> = value["nodes"][0]["labels"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect();
+        assert_eq!(labels, ["app", "tier"]);
+        assert_eq!(value["edges"][0]["edge_type"], "mounts");
+    }
+}
@@ -1,17 +1,31 @@
-use crate::error::KubeliError;
+use crate::ai::cli_detector::get_extended_path;
+use crate::commands::apply::{
+    apply_object, apply_rank, diff_json, parse_manifests, resolve_manifest, resolve_manifest_in,
+    wait_for_crds, AppliedObject, ApplyOutcome, ChangeKind, ConflictResolution, FieldChange,
+    ManifestTarget,
+};
+use crate::error::{ErrorKind, KubeliError};
 use crate::k8s::AppState;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use flate2::read::GzDecoder;
-use k8s_openapi::api::core::v1::Secret;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use k8s_openapi::api::core::v1::{Namespace, Secret};
+use k8s_openapi::ByteString;
 use kube::{
-    api::{DynamicObject, ListParams},
+    api::{DeleteParams, DynamicObject, ListParams, PostParams},
     discovery::ApiResource,
-    Api,
+    Api, ResourceExt,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
-use std::io::Read;
-use tauri::{command, State};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::Stdio;
+use tauri::{command, AppHandle, Emitter, State};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
 
 /// Source that manages the Helm release
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -150,7 +164,8 @@ struct HelmChartMetadataData {
 /// hostile release secrets.
 const MAX_HELM_RELEASE_BYTES: u64 = 64 * 1024 * 1024;
 
-fn decode_helm_release(data: &str) -> Result<HelmReleaseData, KubeliError> {
+/// Undo the base64 and gzip layers of a release secret's `release` key
+fn decompress_helm_release(data: &str) -> Result<String, KubeliError> {
     // First base64 decode
     let decoded1 = BASE64.decode(data)?;
 
@@ -168,9 +183,12 @@ fn decode_helm_release(data: &str) -> Result<HelmReleaseData, KubeliError> {
     let mut decoder = GzDecoder::new(&gzip_data[..]).take(MAX_HELM_RELEASE_BYTES);
     let mut decompressed = String::new();
     decoder.read_to_string(&mut decompressed)?;
+    Ok(decompressed)
+}
 
+fn decode_helm_release(data: &str) -> Result<HelmReleaseData, KubeliError> {
     // Parse JSON
-    serde_json::from_str(&decompressed)
+    serde_json::from_str(&decompress_helm_release(data)?)
         .map_err(|e| KubeliError::unknown(format!("Failed to parse helm release JSON: {}", e)))
 }
 
@@ -182,6 +200,37 @@ async fn decode_helm_release_blocking(data: String) -> Result<HelmReleaseData, K
         .map_err(|e| KubeliError::unknown(format!("Helm decode task failed: {e}")))?
 }
 
+/// The whole release as JSON. Writing a release back must keep every field
+/// Helm stored (chart templates, hooks, labels), not just the ones Kubeli reads.
+async fn decode_helm_release_json_blocking(data: String) -> Result<Value, KubeliError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        serde_json::from_str(&decompress_helm_release(&data)?)
+            .map_err(|e| KubeliError::unknown(format!("Failed to parse helm release JSON: {}", e)))
+    })
+    .await
+    .map_err(|e| KubeliError::unknown(format!("Helm decode task failed: {e}")))?
+}
+
+/// Encode a release the way Helm's secret driver does: gzip, then base64.
+/// The API server adds the second base64 layer of the secret data.
+fn encode_helm_release(release: &Value) -> Result<String, KubeliError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&serde_json::to_vec(release)?)?;
+    Ok(BASE64.encode(encoder.finish()?))
+}
+
+fn history_entry(release: &HelmReleaseData) -> HelmReleaseHistoryEntry {
+    HelmReleaseHistoryEntry {
+        revision: release.version,
+        status: HelmReleaseStatus::from(release.info.status.as_str()),
+        chart: release.chart.metadata.name.clone(),
+        chart_version: release.chart.metadata.version.clone(),
+        app_version: release.chart.metadata.app_version.clone(),
+        deployed: release.info.last_deployed.clone(),
+        description: release.info.description.clone().unwrap_or_default(),
+    }
+}
+
 /// Get the latest revision number for a release from a list of secrets
 fn get_latest_revision(secrets: &[Secret], release_name: &str) -> i32 {
     let prefix = format!("sh.helm.release.v1.{}.v", release_name);
@@ -199,13 +248,32 @@ fn get_latest_revision(secrets: &[Secret], release_name: &str) -> i32 {
         .unwrap_or(0)
 }
 
+/// Decode one revision from its release secret
+async fn load_release_revision(
+    api: &Api<Secret>,
+    name: &str,
+    revision: i32,
+) -> Result<HelmReleaseData, KubeliError> {
+    let secret = api.get(&release_secret_name(name, revision)).await?;
+
+    let data = secret
+        .data
+        .as_ref()
+        .and_then(|d| d.get("release"))
+        .ok_or("Release data not found")?;
+
+    let data_str = String::from_utf8_lossy(&data.0);
+    decode_helm_release_blocking(data_str.into_owned()).await
+}
+
 /// List all Helm releases across all namespaces or in a specific namespace
 #[command]
 pub async fn list_helm_releases(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<HelmReleaseInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut releases: BTreeMap<(String, String), HelmReleaseInfo> = BTreeMap::new();
 
@@ -274,9 +342,11 @@ pub async fn list_helm_releases(
     }
 
     // Also fetch Flux HelmRelease CRDs and merge them
-    let flux_releases =
-        list_flux_helm_releases_internal(state.k8s.get_client().await.ok(), namespace.clone())
-            .await;
+    let flux_releases = list_flux_helm_releases_internal(
+        state.k8s.get_client_for(context.as_deref()).await.ok(),
+        namespace.clone(),
+    )
+    .await;
     for flux_release in flux_releases {
         // Only add if not already present (native Helm takes precedence)
         let key = (flux_release.namespace.clone(), flux_release.name.clone());
@@ -468,8 +538,9 @@ pub async fn get_helm_release(
     name: String,
     namespace: String,
     revision: Option<i32>,
+    context: Option<String>,
 ) -> Result<HelmReleaseDetail, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
     // Server-side filter on the release name - avoids downloading every
@@ -480,18 +551,7 @@ pub async fn get_helm_release(
 
     // Get the requested revision or the latest
     let rev = revision.unwrap_or_else(|| get_latest_revision(&secrets, &name));
-    let secret_name = format!("sh.helm.release.v1.{}.v{}", name, rev);
-
-    let secret = api.get(&secret_name).await?;
-
-    let data = secret
-        .data
-        .as_ref()
-        .and_then(|d| d.get("release"))
-        .ok_or("Release data not found")?;
-
-    let data_str = String::from_utf8_lossy(&data.0);
-    let release_data = decode_helm_release_blocking(data_str.into_owned()).await?;
+    let release_data = load_release_revision(&api, &name, rev).await?;
 
     Ok(HelmReleaseDetail {
         name: release_data.name,
@@ -517,8 +577,9 @@ pub async fn get_helm_release_history(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<Vec<HelmReleaseHistoryEntry>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<Secret> = Api::namespaced(client, &namespace);
     let lp = ListParams::default().labels(&format!("owner=helm,name={}", name));
@@ -537,15 +598,7 @@ pub async fn get_helm_release_history(
         if let Some(data) = secret.data.as_ref().and_then(|d| d.get("release")) {
             let data_str = String::from_utf8_lossy(&data.0);
             if let Ok(release_data) = decode_helm_release_blocking(data_str.into_owned()).await {
-                history.push(HelmReleaseHistoryEntry {
-                    revision: release_data.version,
-                    status: HelmReleaseStatus::from(release_data.info.status.as_str()),
-                    chart: release_data.chart.metadata.name,
-                    chart_version: release_data.chart.metadata.version,
-                    app_version: release_data.chart.metadata.app_version,
-                    deployed: release_data.info.last_deployed,
-                    description: release_data.info.description.unwrap_or_default(),
-                });
+                history.push(history_entry(&release_data));
             }
         }
     }
@@ -563,8 +616,9 @@ pub async fn get_helm_release_values(
     name: String,
     namespace: String,
     revision: Option<i32>,
+    context: Option<String>,
 ) -> Result<serde_json::Value, KubeliError> {
-    let detail = get_helm_release(state, name, namespace, revision).await?;
+    let detail = get_helm_release(state, name, namespace, revision, context).await?;
     Ok(detail.values)
 }
 
@@ -575,53 +629,1712 @@ pub async fn get_helm_release_manifest(
     name: String,
     namespace: String,
     revision: Option<i32>,
+    context: Option<String>,
 ) -> Result<String, KubeliError> {
-    let detail = get_helm_release(state, name, namespace, revision).await?;
+    let detail = get_helm_release(state, name, namespace, revision, context).await?;
     Ok(detail.manifest)
 }
 
-/// Forget a native Helm release by deleting all its release secrets.
-/// Deployed manifest resources are intentionally left running - the UI
-/// presents this as "Forget release", not a full `helm uninstall`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HelmUninstallOptions {
+    /// Mark the release uninstalled instead of deleting its history, like
+    /// `helm uninstall --keep-history`
+    pub keep_history: bool,
+    /// Progress is emitted on `helm-uninstall-{operation_id}` when set
+    pub operation_id: Option<String>,
+}
+
+/// One object of an uninstalled release
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmUninstalledResource {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub outcome: HelmResourceOutcome,
+    pub error: Option<KubeliError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum HelmUninstallEvent {
+    Started {
+        total: usize,
+    },
+    Resource(HelmUninstalledResource),
+    Finished {
+        deleted: usize,
+        kept: usize,
+        failed: usize,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmUninstallResult {
+    pub resources: Vec<HelmUninstalledResource>,
+    /// Release history was kept: by request, or because objects failed to
+    /// delete and the uninstall has to be retried
+    pub history_kept: bool,
+}
+
+/// Uninstall a native Helm release like `helm uninstall --no-hooks`: the
+/// objects of its current manifest are deleted in reverse dependency order,
+/// except ones annotated `helm.sh/resource-policy: keep`, then its release
+/// secrets are deleted (or the release is marked uninstalled with
+/// `keep_history`). When an object fails to delete, the history stays and
+/// the release is marked failed so the uninstall can be retried.
 #[command]
 pub async fn uninstall_helm_release(
+    app: AppHandle,
     state: State<'_, AppState>,
     name: String,
     namespace: String,
-) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await?;
-    let api: Api<Secret> = Api::namespaced(client, &namespace);
+    options: Option<HelmUninstallOptions>,
+    context: Option<String>,
+) -> Result<HelmUninstallResult, KubeliError> {
+    let options = options.unwrap_or_default();
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
+
+    let mut releases = load_stored_releases(&api, &name).await?;
+    let Some(current) = releases.last_mut() else {
+        return Err(KubeliError::new(
+            ErrorKind::NotFound,
+            format!(
+                "Helm release '{}' not found in namespace '{}'",
+                name, namespace
+            ),
+        ));
+    };
+
+    let already_uninstalled = current.status() == "uninstalled";
+    if already_uninstalled && options.keep_history {
+        return Err(KubeliError::new(
+            ErrorKind::Conflict,
+            format!("Helm release '{}' is already uninstalled", name),
+        ));
+    }
+
+    let emit = |event: HelmUninstallEvent| {
+        if let Some(id) = &options.operation_id {
+            let _ = app.emit(&format!("helm-uninstall-{}", id), event);
+        }
+    };
+
+    let mut resources = Vec::new();
+    // An uninstalled release kept its history; its objects are long gone
+    if !already_uninstalled {
+        let manifests = release_uninstall_objects(current.manifest())?;
+        mark_release(&mut current.release, "uninstalling", None);
+        update_stored_release(&api, current).await?;
+
+        emit(HelmUninstallEvent::Started {
+            total: manifests.len(),
+        });
+        for manifest in &manifests {
+            let resource = match resolve_manifest_in(&client, manifest, &namespace).await {
+                Ok(target) => {
+                    let (outcome, error) = match delete_release_object(&target).await {
+                        Ok(outcome) => (outcome, None),
+                        Err(err) => (HelmResourceOutcome::Failed, Some(err)),
+                    };
+                    HelmUninstalledResource {
+                        kind: target.kind,
+                        name: target.name,
+                        namespace: target.namespace,
+                        outcome,
+                        error,
+                    }
+                }
+                Err(err) => {
+                    let (_, kind, namespace, name) = manifest_key(manifest);
+                    HelmUninstalledResource {
+                        kind,
+                        name,
+                        namespace,
+                        outcome: HelmResourceOutcome::Failed,
+                        error: Some(err),
+                    }
+                }
+            };
+            emit(HelmUninstallEvent::Resource(resource.clone()));
+            resources.push(resource);
+        }
+    }
+
+    let count =
+        |outcome: HelmResourceOutcome| resources.iter().filter(|r| r.outcome == outcome).count();
+    let (deleted, kept, failed) = (
+        count(HelmResourceOutcome::Deleted),
+        count(HelmResourceOutcome::Kept),
+        count(HelmResourceOutcome::Failed),
+    );
+
+    let history_kept = options.keep_history || failed > 0;
+    if failed > 0 {
+        mark_release(
+            &mut current.release,
+            "failed",
+            Some(format!(
+                "Uninstallation failed: {} object(s) could not be deleted",
+                failed
+            )),
+        );
+        update_stored_release(&api, current).await?;
+    } else if options.keep_history {
+        current.release["info"]["deleted"] = Value::from(helm_timestamp());
+        mark_release(
+            &mut current.release,
+            "uninstalled",
+            Some("Uninstallation complete".to_string()),
+        );
+        update_stored_release(&api, current).await?;
+    } else {
+        // Including revisions that were skipped because they didn't decode
+        for (_, secret) in release_secrets(&api, &name).await? {
+            api.delete(&secret.name_any(), &DeleteParams::default())
+                .await?;
+        }
+    }
 
-    // Find all secrets for this release (all revisions)
+    emit(HelmUninstallEvent::Finished {
+        deleted,
+        kept,
+        failed,
+    });
+    tracing::info!(
+        "Uninstalled helm release {}/{}: {} deleted, {} kept, {} failed",
+        namespace,
+        name,
+        deleted,
+        kept,
+        failed
+    );
+    Ok(HelmUninstallResult {
+        resources,
+        history_kept,
+    })
+}
+
+/// Secret type of Helm's secret storage driver
+const HELM_RELEASE_SECRET_TYPE: &str = "helm.sh/release.v1";
+/// Resources annotated with this policy survive their removal from a release
+const RESOURCE_POLICY_ANNOTATION: &str = "helm.sh/resource-policy";
+
+fn release_secret_name(name: &str, revision: i32) -> String {
+    format!("sh.helm.release.v1.{}.v{}", name, revision)
+}
+
+/// Helm's timestamp format (Go RFC 3339 with nanoseconds)
+fn helm_timestamp() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Nanos, true)
+}
+
+/// One revision as stored in its release secret
+struct StoredRelease {
+    secret: Secret,
+    release: Value,
+    version: i32,
+}
+
+impl StoredRelease {
+    fn status(&self) -> &str {
+        self.release["info"]["status"].as_str().unwrap_or_default()
+    }
+
+    fn manifest(&self) -> &str {
+        self.release["manifest"].as_str().unwrap_or_default()
+    }
+}
+
+/// The release secrets of every revision of a release, oldest first
+async fn release_secrets(api: &Api<Secret>, name: &str) -> Result<Vec<(i32, Secret)>, KubeliError> {
     let lp = ListParams::default().labels(&format!("owner=helm,name={}", name));
-    let secrets: Vec<Secret> = api.list(&lp).await?.items;
+    let prefix = format!("sh.helm.release.v1.{}.v", name);
 
-    let prefix = format!("sh.helm.release.v1.{}.", name);
-    let release_secrets: Vec<&Secret> = secrets
-        .iter()
-        .filter(|s| {
-            s.metadata
+    let mut secrets: Vec<(i32, Secret)> = api
+        .list(&lp)
+        .await?
+        .items
+        .into_iter()
+        .filter_map(|secret| {
+            let version = secret
+                .metadata
                 .name
-                .as_ref()
-                .map(|n| n.starts_with(&prefix))
-                .unwrap_or(false)
+                .as_deref()
+                .and_then(|n| n.strip_prefix(&prefix))
+                .and_then(|v| v.parse::<i32>().ok())?;
+            Some((version, secret))
         })
         .collect();
+    secrets.sort_by_key(|(version, _)| *version);
+    Ok(secrets)
+}
+
+/// Every stored revision of a release, oldest first. Only the latest one
+/// has to decode; older revisions that don't are skipped with a warning.
+async fn load_stored_releases(
+    api: &Api<Secret>,
+    name: &str,
+) -> Result<Vec<StoredRelease>, KubeliError> {
+    let secrets = release_secrets(api, name).await?;
+    let latest = secrets.last().map(|(version, _)| *version);
+
+    let mut releases = Vec::new();
+    for (version, secret) in secrets {
+        let Some(data) = secret.data.as_ref().and_then(|d| d.get("release")) else {
+            continue;
+        };
+        let data_str = String::from_utf8_lossy(&data.0).into_owned();
+        let release = match decode_helm_release_json_blocking(data_str).await {
+            Ok(release) => release,
+            Err(err) if Some(version) != latest => {
+                tracing::warn!(
+                    "Skipping undecodable revision {} of Helm release '{}': {}",
+                    version,
+                    name,
+                    err
+                );
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        releases.push(StoredRelease {
+            secret,
+            release,
+            version,
+        });
+    }
+    Ok(releases)
+}
 
-    if release_secrets.is_empty() {
-        return Err(KubeliError::unknown(format!(
-            "Helm release '{}' not found in namespace '{}'",
-            name, namespace
-        )));
+/// Set a release's status (and description) in its JSON
+fn mark_release(release: &mut Value, status: &str, description: Option<String>) {
+    release["info"]["status"] = Value::from(status);
+    if let Some(description) = description {
+        release["info"]["description"] = Value::from(description);
     }
+}
 
-    // Delete all release secrets
-    for secret in release_secrets {
-        if let Some(secret_name) = &secret.metadata.name {
-            api.delete(secret_name, &kube::api::DeleteParams::default())
-                .await?;
+/// The release recorded for a rollback: `target`'s chart, values and
+/// manifest as revision `version`, pending until its manifest is applied
+fn rollback_release(target: &Value, current: &Value, version: i32, now: &str) -> Value {
+    let mut release = target.clone();
+    release["version"] = Value::from(version);
+    release["info"]["first_deployed"] = current["info"]["first_deployed"].clone();
+    release["info"]["last_deployed"] = Value::from(now);
+    mark_release(
+        &mut release,
+        "pending-rollback",
+        Some(format!("Rollback to {}", target["version"])),
+    );
+    release
+}
+
+/// Mirror Helm once a new revision has been applied: on failure only the
+/// revision it replaced is superseded, on success every revision still
+/// marked deployed is
+async fn supersede_releases(
+    api: &Api<Secret>,
+    releases: &mut [StoredRelease],
+    replaced_version: i32,
+    failed: bool,
+) -> Result<(), KubeliError> {
+    for stored in releases {
+        let supersede = if failed {
+            stored.version == replaced_version
+        } else {
+            stored.status() == "deployed"
+        };
+        if supersede {
+            mark_release(&mut stored.release, "superseded", None);
+            update_stored_release(api, stored).await?;
         }
     }
+    Ok(())
+}
+
+/// Release secret as Helm's secret driver writes it. Custom labels of
+/// `labels_from` (the revision the release derives from) are carried over.
+fn helm_release_secret(
+    release: &Value,
+    namespace: &str,
+    labels_from: Option<&Secret>,
+) -> Result<Secret, KubeliError> {
+    let name = release["name"].as_str().unwrap_or_default();
+    let version = release["version"].as_i64().unwrap_or_default() as i32;
+
+    let mut labels = labels_from
+        .and_then(|s| s.metadata.labels.clone())
+        .unwrap_or_default();
+    labels.remove("modifiedAt");
+    labels.insert("name".to_string(), name.to_string());
+    labels.insert("owner".to_string(), "helm".to_string());
+    labels.insert(
+        "status".to_string(),
+        release["info"]["status"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    );
+    labels.insert("version".to_string(), version.to_string());
+    labels.insert(
+        "createdAt".to_string(),
+        chrono::Utc::now().timestamp().to_string(),
+    );
+
+    let mut secret = Secret {
+        type_: Some(HELM_RELEASE_SECRET_TYPE.to_string()),
+        ..Default::default()
+    };
+    secret.metadata.name = Some(release_secret_name(name, version));
+    secret.metadata.namespace = Some(namespace.to_string());
+    secret.metadata.labels = Some(labels);
+    secret.data = Some(BTreeMap::from([(
+        "release".to_string(),
+        ByteString(encode_helm_release(release)?.into_bytes()),
+    )]));
+    Ok(secret)
+}
 
+/// Write a changed release back to its existing secret. The secret's
+/// resourceVersion makes this fail rather than overwrite a concurrent change.
+async fn update_stored_release(
+    api: &Api<Secret>,
+    stored: &mut StoredRelease,
+) -> Result<(), KubeliError> {
+    let mut secret = stored.secret.clone();
+    let labels = secret.metadata.labels.get_or_insert_with(BTreeMap::new);
+    labels.insert("status".to_string(), stored.status().to_string());
+    labels.insert(
+        "modifiedAt".to_string(),
+        chrono::Utc::now().timestamp().to_string(),
+    );
+    secret.data.get_or_insert_with(BTreeMap::new).insert(
+        "release".to_string(),
+        ByteString(encode_helm_release(&stored.release)?.into_bytes()),
+    );
+    stored.secret = api
+        .replace(&secret.name_any(), &PostParams::default(), &secret)
+        .await?;
     Ok(())
 }
+
+/// Objects of a release manifest in the order they are applied. Their
+/// namespace is left as rendered: only kinds discovery reports as namespaced
+/// go to the release namespace (see `resolve_manifest_in`).
+fn release_manifest_objects(manifest: &str) -> Result<Vec<Value>, KubeliError> {
+    let mut objects = parse_manifests(manifest)?;
+    objects.sort_by_key(apply_rank);
+    Ok(objects)
+}
+
+/// (group, kind, namespace, name) of a release object
+type ReleaseObjectKey = (String, String, Option<String>, String);
+
+fn failed_object(manifest: &Value, err: KubeliError) -> AppliedObject {
+    AppliedObject {
+        kind: manifest["kind"].as_str().unwrap_or_default().to_string(),
+        name: manifest["metadata"]["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        namespace: manifest["metadata"]["namespace"].as_str().map(String::from),
+        outcome: ApplyOutcome::Failed,
+        error: Some(err),
+    }
+}
+
+/// Server-side apply a release manifest, taking ownership of fields Helm or
+/// a previous apply set: the stored revision is what the objects must look like
+async fn apply_release_manifest(
+    client: &kube::Client,
+    manifests: Vec<Value>,
+    namespace: &str,
+) -> (Vec<AppliedObject>, HashSet<ReleaseObjectKey>) {
+    let mut objects = Vec::new();
+    let mut applied = HashSet::new();
+    let mut new_crds = Vec::new();
+
+    for manifest in manifests {
+        if apply_rank(&manifest) > 1 && !new_crds.is_empty() {
+            wait_for_crds(client, &std::mem::take(&mut new_crds)).await;
+        }
+        let target = match resolve_manifest_in(client, &manifest, namespace).await {
+            Ok(target) => target,
+            Err(err) => {
+                objects.push(failed_object(&manifest, err));
+                continue;
+            }
+        };
+        applied.insert((
+            target.group.clone(),
+            target.kind.clone(),
+            target.namespace.clone(),
+            target.name.clone(),
+        ));
+        let (outcome, error) = match apply_object(&target, ConflictResolution::Force).await {
+            Ok(outcome) => {
+                if target.kind == "CustomResourceDefinition" {
+                    new_crds.push(target.name.clone());
+                }
+                (outcome, None)
+            }
+            Err(err) => (ApplyOutcome::Failed, Some(err)),
+        };
+        objects.push(AppliedObject {
+            kind: target.kind,
+            name: target.name,
+            namespace: target.namespace,
+            outcome,
+            error,
+        });
+    }
+    (objects, applied)
+}
+
+/// Kinds in the order Helm installs them. Uninstalls walk it backwards.
+const HELM_INSTALL_ORDER: &[&str] = &[
+    "PriorityClass",
+    "Namespace",
+    "NetworkPolicy",
+    "ResourceQuota",
+    "LimitRange",
+    "PodSecurityPolicy",
+    "PodDisruptionBudget",
+    "ServiceAccount",
+    "Secret",
+    "SecretList",
+    "ConfigMap",
+    "StorageClass",
+    "PersistentVolume",
+    "PersistentVolumeClaim",
+    "CustomResourceDefinition",
+    "ClusterRole",
+    "ClusterRoleList",
+    "ClusterRoleBinding",
+    "ClusterRoleBindingList",
+    "Role",
+    "RoleList",
+    "RoleBinding",
+    "RoleBindingList",
+    "Service",
+    "DaemonSet",
+    "Pod",
+    "ReplicationController",
+    "ReplicaSet",
+    "Deployment",
+    "HorizontalPodAutoscaler",
+    "StatefulSet",
+    "Job",
+    "CronJob",
+    "IngressClass",
+    "Ingress",
+    "APIService",
+    "MutatingWebhookConfiguration",
+    "ValidatingWebhookConfiguration",
+];
+
+/// Position in uninstall order: Helm's install order reversed. Kinds Helm
+/// does not know (custom resources) go last, sorted by kind, as Helm's kind
+/// sorter places them.
+fn uninstall_rank(manifest: &Value) -> (usize, String) {
+    let kind = manifest["kind"].as_str().unwrap_or_default();
+    match HELM_INSTALL_ORDER.iter().rev().position(|k| *k == kind) {
+        Some(position) => (position, String::new()),
+        None => (HELM_INSTALL_ORDER.len(), kind.to_string()),
+    }
+}
+
+/// Objects of a release manifest in the order they are deleted
+fn release_uninstall_objects(manifest: &str) -> Result<Vec<Value>, KubeliError> {
+    let mut objects = release_manifest_objects(manifest)?;
+    objects.sort_by_key(uninstall_rank);
+    Ok(objects)
+}
+
+/// What deleting one release object did
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum HelmResourceOutcome {
+    Deleted,
+    /// Annotated `helm.sh/resource-policy: keep`
+    Kept,
+    /// Already gone
+    Missing,
+    Failed,
+}
+
+async fn delete_release_object(
+    target: &ManifestTarget,
+) -> Result<HelmResourceOutcome, KubeliError> {
+    let Some(live) = target.api.get_opt(&target.name).await? else {
+        return Ok(HelmResourceOutcome::Missing);
+    };
+    if live
+        .annotations()
+        .get(RESOURCE_POLICY_ANNOTATION)
+        .map(String::as_str)
+        == Some("keep")
+    {
+        return Ok(HelmResourceOutcome::Kept);
+    }
+    match target
+        .api
+        .delete(&target.name, &DeleteParams::background())
+        .await
+    {
+        Ok(_) => Ok(HelmResourceOutcome::Deleted),
+        Err(kube::Error::Api(status)) if status.code == 404 => Ok(HelmResourceOutcome::Missing),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Delete the objects of `manifest` that are not in `keep`, as Helm does for
+/// objects a revision no longer renders. Kept and already deleted objects
+/// are not reported.
+async fn delete_release_objects(
+    client: &kube::Client,
+    manifest: &str,
+    namespace: &str,
+    keep: &HashSet<ReleaseObjectKey>,
+) -> Vec<AppliedObject> {
+    let manifests = match release_uninstall_objects(manifest) {
+        Ok(manifests) => manifests,
+        Err(err) => {
+            tracing::warn!("Skipping cleanup of unparsable release manifest: {}", err);
+            return Vec::new();
+        }
+    };
+
+    let mut deleted = Vec::new();
+    for manifest in &manifests {
+        let target = match resolve_manifest_in(client, manifest, namespace).await {
+            Ok(target) => target,
+            Err(err) => {
+                deleted.push(failed_object(manifest, err));
+                continue;
+            }
+        };
+        let key = (
+            target.group.clone(),
+            target.kind.clone(),
+            target.namespace.clone(),
+            target.name.clone(),
+        );
+        if keep.contains(&key) {
+            continue;
+        }
+        let (outcome, error) = match delete_release_object(&target).await {
+            Ok(HelmResourceOutcome::Deleted) => (ApplyOutcome::Pruned, None),
+            Ok(_) => continue,
+            Err(err) => (ApplyOutcome::Failed, Some(err)),
+        };
+        deleted.push(AppliedObject {
+            kind: target.kind,
+            name: target.name,
+            namespace: target.namespace,
+            outcome,
+            error,
+        });
+    }
+    deleted
+}
+
+/// Revision recorded by a rollback and what happened to each object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmRollbackResult {
+    pub revision: i32,
+    /// Deployed, or Failed when an object could not be applied or deleted
+    pub status: HelmReleaseStatus,
+    pub objects: Vec<AppliedObject>,
+}
+
+/// Roll a native Helm release back to `revision` (the one before the
+/// current when omitted), like `helm rollback --no-hooks`. The revision's
+/// stored manifest is server-side applied and objects only the current
+/// revision had are deleted. The history gets a new revision that goes
+/// pending-rollback -> deployed (or failed), and earlier deployed revisions
+/// become superseded, so the Helm CLI reads a consistent history.
+#[command]
+pub async fn rollback_helm_release(
+    state: State<'_, AppState>,
+    name: String,
+    namespace: String,
+    revision: Option<i32>,
+    context: Option<String>,
+) -> Result<HelmRollbackResult, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
+
+    let mut releases = load_stored_releases(&api, &name).await?;
+    let current_index = releases.len().checked_sub(1).ok_or_else(|| {
+        KubeliError::new(
+            ErrorKind::NotFound,
+            format!(
+                "Helm release '{}' not found in namespace '{}'",
+                name, namespace
+            ),
+        )
+    })?;
+    let current_version = releases[current_index].version;
+    if releases[current_index].status().starts_with("pending-") {
+        return Err(KubeliError::new(
+            ErrorKind::Conflict,
+            format!(
+                "Another operation on release '{}' is in progress ({})",
+                name,
+                releases[current_index].status()
+            ),
+        ));
+    }
+
+    let target_version = revision.unwrap_or(current_version - 1);
+    let target = releases
+        .iter()
+        .find(|r| r.version == target_version)
+        .ok_or_else(|| {
+            KubeliError::new(
+                ErrorKind::NotFound,
+                format!("Release '{}' has no revision {}", name, target_version),
+            )
+        })?;
+
+    // Parsed before anything is written: a revision that cannot be applied
+    // must not leave a pending release behind
+    let manifests = release_manifest_objects(target.manifest())?;
+
+    let new_version = current_version + 1;
+    let mut rollback = rollback_release(
+        &target.release,
+        &releases[current_index].release,
+        new_version,
+        &helm_timestamp(),
+    );
+    let secret = helm_release_secret(&rollback, &namespace, Some(&target.secret))?;
+    let mut secret = api.create(&PostParams::default(), &secret).await?;
+    tracing::info!(
+        "Rolling back helm release {}/{} to revision {} as revision {}",
+        namespace,
+        name,
+        target_version,
+        new_version
+    );
+
+    let (mut objects, applied) = apply_release_manifest(&client, manifests, &namespace).await;
+    objects.extend(
+        delete_release_objects(
+            &client,
+            releases[current_index].manifest(),
+            &namespace,
+            &applied,
+        )
+        .await,
+    );
+
+    let failure = objects
+        .iter()
+        .find_map(|obj| obj.error.as_ref())
+        .map(|err| err.message.clone());
+
+    supersede_releases(&api, &mut releases, current_version, failure.is_some()).await?;
+
+    let status = match &failure {
+        Some(message) => {
+            mark_release(
+                &mut rollback,
+                "failed",
+                Some(format!("Rollback \"{}\" failed: {}", name, message)),
+            );
+            HelmReleaseStatus::Failed
+        }
+        None => {
+            mark_release(&mut rollback, "deployed", None);
+            HelmReleaseStatus::Deployed
+        }
+    };
+    secret.metadata.managed_fields = None;
+    let mut recorded = StoredRelease {
+        secret,
+        release: rollback,
+        version: new_version,
+    };
+    update_stored_release(&api, &mut recorded).await?;
+
+    Ok(HelmRollbackResult {
+        revision: new_version,
+        status,
+        objects,
+    })
+}
+
+/// One resource that differs between two revisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmResourceDiff {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>,
+    pub change: ChangeKind,
+    /// The resource in the older revision, as YAML
+    pub from: Option<String>,
+    /// The resource in the newer revision, as YAML
+    pub to: Option<String>,
+    /// Field changes of a changed resource (`live` is the older value,
+    /// `result` the newer); empty for added and removed resources
+    pub changes: Vec<FieldChange>,
+}
+
+/// What changed between two revisions of a release
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmRevisionDiff {
+    pub from: HelmReleaseHistoryEntry,
+    pub to: HelmReleaseHistoryEntry,
+    pub resources: Vec<HelmResourceDiff>,
+    /// Changes of the user-supplied values
+    pub values: Vec<FieldChange>,
+}
+
+/// Identity of a manifest object across revisions. The API version is left
+/// out so a chart moving a kind to a newer version reads as a change.
+fn manifest_key(manifest: &Value) -> ReleaseObjectKey {
+    let api_version = manifest["apiVersion"].as_str().unwrap_or_default();
+    let group = api_version
+        .split_once('/')
+        .map(|(group, _)| group)
+        .unwrap_or_default();
+    (
+        group.to_string(),
+        manifest["kind"].as_str().unwrap_or_default().to_string(),
+        manifest["metadata"]["namespace"].as_str().map(String::from),
+        manifest["metadata"]["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    )
+}
+
+fn manifest_objects_by_key(
+    manifest: &str,
+) -> Result<BTreeMap<ReleaseObjectKey, Value>, KubeliError> {
+    Ok(parse_manifests(manifest)?
+        .into_iter()
+        .map(|object| (manifest_key(&object), object))
+        .collect())
+}
+
+/// Added, removed and changed resources from one release manifest to
+/// another, ordered by kind, namespace and name. Unchanged ones are left out.
+fn diff_release_manifests(from: &str, to: &str) -> Result<Vec<HelmResourceDiff>, KubeliError> {
+    let from = manifest_objects_by_key(from)?;
+    let to = manifest_objects_by_key(to)?;
+    let keys: BTreeSet<&ReleaseObjectKey> = from.keys().chain(to.keys()).collect();
+
+    let mut diffs = Vec::new();
+    for key in keys {
+        let (old, new) = (from.get(key), to.get(key));
+        let (change, changes) = match (old, new) {
+            (None, Some(_)) => (ChangeKind::Added, Vec::new()),
+            (Some(_), None) => (ChangeKind::Removed, Vec::new()),
+            (Some(old), Some(new)) => {
+                let changes = diff_json(Some(old), new);
+                if changes.is_empty() {
+                    continue;
+                }
+                (ChangeKind::Changed, changes)
+            }
+            (None, None) => continue,
+        };
+        let (_, kind, namespace, name) = key.clone();
+        diffs.push(HelmResourceDiff {
+            kind,
+            name,
+            namespace,
+            change,
+            from: old.map(serde_yaml::to_string).transpose()?,
+            to: new.map(serde_yaml::to_string).transpose()?,
+            changes,
+        });
+    }
+    diffs.sort_by(|a, b| (&a.kind, &a.namespace, &a.name).cmp(&(&b.kind, &b.namespace, &b.name)));
+    Ok(diffs)
+}
+
+/// Values as an object: releases installed without values store null
+fn values_object(values: &Value) -> Value {
+    match values {
+        Value::Null => Value::Object(Default::default()),
+        other => other.clone(),
+    }
+}
+
+/// Compare two revisions of a native Helm release: resources added, removed
+/// or changed in the manifest, and the values diff. `to_revision` defaults
+/// to the latest revision and `from_revision` to the one before it.
+#[command]
+pub async fn diff_helm_revisions(
+    state: State<'_, AppState>,
+    name: String,
+    namespace: String,
+    from_revision: Option<i32>,
+    to_revision: Option<i32>,
+    context: Option<String>,
+) -> Result<HelmRevisionDiff, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let api: Api<Secret> = Api::namespaced(client, &namespace);
+
+    let to_revision = match to_revision {
+        Some(revision) => revision,
+        None => {
+            let lp = ListParams::default().labels(&format!("owner=helm,name={}", name));
+            get_latest_revision(&api.list(&lp).await?.items, &name)
+        }
+    };
+    let from_revision = from_revision.unwrap_or(to_revision - 1);
+
+    let from = load_release_revision(&api, &name, from_revision).await?;
+    let to = load_release_revision(&api, &name, to_revision).await?;
+
+    let resources = diff_release_manifests(&from.manifest, &to.manifest)?;
+    let values = diff_json(
+        Some(&values_object(&from.config)),
+        &values_object(&to.config),
+    );
+
+    Ok(HelmRevisionDiff {
+        from: history_entry(&from),
+        to: history_entry(&to),
+        resources,
+        values,
+    })
+}
+
+/// Options of a chart install
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HelmInstallOptions {
+    /// Values merged over the chart's defaults, like `--values`
+    pub values: Option<Value>,
+    /// Create the release namespace when missing, like `--create-namespace`
+    pub create_namespace: bool,
+    /// Reuse the name of an uninstalled release that kept its history,
+    /// like `--replace`
+    pub replace: bool,
+}
+
+/// Options of a release upgrade
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HelmUpgradeOptions {
+    /// Values merged over the chart's defaults, like `--values`
+    pub values: Option<Value>,
+    /// Merge `values` over the current revision's values instead of
+    /// starting from the chart's defaults, like `--reuse-values`
+    pub reuse_values: bool,
+}
+
+/// Revision recorded by an install or upgrade and what happened to each
+/// object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmInstallResult {
+    pub revision: i32,
+    /// Deployed, or Failed when an object could not be applied
+    pub status: HelmReleaseStatus,
+    /// On install the chart CRDs first (created only when absent), then the
+    /// manifest; an upgrade also lists the objects it deleted
+    pub objects: Vec<AppliedObject>,
+    pub notes: Option<String>,
+    /// Chart hooks for this operation that were not run
+    pub skipped_hooks: Vec<HelmSkippedHook>,
+}
+
+/// A chart hook left out by an install or upgrade
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmSkippedHook {
+    pub name: String,
+    pub kind: String,
+    /// The operation's hook events it is registered for, e.g. `pre-install`
+    pub events: Vec<String>,
+}
+
+/// Find the Helm CLI in its usual install locations, falling back to
+/// `which` with the extended PATH
+async fn find_helm_cli() -> Option<String> {
+    let home = std::env::var("HOME").unwrap_or_default();
+    let possible_paths = [
+        format!("{}/.local/bin/helm", home),
+        "/opt/homebrew/bin/helm".to_string(),
+        "/usr/local/bin/helm".to_string(),
+        "/usr/bin/helm".to_string(),
+    ];
+    if let Some(path) = possible_paths.iter().find(|p| Path::new(p).exists()) {
+        return Some(path.clone());
+    }
+
+    let output = Command::new("which")
+        .arg("helm")
+        .env("PATH", get_extended_path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .await
+        .ok()?;
+    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !path.is_empty()).then_some(path)
+}
+
+/// Run the Helm CLI and return its stdout. `stdin` is piped in and closed.
+async fn run_helm(helm: &str, args: &[&str], stdin: Option<&[u8]>) -> Result<String, KubeliError> {
+    let mut child = Command::new(helm)
+        .args(args)
+        .env("PATH", get_extended_path())
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run helm: {}", e))?;
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        pipe.write_all(input).await?;
+    }
+    let output = child
+        .wait_with_output()
+        .await
+        .map_err(|e| format!("Failed to run helm: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "helm {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// A chart directory or a packaged chart. Anything else would make the
+/// Helm CLI look the chart up in its repositories.
+fn is_local_chart(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    path.join("Chart.yaml").is_file()
+        || (path.is_file() && (name.ends_with(".tgz") || name.ends_with(".tar.gz")))
+}
+
+fn ensure_local_chart(chart_path: &str) -> Result<(), KubeliError> {
+    if is_local_chart(Path::new(chart_path)) {
+        return Ok(());
+    }
+    Err(KubeliError::new(
+        ErrorKind::NotFound,
+        format!(
+            "'{}' is neither a chart directory nor a packaged chart",
+            chart_path
+        ),
+    ))
+}
+
+/// User-supplied chart values; they must form a mapping
+fn chart_values(values: Option<Value>) -> Result<Value, KubeliError> {
+    match values {
+        None | Some(Value::Null) => Ok(Value::Object(Default::default())),
+        Some(values @ Value::Object(_)) => Ok(values),
+        Some(_) => Err("Chart values must be a mapping".into()),
+    }
+}
+
+/// Oldest Helm CLI with `--dry-run=client`
+const MIN_HELM_VERSION: (u64, u64) = (3, 13);
+
+/// Major and minor version of `helm version --short` output such as
+/// `v3.14.2+gc309b6f`
+fn parse_helm_version(output: &str) -> Option<(u64, u64)> {
+    let version = output.trim().strip_prefix('v')?;
+    let mut parts = version.split(['.', '+', '-']);
+    Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+}
+
+/// The Helm CLI, if it is recent enough to render charts client-side
+async fn helm_cli() -> Result<String, KubeliError> {
+    let helm = find_helm_cli().await.ok_or_else(|| {
+        KubeliError::new(
+            ErrorKind::NotFound,
+            "Helm CLI not found in PATH; it is needed to render charts",
+        )
+    })?;
+    let output = run_helm(&helm, &["version", "--short"], None).await?;
+    match parse_helm_version(&output) {
+        Some(version) if version >= MIN_HELM_VERSION => Ok(helm),
+        _ => Err(KubeliError::unknown(format!(
+            "Helm {}.{} or newer is needed to render charts, found {}",
+            MIN_HELM_VERSION.0,
+            MIN_HELM_VERSION.1,
+            output.trim()
+        ))),
+    }
+}
+
+/// Hooks of a rendered release that `operation` (`install` or `upgrade`)
+/// would have run; they are skipped like with `--no-hooks`
+fn skipped_hooks(release: &Value, operation: &str) -> Vec<HelmSkippedHook> {
+    let phases = [format!("pre-{}", operation), format!("post-{}", operation)];
+    release["hooks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|hook| {
+            let events: Vec<String> = hook["events"]
+                .as_array()?
+                .iter()
+                .filter_map(|e| e.as_str())
+                .filter(|e| phases.iter().any(|p| p == e))
+                .map(String::from)
+                .collect();
+            (!events.is_empty()).then(|| HelmSkippedHook {
+                name: hook["name"].as_str().unwrap_or_default().to_string(),
+                kind: hook["kind"].as_str().unwrap_or_default().to_string(),
+                events,
+            })
+        })
+        .collect()
+}
+
+/// Render a chart client-side as release `name` (`--dry-run=client`,
+/// Helm 3.13+) and return the release the Helm CLI would record
+async fn render_chart(
+    helm: &str,
+    name: &str,
+    chart_path: &str,
+    namespace: &str,
+    values: &Value,
+) -> Result<Value, KubeliError> {
+    let rendered = run_helm(
+        helm,
+        &[
+            "install",
+            name,
+            chart_path,
+            "--namespace",
+            namespace,
+            "--dry-run=client",
+            "--no-hooks",
+            "--output",
+            "json",
+            "--values",
+            "-",
+        ],
+        Some(&serde_json::to_vec(values)?),
+    )
+    .await?;
+    Ok(serde_json::from_str(&rendered)?)
+}
+
+/// The release recorded for an install: the Helm CLI's client-side dry-run
+/// release as revision `version`, pending until its manifest is applied
+fn install_release(dry_run: Value, version: i32, now: &str) -> Value {
+    let mut release = dry_run;
+    release["version"] = Value::from(version);
+    release["info"]["first_deployed"] = Value::from(now);
+    release["info"]["last_deployed"] = Value::from(now);
+    release["info"]["deleted"] = Value::from("");
+    release["config"] = values_object(&release["config"]);
+    mark_release(
+        &mut release,
+        "pending-install",
+        Some("Initial install underway".to_string()),
+    );
+    release
+}
+
+/// The release recorded for an upgrade of `current`: the Helm CLI's
+/// client-side dry-run release as revision `version`, pending until its
+/// manifest is applied
+fn upgrade_release(dry_run: Value, current: &Value, version: i32, now: &str) -> Value {
+    let mut release = install_release(dry_run, version, now);
+    release["info"]["first_deployed"] = current["info"]["first_deployed"].clone();
+    mark_release(
+        &mut release,
+        "pending-upgrade",
+        Some("Preparing upgrade".to_string()),
+    );
+    release
+}
+
+/// Merge `overrides` into `base` like Helm coalesces values: mappings merge
+/// key by key, anything else replaces what was there
+fn merge_values(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
+
+async fn ensure_namespace(
+    client: &kube::Client,
+    namespace: &str,
+) -> Result<AppliedObject, KubeliError> {
+    let api: Api<Namespace> = Api::all(client.clone());
+    let outcome = if api.get_opt(namespace).await?.is_some() {
+        ApplyOutcome::Unchanged
+    } else {
+        let mut ns = Namespace::default();
+        ns.metadata.name = Some(namespace.to_string());
+        api.create(&PostParams::default(), &ns).await?;
+        ApplyOutcome::Created
+    };
+    Ok(AppliedObject {
+        kind: "Namespace".to_string(),
+        name: namespace.to_string(),
+        namespace: None,
+        outcome,
+        error: None,
+    })
+}
+
+/// Create the chart's CRDs that do not exist yet. Like Helm, existing CRDs
+/// are never updated.
+async fn create_chart_crds(client: &kube::Client, crds: Vec<Value>) -> Vec<AppliedObject> {
+    let mut objects = Vec::new();
+    let mut created = Vec::new();
+    for crd in crds {
+        let target = match resolve_manifest(client, &crd).await {
+            Ok(target) => target,
+            Err(err) => {
+                objects.push(failed_object(&crd, err));
+                continue;
+            }
+        };
+        let result = match target.api.get_opt(&target.name).await {
+            Ok(Some(_)) => Ok(ApplyOutcome::Unchanged),
+            Ok(None) => apply_object(&target, ConflictResolution::Force).await,
+            Err(err) => Err(err.into()),
+        };
+        let (outcome, error) = match result {
+            Ok(outcome) => {
+                if matches!(outcome, ApplyOutcome::Created) {
+                    created.push(target.name.clone());
+                }
+                (outcome, None)
+            }
+            Err(err) => (ApplyOutcome::Failed, Some(err)),
+        };
+        objects.push(AppliedObject {
+            kind: target.kind,
+            name: target.name,
+            namespace: None,
+            outcome,
+            error,
+        });
+    }
+    wait_for_crds(client, &created).await;
+    objects
+}
+
+/// Install a chart from a local directory or `.tgz` like
+/// `helm install --no-hooks`. The Helm CLI renders the chart client-side
+/// (`--dry-run=client`, Helm 3.13+); its CRDs are created when absent, the
+/// manifest is server-side applied, and the release is recorded in Helm's
+/// secret storage as pending-install -> deployed (or failed). A name may be
+/// reused only with `replace` and when its latest revision is uninstalled.
+/// Rendering client-side means templates see Helm's default Capabilities
+/// (not the cluster's version or APIs) and `lookup` returns nothing.
+#[command]
+pub async fn install_helm_chart(
+    state: State<'_, AppState>,
+    chart_path: String,
+    name: String,
+    namespace: String,
+    options: Option<HelmInstallOptions>,
+    context: Option<String>,
+) -> Result<HelmInstallResult, KubeliError> {
+    let options = options.unwrap_or_default();
+    ensure_local_chart(&chart_path)?;
+    let values = chart_values(options.values)?;
+    let helm = helm_cli().await?;
+
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
+    let releases = load_stored_releases(&api, &name).await?;
+    if let Some(current) = releases.last() {
+        if !options.replace || current.status() != "uninstalled" {
+            return Err(KubeliError::new(
+                ErrorKind::Conflict,
+                format!(
+                    "Release '{}' already exists in namespace '{}' ({})",
+                    name,
+                    namespace,
+                    current.status()
+                ),
+            ));
+        }
+    }
+    let version = releases.last().map_or(1, |r| r.version + 1);
+
+    // Rendered and parsed before anything is written: a chart that cannot
+    // be installed must not leave a pending release behind
+    let rendered = render_chart(&helm, &name, &chart_path, &namespace, &values).await?;
+    let crds = parse_manifests(&run_helm(&helm, &["show", "crds", &chart_path], None).await?)?;
+    let mut release = install_release(rendered, version, &helm_timestamp());
+    let manifests = release_manifest_objects(release["manifest"].as_str().unwrap_or_default())?;
+
+    let mut objects = Vec::new();
+    if options.create_namespace {
+        objects.push(ensure_namespace(&client, &namespace).await?);
+    }
+    let secret = helm_release_secret(&release, &namespace, None)?;
+    let mut secret = api.create(&PostParams::default(), &secret).await?;
+    tracing::info!(
+        "Installing helm chart {} as release {}/{} revision {}",
+        chart_path,
+        namespace,
+        name,
+        version
+    );
+
+    objects.extend(create_chart_crds(&client, crds).await);
+    let (applied, _) = apply_release_manifest(&client, manifests, &namespace).await;
+    objects.extend(applied);
+
+    let failure = objects
+        .iter()
+        .find_map(|obj| obj.error.as_ref())
+        .map(|err| err.message.clone());
+    let status = match &failure {
+        Some(message) => {
+            mark_release(
+                &mut release,
+                "failed",
+                Some(format!("Release \"{}\" failed: {}", name, message)),
+            );
+            HelmReleaseStatus::Failed
+        }
+        None => {
+            mark_release(
+                &mut release,
+                "deployed",
+                Some("Install complete".to_string()),
+            );
+            HelmReleaseStatus::Deployed
+        }
+    };
+    let notes = release["info"]["notes"]
+        .as_str()
+        .filter(|n| !n.is_empty())
+        .map(String::from);
+    let skipped_hooks = skipped_hooks(&release, "install");
+    secret.metadata.managed_fields = None;
+    let mut recorded = StoredRelease {
+        secret,
+        release,
+        version,
+    };
+    update_stored_release(&api, &mut recorded).await?;
+
+    Ok(HelmInstallResult {
+        revision: version,
+        status,
+        objects,
+        notes,
+        skipped_hooks,
+    })
+}
+
+/// Upgrade a native Helm release to a chart from a local directory or
+/// `.tgz` like `helm upgrade --no-hooks`. The chart is rendered as for
+/// `install_helm_chart`, its manifest is server-side applied and objects
+/// only the current revision had are deleted. The history gets a new
+/// revision that goes pending-upgrade -> deployed (or failed), and earlier
+/// deployed revisions become superseded. Like Helm, CRDs are not touched.
+#[command]
+pub async fn upgrade_helm_release(
+    state: State<'_, AppState>,
+    name: String,
+    namespace: String,
+    chart_path: String,
+    options: Option<HelmUpgradeOptions>,
+    context: Option<String>,
+) -> Result<HelmInstallResult, KubeliError> {
+    let options = options.unwrap_or_default();
+    ensure_local_chart(&chart_path)?;
+    let mut values = chart_values(options.values)?;
+    let helm = helm_cli().await?;
+
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
+    let mut releases = load_stored_releases(&api, &name).await?;
+    let current_index = releases
+        .len()
+        .checked_sub(1)
+        .filter(|&i| !releases[i].status().starts_with("uninstall"))
+        .ok_or_else(|| {
+            KubeliError::new(
+                ErrorKind::NotFound,
+                format!(
+                    "Helm release '{}' not found in namespace '{}'",
+                    name, namespace
+                ),
+            )
+        })?;
+    let current = &releases[current_index];
+    if current.status().starts_with("pending-") {
+        return Err(KubeliError::new(
+            ErrorKind::Conflict,
+            format!(
+                "Another operation on release '{}' is in progress ({})",
+                name,
+                current.status()
+            ),
+        ));
+    }
+    let current_version = current.version;
+    if options.reuse_values {
+        let mut reused = values_object(&current.release["config"]);
+        merge_values(&mut reused, values);
+        values = reused;
+    }
+
+    // Rendered and parsed before anything is written: a chart that cannot
+    // be applied must not leave a pending release behind
+    let rendered = render_chart(&helm, &name, &chart_path, &namespace, &values).await?;
+    let new_version = current_version + 1;
+    let mut release = upgrade_release(rendered, &current.release, new_version, &helm_timestamp());
+    let manifests = release_manifest_objects(release["manifest"].as_str().unwrap_or_default())?;
+
+    let secret = helm_release_secret(&release, &namespace, Some(&current.secret))?;
+    let mut secret = api.create(&PostParams::default(), &secret).await?;
+    tracing::info!(
+        "Upgrading helm release {}/{} to chart {} as revision {}",
+        namespace,
+        name,
+        chart_path,
+        new_version
+    );
+
+    let (mut objects, applied) = apply_release_manifest(&client, manifests, &namespace).await;
+    objects.extend(
+        delete_release_objects(
+            &client,
+            releases[current_index].manifest(),
+            &namespace,
+            &applied,
+        )
+        .await,
+    );
+
+    let failure = objects
+        .iter()
+        .find_map(|obj| obj.error.as_ref())
+        .map(|err| err.message.clone());
+    supersede_releases(&api, &mut releases, current_version, failure.is_some()).await?;
+
+    let status = match &failure {
+        Some(message) => {
+            mark_release(
+                &mut release,
+                "failed",
+                Some(format!("Upgrade \"{}\" failed: {}", name, message)),
+            );
+            HelmReleaseStatus::Failed
+        }
+        None => {
+            mark_release(
+                &mut release,
+                "deployed",
+                Some("Upgrade complete".to_string()),
+            );
+            HelmReleaseStatus::Deployed
+        }
+    };
+    let notes = release["info"]["notes"]
+        .as_str()
+        .filter(|n| !n.is_empty())
+        .map(String::from);
+    let skipped_hooks = skipped_hooks(&release, "upgrade");
+    secret.metadata.managed_fields = None;
+    let mut recorded = StoredRelease {
+        secret,
+        release,
+        version: new_version,
+    };
+    update_stored_release(&api, &mut recorded).await?;
+
+    Ok(HelmInstallResult {
+        revision: new_version,
+        status,
+        objects,
+        notes,
+        skipped_hooks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn stored(version: i64, status: &str) -> Value {
+        json!({
+            "name": "web",
+            "version": version,
+            "info": {
+                "first_deployed": "2024-01-01T00:00:00Z",
+                "last_deployed": format!("2024-01-0{}T00:00:00Z", version),
+                "description": "Upgrade complete",
+                "status": status
+            },
+            "chart": {"metadata": {"name": "web", "version": format!("1.{}.0", version)}},
+            "config": {"replicas": version},
+            "manifest": "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: web\n",
+            "hooks": [{"name": "migrate"}]
+        })
+    }
+
+    #[test]
+    fn encoded_release_decodes_to_the_same_json() {
+        let release = stored(2, "deployed");
+        let encoded = encode_helm_release(&release).unwrap();
+        // The API server base64-encodes secret data once more
+        let stored_data = BASE64.encode(encoded.as_bytes());
+        let decoded: Value =
+            serde_json::from_str(&decompress_helm_release(&stored_data).unwrap()).unwrap();
+        assert_eq!(decoded, release);
+        assert_eq!(decode_helm_release(&encoded).unwrap().version, 2);
+    }
+
+    #[test]
+    fn rollback_release_copies_the_target_as_a_new_pending_revision() {
+        let target = stored(1, "superseded");
+        let mut current = stored(2, "deployed");
+        current["info"]["first_deployed"] = json!("2023-12-31T00:00:00Z");
+
+        let release = rollback_release(&target, &current, 3, "2024-02-01T00:00:00Z");
+        assert_eq!(release["version"], 3);
+        assert_eq!(release["info"]["status"], "pending-rollback");
+        assert_eq!(release["info"]["description"], "Rollback to 1");
+        assert_eq!(release["info"]["first_deployed"], "2023-12-31T00:00:00Z");
+        assert_eq!(release["info"]["last_deployed"], "2024-02-01T00:00:00Z");
+        assert_eq!(release["config"], target["config"]);
+        assert_eq!(release["hooks"], target["hooks"]);
+    }
+
+    #[test]
+    fn release_secret_carries_helm_labels() {
+        let release = stored(3, "pending-rollback");
+        let mut from = Secret::default();
+        from.metadata.labels = Some(BTreeMap::from([
+            ("team".to_string(), "web".to_string()),
+            ("modifiedAt".to_string(), "1".to_string()),
+        ]));
+
+        let secret = helm_release_secret(&release, "apps", Some(&from)).unwrap();
+        assert_eq!(
+            secret.metadata.name.as_deref(),
+            Some("sh.helm.release.v1.web.v3")
+        );
+        assert_eq!(secret.type_.as_deref(), Some(HELM_RELEASE_SECRET_TYPE));
+        let labels = secret.metadata.labels.unwrap();
+        assert_eq!(labels["owner"], "helm");
+        assert_eq!(labels["status"], "pending-rollback");
+        assert_eq!(labels["version"], "3");
+        assert_eq!(labels["team"], "web");
+        assert!(!labels.contains_key("modifiedAt"));
+    }
+
+    #[test]
+    fn release_objects_leave_namespaces_to_discovery() {
+        let objects = release_manifest_objects(
+            "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: a\n---\n\
+             apiVersion: v1\nkind: Namespace\nmetadata:\n  name: extra\n---\n\
+             apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: b\n  namespace: other\n",
+        )
+        .unwrap();
+        assert_eq!(objects[0]["kind"], "Namespace");
+        assert!(objects[0]["metadata"].get("namespace").is_none());
+        assert!(objects[1]["metadata"].get("namespace").is_none());
+        assert_eq!(objects[2]["metadata"]["namespace"], "other");
+    }
+
+    #[test]
+    fn revision_diff_calls_out_resources_by_kind_and_name() {
+        let from = "apiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: web\n\
+                    spec:\n  replicas: 1\n---\n\
+                    apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: old\n---\n\
+                    apiVersion: v1\nkind: Service\nmetadata:\n  name: web\n";
+        let to = "apiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: web\n\
+                  spec:\n  replicas: 3\n---\n\
+                  apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: new\n---\n\
+                  apiVersion: v1\nkind: Service\nmetadata:\n  name: web\n";
+
+        let diffs = diff_release_manifests(from, to).unwrap();
+        let summary: Vec<(&str, &str, &ChangeKind)> = diffs
+            .iter()
+            .map(|d| (d.kind.as_str(), d.name.as_str(), &d.change))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("ConfigMap", "new", &ChangeKind::Added),
+                ("ConfigMap", "old", &ChangeKind::Removed),
+                ("Deployment", "web", &ChangeKind::Changed),
+            ]
+        );
+        assert_eq!(diffs[2].changes.len(), 1);
+        assert_eq!(diffs[2].changes[0].path, "spec.replicas");
+        assert!(diffs[0].from.is_none() && diffs[0].to.is_some());
+    }
+
+    #[test]
+    fn values_diff_treats_missing_values_as_empty() {
+        let changes = diff_json(
+            Some(&values_object(&Value::Null)),
+            &values_object(&json!({"image": {"tag": "1.2"}})),
+        );
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "image.tag");
+        assert_eq!(changes[0].change, ChangeKind::Added);
+    }
+
+    #[test]
+    fn uninstall_order_reverses_dependencies() {
+        let objects = release_uninstall_objects(
+            "apiVersion: v1\nkind: Namespace\nmetadata:\n  name: extra\n---\n\
+             apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: conf\n---\n\
+             apiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: web\n---\n\
+             apiVersion: example.com/v1\nkind: Widget\nmetadata:\n  name: w\n---\n\
+             apiVersion: v1\nkind: Service\nmetadata:\n  name: web\n---\n\
+             apiVersion: example.com/v1\nkind: Gadget\nmetadata:\n  name: g\n",
+        )
+        .unwrap();
+        let kinds: Vec<&str> = objects.iter().filter_map(|o| o["kind"].as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "Deployment",
+                "Service",
+                "ConfigMap",
+                "Namespace",
+                "Gadget",
+                "Widget"
+            ]
+        );
+    }
+
+    #[test]
+    fn installed_release_starts_pending_as_the_given_revision() {
+        let dry_run = json!({
+            "name": "web",
+            "namespace": "apps",
+            "version": 1,
+            "info": {"status": "pending-install", "description": "Dry run complete"},
+            "chart": {"metadata": {"name": "web", "version": "1.0.0"}},
+            "manifest": "",
+        });
+        let release = install_release(dry_run, 3, "2024-01-01T00:00:00Z");
+        assert_eq!(release["version"], 3);
+        assert_eq!(release["info"]["status"], "pending-install");
+        assert_eq!(release["info"]["first_deployed"], "2024-01-01T00:00:00Z");
+        assert_eq!(release["config"], json!({}));
+        assert_eq!(
+            decode_helm_release(&encode_helm_release(&release).unwrap())
+                .unwrap()
+                .version,
+            3
+        );
+    }
+
+    #[test]
+    fn helm_versions_parse_from_the_short_output() {
+        assert_eq!(parse_helm_version("v3.14.2+gc309b6f\n"), Some((3, 14)));
+        assert_eq!(parse_helm_version("v3.9.0"), Some((3, 9)));
+        assert!(parse_helm_version("v3.9.0").unwrap() < MIN_HELM_VERSION);
+        assert!(parse_helm_version("v4.0.0-rc.1").unwrap() >= MIN_HELM_VERSION);
+        assert_eq!(parse_helm_version("unknown"), None);
+    }
+
+    #[test]
+    fn skipped_hooks_are_those_of_the_operation() {
+        let release = json!({
+            "hooks": [
+                {"name": "migrate", "kind": "Job", "events": ["pre-install", "pre-upgrade"]},
+                {"name": "smoke", "kind": "Pod", "events": ["test"]},
+                {"name": "cleanup", "kind": "Job", "events": ["post-delete"]},
+            ]
+        });
+        let hooks = skipped_hooks(&release, "install");
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0].name, "migrate");
+        assert_eq!(hooks[0].kind, "Job");
+        assert_eq!(hooks[0].events, vec!["pre-install"]);
+        assert!(skipped_hooks(&json!({}), "upgrade").is_empty());
+    }
+
+    #[test]
+    fn upgraded_release_keeps_the_first_deploy_time() {
+        let current = stored(2, "deployed");
+        let dry_run = json!({
+            "name": "web",
+            "version": 1,
+            "info": {"status": "pending-install"},
+            "config": {"replicas": 3},
+            "manifest": "",
+        });
+        let release = upgrade_release(dry_run, &current, 3, "2024-02-01T00:00:00Z");
+        assert_eq!(release["version"], 3);
+        assert_eq!(release["info"]["status"], "pending-upgrade");
+        assert_eq!(
+            release["info"]["first_deployed"],
+            current["info"]["first_deployed"]
+        );
+        assert_eq!(release["info"]["last_deployed"], "2024-02-01T00:00:00Z");
+        assert_eq!(release["config"], json!({"replicas": 3}));
+    }
+
+    #[test]
+    fn reused_values_merge_mappings_and_replace_the_rest() {
+        let mut values = json!({
+            "image": {"repository": "web", "tag": "1.0"},
+            "ports": [80],
+            "debug": true,
+        });
+        merge_values(
+            &mut values,
+            json!({"image": {"tag": "1.1"}, "ports": [8080], "replicas": 2}),
+        );
+        assert_eq!(
+            values,
+            json!({
+                "image": {"repository": "web", "tag": "1.1"},
+                "ports": [8080],
+                "debug": true,
+                "replicas": 2,
+            })
+        );
+    }
+
+    #[test]
+    fn local_charts_are_directories_with_chart_yaml_or_archives() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!is_local_chart(dir.path()));
+        std::fs::write(dir.path().join("Chart.yaml"), "name: web\n").unwrap();
+        assert!(is_local_chart(dir.path()));
+
+        let archive = dir.path().join("web-1.0.0.tgz");
+        assert!(!is_local_chart(&archive));
+        std::fs::write(&archive, b"").unwrap();
+        assert!(is_local_chart(&archive));
+        assert!(!is_local_chart(Path::new("bitnami/nginx")));
+    }
+}
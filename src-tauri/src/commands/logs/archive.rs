@@ -0,0 +1,667 @@
+//! Persistent log archive.
+//!
+//! Streams opened with `record: true` also write their lines to a local
+//! SQLite database, so logs survive pod rescheduling and kubelet rotation.
+//! Lines are indexed by cluster/namespace/pod/container and time, with an
+//! FTS5 index over the message for text search. The database is capped in
+//! size: once it grows past the limit, the oldest lines are dropped.
+
+use super::structured::parse_structured;
+use super::LogEntry;
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, params_from_iter, Connection, Result as SqliteResult};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Default size cap of the archive database
+const DEFAULT_MAX_BYTES: u64 = 256 * 1024 * 1024;
+/// Smallest accepted cap; anything lower would drop lines almost immediately
+const MIN_MAX_BYTES: u64 = 1024 * 1024;
+/// Upper bound on prune passes; each pass deletes in proportion to the excess
+const MAX_PRUNE_PASSES: usize = 4;
+/// Search results are capped so a broad query cannot exhaust memory
+const MAX_SEARCH_LIMIT: u32 = 10_000;
+const DEFAULT_SEARCH_LIMIT: u32 = 1_000;
+
+/// Failure of an archive operation
+#[derive(Debug)]
+pub enum LogArchiveError {
+    Sqlite(rusqlite::Error),
+    /// The blocking task that ran the query panicked or was cancelled
+    Task(String),
+}
+
+impl std::fmt::Display for LogArchiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogArchiveError::Sqlite(e) => write!(f, "{}", e),
+            LogArchiveError::Task(m) => write!(f, "log archive task failed: {}", m),
+        }
+    }
+}
+
+impl std::error::Error for LogArchiveError {}
+
+impl From<rusqlite::Error> for LogArchiveError {
+    fn from(err: rusqlite::Error) -> Self {
+        LogArchiveError::Sqlite(err)
+    }
+}
+
+pub type LogArchiveResult<T> = Result<T, LogArchiveError>;
+
+/// One archived line, as returned by searches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedLogLine {
+    pub id: i64,
+    pub context: String,
+    #[serde(flatten)]
+    pub entry: LogEntry,
+}
+
+/// Search over the archive. Every set field narrows the result.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LogArchiveQuery {
+    pub context: Option<String>,
+    pub namespace: Option<String>,
+    pub pod: Option<String>,
+    pub container: Option<String>,
+    /// Words or phrase to find in the message (full-text, case-insensitive)
+    pub text: Option<String>,
+    /// RFC3339 lower bound, inclusive
+    pub since: Option<String>,
+    /// RFC3339 upper bound, exclusive
+    pub until: Option<String>,
+    /// Maximum lines returned (default 1000, at most 10000)
+    pub limit: Option<u32>,
+    /// Newest lines first instead of chronological order
+    #[serde(default)]
+    pub newest_first: bool,
+}
+
+/// A pod/container with archived lines
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedLogSource {
+    pub context: String,
+    pub namespace: String,
+    pub pod: String,
+    pub container: String,
+    pub line_count: i64,
+    pub first_timestamp: String,
+    pub last_timestamp: String,
+}
+
+/// Size and retention state of the archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogArchiveStats {
+    pub line_count: i64,
+    pub size_bytes: u64,
+    pub max_bytes: u64,
+}
+
+/// Log archive using SQLite
+pub struct LogArchive {
+    conn: Arc<Mutex<Connection>>,
+}
+
+fn to_millis(raw: &str) -> Option<i64> {
+    DateTime::parse_from_rfc3339(raw)
+        .ok()
+        .map(|dt| dt.timestamp_millis())
+}
+
+fn from_millis(ms: i64) -> String {
+    DateTime::<Utc>::from_timestamp_millis(ms)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Quote user input as a single FTS5 phrase so operators and stray quotes
+/// in a search never turn into a query syntax error.
+fn fts_phrase(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+impl LogArchive {
+    /// Create a new log archive with database at the given path
+    pub fn new(db_path: PathBuf) -> SqliteResult<Self> {
+        // Ensure parent directory exists
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let conn = Connection::open(&db_path)?;
+        // WAL keeps searches from blocking the recorder and vice versa
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+
+        Self::migrate(&conn)?;
+
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Run database migrations
+    fn migrate(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS log_lines (
+                id INTEGER PRIMARY KEY,
+                context TEXT NOT NULL,
+                namespace TEXT NOT NULL,
+                pod TEXT NOT NULL,
+                container TEXT NOT NULL,
+                ts INTEGER NOT NULL,
+                level TEXT,
+                message TEXT NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_log_lines_source
+                ON log_lines(context, namespace, pod, container, ts);
+
+            CREATE INDEX IF NOT EXISTS idx_log_lines_ts
+                ON log_lines(ts);
+
+            CREATE VIRTUAL TABLE IF NOT EXISTS log_lines_fts USING fts5(
+                message,
+                content='log_lines',
+                content_rowid='id'
+            );
+
+            CREATE TRIGGER IF NOT EXISTS log_lines_ai AFTER INSERT ON log_lines BEGIN
+                INSERT INTO log_lines_fts(rowid, message) VALUES (new.id, new.message);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS log_lines_ad AFTER DELETE ON log_lines BEGIN
+                INSERT INTO log_lines_fts(log_lines_fts, rowid, message)
+                VALUES ('delete', old.id, old.message);
+            END;
+
+            CREATE TABLE IF NOT EXISTS settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );",
+        )
+    }
+
+    /// Run a closure against the connection on the blocking pool
+    async fn with_conn<T, F>(&self, f: F) -> LogArchiveResult<T>
+    where
+        F: FnOnce(&mut Connection) -> SqliteResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        let result = tauri::async_runtime::spawn_blocking(move || {
+            let mut conn = conn.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            f(&mut conn)
+        })
+        .await
+        .map_err(|e| LogArchiveError::Task(e.to_string()))?;
+        Ok(result?)
+    }
+
+    fn max_bytes_of(conn: &Connection) -> SqliteResult<u64> {
+        let value: Option<String> = conn
+            .query_row(
+                "SELECT value FROM settings WHERE key = 'max_bytes'",
+                [],
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                e => Err(e),
+            })?;
+        Ok(value
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_BYTES))
+    }
+
+    /// Bytes of the database actually holding data. Freed pages are reused
+    /// by later inserts, so keeping this under the cap bounds the file size.
+    fn used_bytes(conn: &Connection) -> SqliteResult<u64> {
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let free_pages: i64 = conn.query_row("PRAGMA freelist_count", [], |row| row.get(0))?;
+        Ok(((page_count - free_pages).max(0) * page_size) as u64)
+    }
+
+    /// Drop the oldest lines once the archive outgrows its cap. Pruning
+    /// goes down to 90% of the cap so it runs once per few megabytes of new
+    /// lines rather than on every batch.
+    fn enforce_retention(conn: &Connection) -> SqliteResult<usize> {
+        let max_bytes = Self::max_bytes_of(conn)?;
+        let target = max_bytes / 10 * 9;
+        // Start pruning past the cap, then keep going down to the target
+        let mut limit = max_bytes;
+        let mut removed = 0;
+        for _ in 0..MAX_PRUNE_PASSES {
+            let used = Self::used_bytes(conn)?;
+            if used <= limit {
+                break;
+            }
+            limit = target;
+            let count: i64 =
+                conn.query_row("SELECT COUNT(*) FROM log_lines", [], |row| row.get(0))?;
+            if count == 0 {
+                break;
+            }
+            let excess = 1.0 - target as f64 / used as f64;
+            let batch = ((count as f64 * excess).ceil() as i64).max(1);
+            removed += conn.execute(
+                "DELETE FROM log_lines WHERE id IN
+                 (SELECT id FROM log_lines ORDER BY id ASC LIMIT ?1)",
+                params![batch],
+            )?;
+            // Deletes only add tombstones to the FTS index; merge them away
+            // so the pages are actually freed
+            conn.execute(
+                "INSERT INTO log_lines_fts(log_lines_fts) VALUES ('optimize')",
+                [],
+            )?;
+        }
+        if removed > 0 {
+            tracing::debug!("Log archive retention dropped {} lines", removed);
+        }
+        Ok(removed)
+    }
+
+    /// Append a batch of streamed lines. Lines without a kubelet timestamp
+    /// are stamped with the time they were recorded.
+    pub async fn append(&self, context: String, entries: Vec<LogEntry>) -> LogArchiveResult<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.with_conn(move |conn| {
+            let now = Utc::now().timestamp_millis();
+            let tx = conn.transaction()?;
+            {
+                let mut stmt = tx.prepare_cached(
+                    "INSERT INTO log_lines
+                     (context, namespace, pod, container, ts, level, message)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                )?;
+                for entry in &entries {
+                    let ts = entry
+                        .timestamp
+                        .as_deref()
+                        .and_then(to_millis)
+                        .unwrap_or(now);
+                    stmt.execute(params![
+                        context,
+                        entry.namespace,
+                        entry.pod,
+                        entry.container,
+                        ts,
+                        entry.level.map(|level| level.as_str()),
+                        entry.message,
+                    ])?;
+                }
+            }
+            tx.commit()?;
+            Self::enforce_retention(conn)?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Search archived lines by source, time range and text
+    pub async fn search(&self, query: LogArchiveQuery) -> LogArchiveResult<Vec<ArchivedLogLine>> {
+        self.with_conn(move |conn| {
+            let mut sql = String::from(
+                "SELECT l.id, l.context, l.namespace, l.pod, l.container, l.ts, l.message
+                 FROM log_lines l",
+            );
+            let mut clauses: Vec<&str> = Vec::new();
+            let mut values: Vec<rusqlite::types::Value> = Vec::new();
+
+            if let Some(text) = query.text.as_deref().filter(|t| !t.trim().is_empty()) {
+                sql.push_str(" JOIN log_lines_fts f ON f.rowid = l.id");
+                clauses.push("log_lines_fts MATCH ?");
+                values.push(fts_phrase(text.trim()).into());
+            }
+            for (column, value) in [
+                ("l.context = ?", &query.context),
+                ("l.namespace = ?", &query.namespace),
+                ("l.pod = ?", &query.pod),
+                ("l.container = ?", &query.container),
+            ] {
+                if let Some(value) = value {
+                    clauses.push(column);
+                    values.push(value.clone().into());
+                }
+            }
+            for (column, bound) in [("l.ts >= ?", &query.since), ("l.ts < ?", &query.until)] {
+                if let Some(raw) = bound {
+                    let ms = to_millis(raw).ok_or_else(|| {
+                        rusqlite::Error::InvalidParameterName(format!(
+                            "invalid RFC3339 timestamp '{}'",
+                            raw
+                        ))
+                    })?;
+                    clauses.push(column);
+                    values.push(ms.into());
+                }
+            }
+
+            if !clauses.is_empty() {
+                sql.push_str(" WHERE ");
+                sql.push_str(&clauses.join(" AND "));
+            }
+            let order = if query.newest_first { "DESC" } else { "ASC" };
+            sql.push_str(&format!(" ORDER BY l.ts {order}, l.id {order} LIMIT ?"));
+            let limit = query
+                .limit
+                .unwrap_or(DEFAULT_SEARCH_LIMIT)
+                .min(MAX_SEARCH_LIMIT);
+            values.push(i64::from(limit).into());
+
+            let mut stmt = conn.prepare(&sql)?;
+            let rows = stmt.query_map(params_from_iter(values), |row| {
+                let message: String = row.get(6)?;
+                let structured = parse_structured(&message);
+                Ok(ArchivedLogLine {
+                    id: row.get(0)?,
+                    context: row.get(1)?,
+                    entry: LogEntry {
+                        timestamp: Some(from_millis(row.get(5)?)),
+                        message,
+                        container: row.get(4)?,
+                        pod: row.get(3)?,
+                        namespace: row.get(2)?,
+                        level: structured.level,
+                        msg: structured.msg,
+                        fields: structured.fields,
+                    },
+                })
+            })?;
+
+            let mut lines = Vec::new();
+            for row in rows {
+                lines.push(row?);
+            }
+            Ok(lines)
+        })
+        .await
+    }
+
+    /// List the pods and containers with archived lines
+    pub async fn list_sources(
+        &self,
+        context: Option<String>,
+    ) -> LogArchiveResult<Vec<ArchivedLogSource>> {
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT context, namespace, pod, container, COUNT(*), MIN(ts), MAX(ts)
+                 FROM log_lines
+                 WHERE ?1 IS NULL OR context = ?1
+                 GROUP BY context, namespace, pod, container
+                 ORDER BY MAX(ts) DESC",
+            )?;
+            let rows = stmt.query_map(params![context], |row| {
+                Ok(ArchivedLogSource {
+                    context: row.get(0)?,
+                    namespace: row.get(1)?,
+                    pod: row.get(2)?,
+                    container: row.get(3)?,
+                    line_count: row.get(4)?,
+                    first_timestamp: from_millis(row.get(5)?),
+                    last_timestamp: from_millis(row.get(6)?),
+                })
+            })?;
+
+            let mut sources = Vec::new();
+            for row in rows {
+                sources.push(row?);
+            }
+            Ok(sources)
+        })
+        .await
+    }
+
+    /// Get line count, size and cap
+    pub async fn stats(&self) -> LogArchiveResult<LogArchiveStats> {
+        self.with_conn(|conn| {
+            Ok(LogArchiveStats {
+                line_count: conn
+                    .query_row("SELECT COUNT(*) FROM log_lines", [], |row| row.get(0))?,
+                size_bytes: Self::used_bytes(conn)?,
+                max_bytes: Self::max_bytes_of(conn)?,
+            })
+        })
+        .await
+    }
+
+    /// Change the size cap and apply it right away
+    pub async fn set_max_bytes(&self, max_bytes: u64) -> LogArchiveResult<LogArchiveStats> {
+        let max_bytes = max_bytes.max(MIN_MAX_BYTES);
+        self.with_conn(move |conn| {
+            conn.execute(
+                "INSERT OR REPLACE INTO settings (key, value) VALUES ('max_bytes', ?1)",
+                params![max_bytes.to_string()],
+            )?;
+            Self::enforce_retention(conn)?;
+            Ok(())
+        })
+        .await?;
+        self.stats().await
+    }
+
+    /// Delete archived lines, optionally only those of one context,
+    /// namespace or pod
+    pub async fn clear(
+        &self,
+        context: Option<String>,
+        namespace: Option<String>,
+        pod: Option<String>,
+    ) -> LogArchiveResult<usize> {
+        self.with_conn(move |conn| {
+            conn.execute(
+                "DELETE FROM log_lines
+                 WHERE (?1 IS NULL OR context = ?1)
+                   AND (?2 IS NULL OR namespace = ?2)
+                   AND (?3 IS NULL OR pod = ?3)",
+                params![context, namespace, pod],
+            )
+        })
+        .await
+    }
+}
+
+/// Thread-safe wrapper
+pub type SharedLogArchive = Arc<LogArchive>;
+
+/// Create a new shared log archive
+pub fn create_log_archive(db_path: PathBuf) -> Result<SharedLogArchive, String> {
+    LogArchive::new(db_path)
+        .map(Arc::new)
+        .map_err(|e| format!("Failed to create log archive: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::structured::LogLevel;
+    use super::*;
+
+    fn entry(pod: &str, timestamp: &str, message: &str) -> LogEntry {
+        super::super::parse_log_line(&format!("{} {}", timestamp, message), "app", pod, "default")
+    }
+
+    fn archive() -> LogArchive {
+        LogArchive::new(PathBuf::from(":memory:")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn searches_by_text_source_and_time() {
+        let archive = archive();
+        archive
+            .append(
+                "prod".to_string(),
+                vec![
+                    entry("web-1", "2024-01-01T10:00:00.000000000Z", "GET /users 200"),
+                    entry(
+                        "web-1",
+                        "2024-01-01T10:05:00.000000000Z",
+                        "upstream timeout after 30s",
+                    ),
+                    entry(
+                        "web-2",
+                        "2024-01-01T10:06:00.000000000Z",
+                        "upstream Timeout after 5s",
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+        archive
+            .append(
+                "staging".to_string(),
+                vec![entry(
+                    "web-1",
+                    "2024-01-01T10:07:00.000000000Z",
+                    "upstream timeout",
+                )],
+            )
+            .await
+            .unwrap();
+
+        let hits = archive
+            .search(LogArchiveQuery {
+                context: Some("prod".to_string()),
+                text: Some("upstream timeout".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].entry.pod, "web-1");
+        assert_eq!(
+            hits[0].entry.timestamp.as_deref(),
+            Some("2024-01-01T10:05:00.000Z")
+        );
+
+        let window = archive
+            .search(LogArchiveQuery {
+                pod: Some("web-1".to_string()),
+                since: Some("2024-01-01T10:01:00.000000000Z".to_string()),
+                until: Some("2024-01-01T10:07:00Z".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(window.len(), 1);
+        assert_eq!(window[0].context, "prod");
+    }
+
+    #[tokio::test]
+    async fn search_text_with_quotes_is_not_a_syntax_error() {
+        let archive = archive();
+        archive
+            .append(
+                "prod".to_string(),
+                vec![entry(
+                    "web-1",
+                    "2024-01-01T10:00:00.000000000Z",
+                    r#"bad "token" OR x"#,
+                )],
+            )
+            .await
+            .unwrap();
+        let hits = archive
+            .search(LogArchiveQuery {
+                text: Some(r#""token" OR"#.to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn archived_lines_are_parsed_again_on_read() {
+        let archive = archive();
+        archive
+            .append(
+                "prod".to_string(),
+                vec![entry(
+                    "api-1",
+                    "2024-01-01T10:00:00.000000000Z",
+                    r#"{"level":"error","msg":"boom","status":500}"#,
+                )],
+            )
+            .await
+            .unwrap();
+        let hits = archive.search(LogArchiveQuery::default()).await.unwrap();
+        assert_eq!(hits[0].entry.level, Some(LogLevel::Error));
+        assert_eq!(hits[0].entry.msg.as_deref(), Some("boom"));
+    }
+
+    #[tokio::test]
+    async fn lists_sources_and_clears_by_pod() {
+        let archive = archive();
+        archive
+            .append(
+                "prod".to_string(),
+                vec![
+                    entry("web-1", "2024-01-01T10:00:00.000000000Z", "a"),
+                    entry("web-1", "2024-01-01T10:01:00.000000000Z", "b"),
+                    entry("web-2", "2024-01-01T10:02:00.000000000Z", "c"),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let sources = archive.list_sources(None).await.unwrap();
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].pod, "web-2");
+        assert_eq!(sources[1].line_count, 2);
+
+        let removed = archive
+            .clear(None, None, Some("web-1".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(archive.stats().await.unwrap().line_count, 1);
+    }
+
+    #[tokio::test]
+    async fn retention_drops_oldest_lines_past_the_cap() {
+        let archive = archive();
+        archive.set_max_bytes(0).await.unwrap();
+        let payload = "x".repeat(400);
+        for batch in 0..10 {
+            let entries = (0..1_000)
+                .map(|i| {
+                    entry(
+                        "web-1",
+                        "2024-01-01T10:00:00.000000000Z",
+                        &format!("{} {} {}", batch, i, payload),
+                    )
+                })
+                .collect();
+            archive.append("prod".to_string(), entries).await.unwrap();
+        }
+
+        let stats = archive.stats().await.unwrap();
+        assert_eq!(stats.max_bytes, MIN_MAX_BYTES);
+        assert!(stats.size_bytes <= stats.max_bytes);
+        assert!(stats.line_count < 10_000);
+
+        // The newest batch is kept
+        let newest = archive
+            .search(LogArchiveQuery {
+                newest_first: true,
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(newest[0].entry.message.starts_with("9 999 "));
+    }
+
+    #[test]
+    fn fts_phrase_escapes_quotes() {
+        assert_eq!(fts_phrase(r#"a "b""#), r#""a ""b""""#);
+    }
+}
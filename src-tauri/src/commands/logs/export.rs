@@ -0,0 +1,174 @@
+//! Rendering of downloaded logs as plain text, CSV or NDJSON.
+
+use super::LogEntry;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// File format for `download_pod_logs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogExportFormat {
+    /// The raw log lines, as the kubelet returns them
+    #[default]
+    Text,
+    /// One row per line; structured fields become extra columns
+    Csv,
+    /// One serialized `LogEntry` per line
+    Ndjson,
+}
+
+/// Fixed CSV columns, followed by one `fields.<key>` column per structured
+/// field
+const CSV_COLUMNS: [&str; 7] = [
+    "timestamp",
+    "namespace",
+    "pod",
+    "container",
+    "level",
+    "msg",
+    "message",
+];
+
+pub fn render(entries: &[LogEntry], format: LogExportFormat) -> String {
+    match format {
+        LogExportFormat::Text => render_text(entries),
+        LogExportFormat::Csv => render_csv(entries),
+        LogExportFormat::Ndjson => render_ndjson(entries),
+    }
+}
+
+fn render_text(entries: &[LogEntry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        if let Some(timestamp) = &entry.timestamp {
+            out.push_str(timestamp);
+            out.push(' ');
+        }
+        out.push_str(&entry.message);
+        out.push('\n');
+    }
+    out
+}
+
+fn render_ndjson(entries: &[LogEntry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        // LogEntry only holds strings and JSON values, so this cannot fail
+        if let Ok(line) = serde_json::to_string(entry) {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn render_csv(entries: &[LogEntry]) -> String {
+    let field_columns: BTreeSet<&str> = entries
+        .iter()
+        .flat_map(|entry| entry.fields.keys().map(String::as_str))
+        .collect();
+
+    let mut out = String::new();
+    let header = CSV_COLUMNS
+        .iter()
+        .map(|column| column.to_string())
+        .chain(field_columns.iter().map(|key| format!("fields.{}", key)));
+    push_csv_row(&mut out, header);
+
+    for entry in entries {
+        let fixed = [
+            entry.timestamp.clone().unwrap_or_default(),
+            entry.namespace.clone(),
+            entry.pod.clone(),
+            entry.container.clone(),
+            entry
+                .level
+                .map(|level| level.as_str().to_string())
+                .unwrap_or_default(),
+            entry.msg.clone().unwrap_or_default(),
+            entry.message.clone(),
+        ];
+        let fields = field_columns
+            .iter()
+            .map(|key| match entry.fields.get(*key) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+            });
+        push_csv_row(&mut out, fixed.into_iter().chain(fields));
+    }
+    out
+}
+
+fn push_csv_row(out: &mut String, cells: impl Iterator<Item = String>) {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            out.push('"');
+            out.push_str(&cell.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(&cell);
+        }
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse_log_line;
+    use super::*;
+
+    fn entries() -> Vec<LogEntry> {
+        [
+            r#"2024-01-01T00:00:00Z {"level":"error","msg":"boom, again","status":503}"#,
+            "2024-01-01T00:00:01Z level=info msg=ok path=/healthz",
+            "2024-01-01T00:00:02Z plain \"quoted\" text",
+        ]
+        .iter()
+        .map(|line| parse_log_line(line, "app", "web-1", "default"))
+        .collect()
+    }
+
+    #[test]
+    fn text_export_reproduces_the_lines() {
+        let text = render(&entries(), LogExportFormat::Text);
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.ends_with("2024-01-01T00:00:02Z plain \"quoted\" text\n"));
+    }
+
+    #[test]
+    fn csv_export_adds_a_column_per_field() {
+        let csv = render(&entries(), LogExportFormat::Csv);
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            rows[0],
+            "timestamp,namespace,pod,container,level,msg,message,\
+             fields.level,fields.msg,fields.path,fields.status"
+        );
+        assert!(
+            rows[1].starts_with("2024-01-01T00:00:00Z,default,web-1,app,error,\"boom, again\",")
+        );
+        assert!(rows[1].ends_with(",error,\"boom, again\",,503"));
+        assert!(rows[2].ends_with(",info,ok,/healthz,"));
+        assert!(rows[3].contains(",\"plain \"\"quoted\"\" text\","));
+    }
+
+    #[test]
+    fn ndjson_export_serializes_each_entry() {
+        let ndjson = render(&entries(), LogExportFormat::Ndjson);
+        let first: Value = serde_json::from_str(ndjson.lines().next().unwrap()).unwrap();
+        assert_eq!(first["level"], "error");
+        assert_eq!(first["fields"]["status"], 503);
+        assert_eq!(ndjson.lines().count(), 3);
+    }
+
+    #[test]
+    fn export_format_deserializes_lowercase() {
+        let format: LogExportFormat = serde_json::from_str("\"ndjson\"").unwrap();
+        assert_eq!(format, LogExportFormat::Ndjson);
+    }
+}
@@ -0,0 +1,300 @@
+//! Server-side log filtering.
+//!
+//! Busy pods emit far more lines than the log viewer shows once a search or
+//! level filter is active. Filtering here, before lines are batched into
+//! `LogEvent::Lines`, keeps those lines off the IPC bridge entirely.
+
+use super::structured::LogLevel;
+use super::LogEntry;
+use crate::error::KubeliError;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Filter settings sent by the frontend with `LogOptions`
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LogFilterOptions {
+    /// Keep only lines matching this regex
+    pub include: Option<String>,
+    /// Drop lines matching this regex
+    pub exclude: Option<String>,
+    /// Drop lines below this level (trace, debug, info, warn, error, fatal).
+    /// Lines without a recognizable level are kept, so stack traces and
+    /// other continuation lines survive.
+    pub min_level: Option<String>,
+    /// Field predicates such as `level=error` or `status>=500`, all of which
+    /// must hold. Nested JSON fields use dots (`http.status>=500`). Lines
+    /// that are neither JSON nor logfmt never match a predicate.
+    #[serde(default)]
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// One parsed `field<op>value` predicate
+#[derive(Debug, Clone)]
+struct FieldPredicate {
+    path: Vec<String>,
+    op: CompareOp,
+    value: String,
+}
+
+impl FieldPredicate {
+    fn parse(raw: &str) -> Result<Self, KubeliError> {
+        // Two-character operators first so `>=` is not read as `>`
+        const OPS: [(&str, CompareOp); 6] = [
+            (">=", CompareOp::Ge),
+            ("<=", CompareOp::Le),
+            ("!=", CompareOp::Ne),
+            ("=", CompareOp::Eq),
+            (">", CompareOp::Gt),
+            ("<", CompareOp::Lt),
+        ];
+        let (pos, token, op) = OPS
+            .iter()
+            .filter_map(|(token, op)| raw.find(token).map(|pos| (pos, *token, *op)))
+            .min_by_key(|(pos, token, _)| (*pos, std::cmp::Reverse(token.len())))
+            .ok_or_else(|| {
+                KubeliError::unknown(format!("Invalid field filter '{}': missing operator", raw))
+            })?;
+
+        let field = raw[..pos].trim();
+        if field.is_empty() {
+            return Err(KubeliError::unknown(format!(
+                "Invalid field filter '{}': missing field name",
+                raw
+            )));
+        }
+        Ok(Self {
+            path: field.split('.').map(str::to_string).collect(),
+            op,
+            value: raw[pos + token.len()..]
+                .trim()
+                .trim_matches('"')
+                .to_string(),
+        })
+    }
+
+    fn matches(&self, fields: &BTreeMap<String, Value>) -> bool {
+        let Some((first, rest)) = self.path.split_first() else {
+            return false;
+        };
+        let Some(mut current) = fields.get(first) else {
+            return false;
+        };
+        for key in rest {
+            match current.get(key) {
+                Some(next) => current = next,
+                None => return false,
+            }
+        }
+
+        let actual_number = match current {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.parse::<f64>().ok(),
+            _ => None,
+        };
+        if let (Some(actual), Ok(expected)) = (actual_number, self.value.parse::<f64>()) {
+            return match self.op {
+                CompareOp::Eq => actual == expected,
+                CompareOp::Ne => actual != expected,
+                CompareOp::Gt => actual > expected,
+                CompareOp::Ge => actual >= expected,
+                CompareOp::Lt => actual < expected,
+                CompareOp::Le => actual <= expected,
+            };
+        }
+
+        let actual = match current {
+            Value::String(s) => s.clone(),
+            Value::Null => return false,
+            other => other.to_string(),
+        };
+        match self.op {
+            CompareOp::Eq => actual.eq_ignore_ascii_case(&self.value),
+            CompareOp::Ne => !actual.eq_ignore_ascii_case(&self.value),
+            // Ordering is only meaningful for numbers
+            _ => false,
+        }
+    }
+}
+
+/// Compiled filter, built once per stream and applied to every line
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    include: Option<Regex>,
+    exclude: Option<Regex>,
+    min_level: Option<LogLevel>,
+    predicates: Vec<FieldPredicate>,
+}
+
+impl LogFilter {
+    /// Compile the frontend options. Invalid regexes, levels and predicates
+    /// are reported up front instead of silently matching nothing.
+    pub fn compile(options: Option<&LogFilterOptions>) -> Result<Self, KubeliError> {
+        let Some(options) = options else {
+            return Ok(Self::default());
+        };
+
+        let regex = |pattern: &Option<String>, what: &str| -> Result<Option<Regex>, KubeliError> {
+            pattern
+                .as_deref()
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    Regex::new(p).map_err(|e| {
+                        KubeliError::unknown(format!("Invalid {} pattern: {}", what, e))
+                    })
+                })
+                .transpose()
+        };
+
+        let min_level = options
+            .min_level
+            .as_deref()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                LogLevel::parse(l)
+                    .ok_or_else(|| KubeliError::unknown(format!("Unknown log level '{}'", l)))
+            })
+            .transpose()?;
+
+        let predicates = options
+            .fields
+            .iter()
+            .filter(|f| !f.trim().is_empty())
+            .map(|f| FieldPredicate::parse(f))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            include: regex(&options.include, "include")?,
+            exclude: regex(&options.exclude, "exclude")?,
+            min_level,
+            predicates,
+        })
+    }
+
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        let message = entry.message.as_str();
+        if let Some(include) = &self.include {
+            if !include.is_match(message) {
+                return false;
+            }
+        }
+        if let Some(exclude) = &self.exclude {
+            if exclude.is_match(message) {
+                return false;
+            }
+        }
+        if let Some(min_level) = self.min_level {
+            if entry.level.is_some_and(|level| level < min_level) {
+                return false;
+            }
+        }
+        self.predicates.iter().all(|p| p.matches(&entry.fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: &str) -> LogEntry {
+        super::super::parse_log_line(message, "app", "web-1", "default")
+    }
+
+    fn filter(options: LogFilterOptions) -> LogFilter {
+        LogFilter::compile(Some(&options)).expect("valid filter")
+    }
+
+    #[test]
+    fn no_options_lets_everything_through() {
+        let f = LogFilter::compile(None).unwrap();
+        assert!(f.matches(&entry("anything")));
+    }
+
+    #[test]
+    fn include_and_exclude_regexes() {
+        let f = filter(LogFilterOptions {
+            include: Some("GET|POST".to_string()),
+            exclude: Some("/healthz".to_string()),
+            ..Default::default()
+        });
+        assert!(f.matches(&entry("GET /api/users 200")));
+        assert!(!f.matches(&entry("GET /healthz 200")));
+        assert!(!f.matches(&entry("connection pool resized")));
+    }
+
+    #[test]
+    fn invalid_regex_is_reported() {
+        let err = LogFilter::compile(Some(&LogFilterOptions {
+            include: Some("(unclosed".to_string()),
+            ..Default::default()
+        }))
+        .unwrap_err();
+        assert!(err.message.contains("include"));
+    }
+
+    #[test]
+    fn min_level_drops_lower_levels_but_keeps_unleveled_lines() {
+        let f = filter(LogFilterOptions {
+            min_level: Some("warn".to_string()),
+            ..Default::default()
+        });
+        assert!(!f.matches(&entry("INFO started")));
+        assert!(f.matches(&entry("WARN disk almost full")));
+        assert!(f.matches(&entry(r#"{"level":"error"}"#)));
+        assert!(f.matches(&entry("    at com.example.Main(Main.java:1)")));
+    }
+
+    #[test]
+    fn unknown_min_level_is_rejected() {
+        assert!(LogFilter::compile(Some(&LogFilterOptions {
+            min_level: Some("loud".to_string()),
+            ..Default::default()
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn field_predicates_compare_strings_and_numbers() {
+        let f = filter(LogFilterOptions {
+            fields: vec!["level=error".to_string(), "status>=500".to_string()],
+            ..Default::default()
+        });
+        assert!(f.matches(&entry(r#"{"level":"ERROR","status":503}"#)));
+        assert!(!f.matches(&entry(r#"{"level":"error","status":404}"#)));
+        assert!(!f.matches(&entry(r#"{"level":"info","status":500}"#)));
+        assert!(f.matches(&entry("level=error status=500")));
+        assert!(!f.matches(&entry("ERROR status=500")));
+    }
+
+    #[test]
+    fn field_predicates_follow_nested_paths_and_string_numbers() {
+        let f = filter(LogFilterOptions {
+            fields: vec!["http.status<400".to_string(), "user != bot".to_string()],
+            ..Default::default()
+        });
+        assert!(f.matches(&entry(r#"{"http":{"status":"200"},"user":"alice"}"#)));
+        assert!(!f.matches(&entry(r#"{"http":{"status":200},"user":"bot"}"#)));
+        assert!(!f.matches(&entry(r#"{"user":"alice"}"#)));
+    }
+
+    #[test]
+    fn predicate_without_operator_is_rejected() {
+        assert!(FieldPredicate::parse("level").is_err());
+        assert!(FieldPredicate::parse("=error").is_err());
+        let p = FieldPredicate::parse("latency_ms>=250").unwrap();
+        assert_eq!(p.op, CompareOp::Ge);
+        assert_eq!(p.path, vec!["latency_ms"]);
+        assert_eq!(p.value, "250");
+    }
+}
@@ -0,0 +1,1508 @@
+use crate::commands::resources::label_selector_to_query;
+use crate::error::KubeliError;
+use crate::k8s::AppState;
+use futures::AsyncBufReadExt as FuturesAsyncBufReadExt;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::{Api, LogParams};
+use kube::runtime::watcher::{watcher, Config, Event};
+use kube::runtime::WatchStreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use tauri::{command, AppHandle, Emitter, State};
+use tokio::sync::{mpsc, RwLock};
+use tokio_util::sync::CancellationToken;
+
+mod archive;
+mod export;
+mod filter;
+mod structured;
+
+pub use archive::{create_log_archive, SharedLogArchive};
+use archive::{ArchivedLogLine, ArchivedLogSource, LogArchiveQuery, LogArchiveStats};
+pub use export::LogExportFormat;
+use filter::LogFilter;
+pub use filter::LogFilterOptions;
+use structured::parse_structured;
+pub use structured::LogLevel;
+
+/// Log batches are flushed every FLUSH_INTERVAL or MAX_BATCH lines, whichever
+/// comes first. One IPC event per line would flood the webview on busy pods.
+const FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+const MAX_BATCH: usize = 100;
+
+/// Log stream entry with metadata
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LogEntry {
+    pub timestamp: Option<String>,
+    pub message: String,
+    pub container: String,
+    pub pod: String,
+    pub namespace: String,
+    /// Normalized severity from the structured level field, or from a level
+    /// word near the start of a plain line
+    pub level: Option<LogLevel>,
+    /// Message field of a JSON or logfmt line
+    pub msg: Option<String>,
+    /// Top-level fields of a JSON or logfmt line; empty for plain text
+    #[serde(default)]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+/// Log stream event types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum LogEvent {
+    Line(LogEntry),
+    /// Batch of lines flushed together (every 50 ms or 100 lines) so a busy
+    /// pod does not cause one IPC event per line.
+    Lines(Vec<LogEntry>),
+    Error(KubeliError),
+    /// The stream ended after it had been running.
+    ///
+    /// Distinct from `Error`, which stays reserved for a connection that never
+    /// established (RBAC, pod not found). A stream can end for entirely normal
+    /// reasons — the container exited, the kubelet hit its idle timeout, a load
+    /// balancer dropped an idle TCP connection — so this is a recoverable state
+    /// offering reconnection, not a failure.
+    Ended {
+        stream_id: String,
+        /// Present when the stream was cut rather than reaching a clean EOF
+        reason: Option<String>,
+    },
+    Started {
+        stream_id: String,
+    },
+    Stopped {
+        stream_id: String,
+    },
+    /// An aggregated stream started following a container
+    Attached {
+        pod: String,
+        container: String,
+    },
+    /// An aggregated stream stopped following a container (pod deleted or
+    /// no longer matching the selector)
+    Detached {
+        pod: String,
+        container: String,
+    },
+}
+
+/// Options for streaming logs
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LogOptions {
+    pub namespace: String,
+    pub pod_name: String,
+    pub container: Option<String>,
+    pub follow: Option<bool>,
+    pub tail_lines: Option<i64>,
+    pub since_seconds: Option<i64>,
+    pub timestamps: Option<bool>,
+    pub previous: Option<bool>,
+    /// Cluster context to read from; the active cluster when omitted
+    pub context: Option<String>,
+    /// Lines not matching the filter are dropped before reaching the UI
+    pub filter: Option<LogFilterOptions>,
+    /// Also write streamed lines to the local log archive
+    pub record: Option<bool>,
+}
+
+/// Workload whose pods an aggregated log stream follows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogWorkloadRef {
+    /// Deployment, StatefulSet, DaemonSet, ReplicaSet or Job
+    pub kind: String,
+    pub name: String,
+}
+
+/// Options for an aggregated log stream over every pod matching a selector.
+/// Either `label_selector` or `workload` must be set.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AggregatedLogOptions {
+    pub namespace: String,
+    pub label_selector: Option<String>,
+    pub workload: Option<LogWorkloadRef>,
+    /// Only follow containers with this name; every container when omitted
+    pub container: Option<String>,
+    pub tail_lines: Option<i64>,
+    pub since_seconds: Option<i64>,
+    pub timestamps: Option<bool>,
+    /// Cluster context to read from; the active cluster when omitted
+    pub context: Option<String>,
+    /// Lines not matching the filter are dropped before reaching the UI
+    pub filter: Option<LogFilterOptions>,
+    /// Also write streamed lines to the local log archive
+    pub record: Option<bool>,
+}
+
+/// Active log stream session
+struct LogStreamSession {
+    stop_flag: Arc<AtomicBool>,
+    /// Context the stream reads from, so a disconnect only stops its own
+    context: String,
+}
+
+/// Writes the flushed batches of a recording stream to the archive. One
+/// writer task per stream keeps the batches in order.
+#[derive(Clone)]
+struct LogRecorder {
+    batches: mpsc::UnboundedSender<Vec<LogEntry>>,
+}
+
+impl LogRecorder {
+    fn new(archive: SharedLogArchive, context: String) -> Self {
+        let (batches, mut rx) = mpsc::unbounded_channel::<Vec<LogEntry>>();
+        // Ends once the stream drops its recorder and the queue is drained
+        tokio::spawn(async move {
+            while let Some(lines) = rx.recv().await {
+                if let Err(e) = archive.append(context.clone(), lines).await {
+                    tracing::warn!("Failed to archive log lines: {}", e);
+                }
+            }
+        });
+        Self { batches }
+    }
+
+    fn record(&self, lines: &[LogEntry]) {
+        if self.batches.send(lines.to_vec()).is_err() {
+            tracing::warn!("Log archive writer stopped; dropping {} lines", lines.len());
+        }
+    }
+}
+
+/// Manager for active log streams
+pub struct LogStreamManager {
+    active_streams: RwLock<HashMap<String, LogStreamSession>>,
+    /// Set once at startup; streams only record when it opened successfully
+    archive: OnceLock<SharedLogArchive>,
+}
+
+impl LogStreamManager {
+    pub fn new() -> Self {
+        Self {
+            active_streams: RwLock::new(HashMap::new()),
+            archive: OnceLock::new(),
+        }
+    }
+
+    pub fn set_archive(&self, archive: SharedLogArchive) {
+        if self.archive.set(archive).is_err() {
+            tracing::warn!("Log archive already initialized");
+        }
+    }
+
+    fn archive(&self) -> Result<&SharedLogArchive, KubeliError> {
+        self.archive
+            .get()
+            .ok_or_else(|| KubeliError::unknown("Log archive is not available"))
+    }
+
+    /// Recorder for a stream that asked to record, if any
+    fn recorder(
+        &self,
+        record: Option<bool>,
+        context: &str,
+    ) -> Result<Option<LogRecorder>, KubeliError> {
+        if !record.unwrap_or(false) {
+            return Ok(None);
+        }
+        Ok(Some(LogRecorder::new(
+            Arc::clone(self.archive()?),
+            context.to_string(),
+        )))
+    }
+
+    pub async fn add_stream(&self, id: String, context: String, stop_flag: Arc<AtomicBool>) {
+        let mut streams = self.active_streams.write().await;
+        streams.insert(id, LogStreamSession { stop_flag, context });
+    }
+
+    pub async fn stop_stream(&self, id: &str) -> bool {
+        let streams = self.active_streams.read().await;
+        if let Some(session) = streams.get(id) {
+            session.stop_flag.store(true, Ordering::SeqCst);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub async fn remove_stream(&self, id: &str) {
+        let mut streams = self.active_streams.write().await;
+        streams.remove(id);
+    }
+
+    pub async fn is_active(&self, id: &str) -> bool {
+        let streams = self.active_streams.read().await;
+        streams.contains_key(id)
+    }
+
+    /// Stop the log streams reading from one context. Called when that
+    /// context disconnects or reconnects; streams on other clusters continue.
+    pub async fn stop_context(&self, context: &str) {
+        let mut streams = self.active_streams.write().await;
+        streams.retain(|_, session| {
+            if session.context == context {
+                session.stop_flag.store(true, Ordering::SeqCst);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Stop every active log stream.
+    pub async fn stop_all(&self) {
+        let mut streams = self.active_streams.write().await;
+        for session in streams.values() {
+            session.stop_flag.store(true, Ordering::SeqCst);
+        }
+        streams.clear();
+    }
+}
+
+impl Default for LogStreamManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get logs from a pod (non-streaming, returns all at once)
+#[command]
+pub async fn get_pod_logs(
+    state: State<'_, AppState>,
+    options: LogOptions,
+) -> Result<Vec<LogEntry>, KubeliError> {
+    let filter = LogFilter::compile(options.filter.as_ref())?;
+    let client = state.k8s.get_client_for(options.context.as_deref()).await?;
+
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+
+    let mut log_params = LogParams {
+        follow: false,
+        timestamps: options.timestamps.unwrap_or(true),
+        tail_lines: options.tail_lines,
+        since_seconds: options.since_seconds,
+        previous: options.previous.unwrap_or(false),
+        ..Default::default()
+    };
+
+    if let Some(container) = &options.container {
+        log_params.container = Some(container.clone());
+    }
+
+    let logs = pods.logs(&options.pod_name, &log_params).await?;
+
+    let container_name = options
+        .container
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let entries: Vec<LogEntry> = logs
+        .lines()
+        .map(|line| parse_log_line(line, &container_name, &options.pod_name, &options.namespace))
+        .filter(|entry| filter.matches(entry))
+        .collect();
+
+    tracing::info!(
+        "Retrieved {} log lines from {}/{}",
+        entries.len(),
+        options.namespace,
+        options.pod_name
+    );
+
+    Ok(entries)
+}
+
+/// Builds the LogParams for a streaming request.
+///
+/// The 100-line tail default must not apply alongside `since_seconds`: the
+/// kubelet honours both parameters, so a resume would return at most the last
+/// 100 lines within the window and silently drop the rest of the gap.
+fn stream_log_params(options: &LogOptions) -> LogParams {
+    LogParams {
+        follow: options.follow.unwrap_or(true),
+        timestamps: options.timestamps.unwrap_or(true),
+        tail_lines: match options.since_seconds {
+            Some(_) => options.tail_lines,
+            None => options.tail_lines.or(Some(100)),
+        },
+        since_seconds: options.since_seconds,
+        previous: options.previous.unwrap_or(false),
+        container: options.container.clone(),
+        ..Default::default()
+    }
+}
+
+/// Stream logs from a pod in real-time
+#[command]
+pub async fn stream_pod_logs(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    stream_id: String,
+    options: LogOptions,
+) -> Result<(), KubeliError> {
+    tracing::info!(
+        "Starting log stream {} for {}/{} container={:?}",
+        stream_id,
+        options.namespace,
+        options.pod_name,
+        options.container
+    );
+
+    // Check if stream already exists
+    if log_manager.is_active(&stream_id).await {
+        return Err(KubeliError::unknown(format!(
+            "Stream {} already exists",
+            stream_id
+        )));
+    }
+
+    // Compile up front so an invalid pattern fails the command instead of
+    // producing an empty stream
+    let filter = LogFilter::compile(options.filter.as_ref())?;
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await?;
+
+    let recorder = log_manager.recorder(options.record, &context)?;
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+
+    let log_params = stream_log_params(&options);
+
+    // Create stop flag
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    log_manager
+        .add_stream(stream_id.clone(), context, stop_flag.clone())
+        .await;
+
+    let event_name = format!("log-stream-{}", stream_id);
+    let container_name = options
+        .container
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let pod_name = options.pod_name.clone();
+    let namespace = options.namespace.clone();
+
+    // Emit started event
+    let _ = app.emit(
+        &event_name,
+        LogEvent::Started {
+            stream_id: stream_id.clone(),
+        },
+    );
+
+    // Spawn the log streaming task
+    let stream_id_clone = stream_id.clone();
+    let log_manager_clone = Arc::clone(&log_manager);
+
+    tokio::spawn(async move {
+        tracing::info!("Log stream task started for {}/{}", namespace, pod_name);
+        match pods.log_stream(&pod_name, &log_params).await {
+            Ok(stream) => {
+                tracing::info!("Log stream connected for {}/{}", namespace, pod_name);
+                // Use futures::io::AsyncBufReadExt::lines() which returns a Stream
+                use futures::StreamExt;
+                let mut lines_stream = stream.lines();
+
+                // Batch lines: flush every FLUSH_INTERVAL or MAX_BATCH lines,
+                // whichever comes first.
+                let mut batch: Vec<LogEntry> = Vec::new();
+                let flush = |batch: &mut Vec<LogEntry>| {
+                    if !batch.is_empty() {
+                        let lines = std::mem::take(batch);
+                        if let Some(recorder) = &recorder {
+                            recorder.record(&lines);
+                        }
+                        let _ = app.emit(&event_name, LogEvent::Lines(lines));
+                    }
+                };
+
+                loop {
+                    if stop_flag.load(Ordering::SeqCst) {
+                        tracing::info!("Log stream {} stopped by user", stream_id_clone);
+                        flush(&mut batch);
+                        break;
+                    }
+
+                    match tokio::time::timeout(FLUSH_INTERVAL, lines_stream.next()).await {
+                        // Flush interval elapsed without a new line
+                        Err(_) => flush(&mut batch),
+                        Ok(Some(Ok(line))) => {
+                            let entry =
+                                parse_log_line(&line, &container_name, &pod_name, &namespace);
+                            if !filter.matches(&entry) {
+                                continue;
+                            }
+                            batch.push(entry);
+                            if batch.len() >= MAX_BATCH {
+                                flush(&mut batch);
+                            }
+                        }
+                        Ok(Some(Err(e))) => {
+                            // The stream was already running, so this is a drop
+                            // rather than a failure to connect: report it as an
+                            // end the user can reconnect from.
+                            tracing::info!("Log stream {} dropped: {}", stream_id_clone, e);
+                            flush(&mut batch);
+                            let _ = app.emit(
+                                &event_name,
+                                LogEvent::Ended {
+                                    stream_id: stream_id_clone.clone(),
+                                    reason: Some(e.to_string()),
+                                },
+                            );
+                            break;
+                        }
+                        Ok(None) => {
+                            // Clean EOF — the container exited or stopped writing
+                            flush(&mut batch);
+                            let _ = app.emit(
+                                &event_name,
+                                LogEvent::Ended {
+                                    stream_id: stream_id_clone.clone(),
+                                    reason: None,
+                                },
+                            );
+                            break;
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to start log stream for {}/{} (container: {:?}): {}",
+                    namespace,
+                    pod_name,
+                    container_name,
+                    e
+                );
+                let _ = app.emit(&event_name, LogEvent::Error(KubeliError::from(e)));
+            }
+        }
+
+        // Clean up
+        log_manager_clone.remove_stream(&stream_id_clone).await;
+        let _ = app.emit(
+            &event_name,
+            LogEvent::Stopped {
+                stream_id: stream_id_clone,
+            },
+        );
+    });
+
+    Ok(())
+}
+
+/// Resolve the label selector of an aggregated stream: the explicit selector
+/// wins, otherwise the workload's own pod selector is looked up.
+async fn resolve_aggregate_selector(
+    client: kube::Client,
+    options: &AggregatedLogOptions,
+) -> Result<String, KubeliError> {
+    if let Some(selector) = options
+        .label_selector
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+    {
+        return Ok(selector.to_string());
+    }
+
+    let workload = options
+        .workload
+        .as_ref()
+        .ok_or_else(|| KubeliError::unknown("Either a label selector or a workload is required"))?;
+    let ns = &options.namespace;
+    let selector = match workload.kind.to_lowercase().as_str() {
+        "deployment" => Api::<Deployment>::namespaced(client, ns)
+            .get(&workload.name)
+            .await?
+            .spec
+            .map(|s| s.selector),
+        "statefulset" => Api::<StatefulSet>::namespaced(client, ns)
+            .get(&workload.name)
+            .await?
+            .spec
+            .map(|s| s.selector),
+        "daemonset" => Api::<DaemonSet>::namespaced(client, ns)
+            .get(&workload.name)
+            .await?
+            .spec
+            .map(|s| s.selector),
+        "replicaset" => Api::<ReplicaSet>::namespaced(client, ns)
+            .get(&workload.name)
+            .await?
+            .spec
+            .map(|s| s.selector),
+        "job" => Api::<Job>::namespaced(client, ns)
+            .get(&workload.name)
+            .await?
+            .spec
+            .and_then(|s| s.selector),
+        other => {
+            return Err(KubeliError::unknown(format!(
+                "Unsupported workload kind for log aggregation: {}",
+                other
+            )))
+        }
+    };
+
+    // An empty selector matches every pod in the namespace - never what the
+    // user asked for when naming one workload.
+    selector
+        .map(|s| label_selector_to_query(&s))
+        .filter(|query| !query.is_empty())
+        .ok_or_else(|| {
+            KubeliError::unknown(format!(
+                "{} {} has no pod selector",
+                workload.kind, workload.name
+            ))
+        })
+}
+
+/// Containers of a pod that have started and therefore have logs to follow.
+/// Pending containers are picked up by a later watch event once they run.
+fn loggable_containers(pod: &Pod, only: Option<&str>) -> Vec<String> {
+    let Some(statuses) = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.container_statuses.as_ref())
+    else {
+        return Vec::new();
+    };
+    statuses
+        .iter()
+        .filter(|cs| only.is_none_or(|name| cs.name == name))
+        .filter(|cs| {
+            cs.state
+                .as_ref()
+                .is_some_and(|st| st.running.is_some() || st.terminated.is_some())
+        })
+        .map(|cs| cs.name.clone())
+        .collect()
+}
+
+/// (pod, container) pair an aggregated stream follows
+type FollowKey = (String, String);
+
+/// Follow one container and forward its lines to the aggregator. Reports the
+/// key on `ended` when the stream finishes on its own so the container can be
+/// re-attached after a restart.
+async fn follow_container(
+    pods: Api<Pod>,
+    key: FollowKey,
+    namespace: String,
+    params: LogParams,
+    lines: mpsc::Sender<LogEntry>,
+    ended: mpsc::Sender<FollowKey>,
+    token: CancellationToken,
+) {
+    let (pod, container) = &key;
+    let stream = tokio::select! {
+        _ = token.cancelled() => return,
+        stream = pods.log_stream(pod, &params) => stream,
+    };
+    match stream {
+        Ok(stream) => {
+            use futures::StreamExt;
+            let mut lines_stream = stream.lines();
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    line = lines_stream.next() => match line {
+                        Some(Ok(line)) => {
+                            let entry = parse_log_line(&line, container, pod, &namespace);
+                            if lines.send(entry).await.is_err() {
+                                return;
+                            }
+                        }
+                        Some(Err(e)) => {
+                            tracing::debug!("Log follow {}/{} dropped: {}", pod, container, e);
+                            break;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            tracing::warn!("Failed to follow logs of {}/{}: {}", pod, container, e);
+        }
+    }
+    let _ = ended.send(key).await;
+}
+
+/// Start an aggregated log stream over every pod matching a label selector
+/// or workload, stern-style. A pod watcher attaches new pods as they start
+/// and detaches pods that go away; lines of all containers are batched into
+/// the usual `LogEvent::Lines` on `log-stream-{stream_id}`.
+#[command]
+pub async fn stream_aggregated_logs(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    stream_id: String,
+    options: AggregatedLogOptions,
+) -> Result<(), KubeliError> {
+    if log_manager.is_active(&stream_id).await {
+        return Err(KubeliError::unknown(format!(
+            "Stream {} already exists",
+            stream_id
+        )));
+    }
+
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await?;
+    let filter = LogFilter::compile(options.filter.as_ref())?;
+    let recorder = log_manager.recorder(options.record, &context)?;
+    let selector = resolve_aggregate_selector(client.clone(), &options).await?;
+    tracing::info!(
+        "Starting aggregated log stream {} for {} [{}]",
+        stream_id,
+        options.namespace,
+        selector
+    );
+
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    log_manager
+        .add_stream(stream_id.clone(), context, stop_flag.clone())
+        .await;
+
+    let event_name = format!("log-stream-{}", stream_id);
+    let _ = app.emit(
+        &event_name,
+        LogEvent::Started {
+            stream_id: stream_id.clone(),
+        },
+    );
+
+    let log_manager = Arc::clone(&log_manager);
+    tokio::spawn(async move {
+        run_aggregated_stream(
+            app.clone(),
+            &event_name,
+            pods,
+            selector,
+            options,
+            filter,
+            recorder,
+            stop_flag,
+        )
+        .await;
+
+        log_manager.remove_stream(&stream_id).await;
+        let _ = app.emit(&event_name, LogEvent::Stopped { stream_id });
+    });
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn run_aggregated_stream(
+    app: AppHandle,
+    event_name: &str,
+    pods: Api<Pod>,
+    selector: String,
+    options: AggregatedLogOptions,
+    filter: LogFilter,
+    recorder: Option<LogRecorder>,
+    stop_flag: Arc<AtomicBool>,
+) {
+    use futures::StreamExt;
+
+    let root = CancellationToken::new();
+    let (line_tx, mut line_rx) = mpsc::channel::<LogEntry>(1024);
+    let (ended_tx, mut ended_rx) = mpsc::channel::<FollowKey>(64);
+    let mut followers: HashMap<FollowKey, CancellationToken> = HashMap::new();
+    // When a follow ended on its own, re-attaching resumes from that moment
+    // instead of replaying the tail.
+    let mut ended_at: HashMap<FollowKey, k8s_openapi::jiff::Timestamp> = HashMap::new();
+    // Pods seen during a (re)list; anything followed but not seen is gone.
+    let mut relist: Option<HashSet<String>> = None;
+    // Pods from the initial listing get the tail; pods that appear later are
+    // followed from their first line so nothing they print is missed.
+    let mut initial_listing_done = false;
+
+    let mut pod_events = watcher(pods.clone(), Config::default().labels(&selector))
+        .default_backoff()
+        .boxed();
+    let mut ticker = tokio::time::interval(FLUSH_INTERVAL);
+    let mut batch: Vec<LogEntry> = Vec::new();
+    let flush = |batch: &mut Vec<LogEntry>| {
+        if !batch.is_empty() {
+            let lines = std::mem::take(batch);
+            if let Some(recorder) = &recorder {
+                recorder.record(&lines);
+            }
+            let _ = app.emit(event_name, LogEvent::Lines(lines));
+        }
+    };
+
+    let attach = |pod: &Pod,
+                  followers: &mut HashMap<FollowKey, CancellationToken>,
+                  ended_at: &mut HashMap<FollowKey, k8s_openapi::jiff::Timestamp>,
+                  initial: bool| {
+        let Some(pod_name) = pod.metadata.name.clone() else {
+            return;
+        };
+        if pod.metadata.deletion_timestamp.is_some() {
+            return;
+        }
+        for container in loggable_containers(pod, options.container.as_deref()) {
+            let key = (pod_name.clone(), container.clone());
+            if followers.contains_key(&key) {
+                continue;
+            }
+            let mut params = LogParams {
+                follow: true,
+                timestamps: options.timestamps.unwrap_or(true),
+                container: Some(container.clone()),
+                ..Default::default()
+            };
+            if let Some(since) = ended_at.remove(&key) {
+                params.since_time = Some(since);
+            } else if initial {
+                params.since_seconds = options.since_seconds;
+                params.tail_lines = match options.since_seconds {
+                    Some(_) => options.tail_lines,
+                    None => options.tail_lines.or(Some(100)),
+                };
+            }
+            let token = root.child_token();
+            followers.insert(key.clone(), token.clone());
+            let _ = app.emit(
+                event_name,
+                LogEvent::Attached {
+                    pod: pod_name.clone(),
+                    container,
+                },
+            );
+            tokio::spawn(follow_container(
+                pods.clone(),
+                key,
+                options.namespace.clone(),
+                params,
+                line_tx.clone(),
+                ended_tx.clone(),
+                token,
+            ));
+        }
+    };
+    let detach = |pod_name: &str, followers: &mut HashMap<FollowKey, CancellationToken>| {
+        followers.retain(|(pod, container), token| {
+            if pod != pod_name {
+                return true;
+            }
+            token.cancel();
+            let _ = app.emit(
+                event_name,
+                LogEvent::Detached {
+                    pod: pod.clone(),
+                    container: container.clone(),
+                },
+            );
+            false
+        });
+    };
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                flush(&mut batch);
+            }
+            Some(entry) = line_rx.recv() => {
+                if !filter.matches(&entry) {
+                    continue;
+                }
+                batch.push(entry);
+                if batch.len() >= MAX_BATCH {
+                    flush(&mut batch);
+                }
+            }
+            Some(key) = ended_rx.recv() => {
+                // Only forget a follower that ended by itself; a cancelled one
+                // was already removed by detach.
+                if followers.get(&key).is_some_and(|t| !t.is_cancelled()) {
+                    followers.remove(&key);
+                    ended_at.insert(key, k8s_openapi::jiff::Timestamp::now());
+                }
+            }
+            event = pod_events.next() => match event {
+                Some(Ok(Event::Init)) => relist = Some(HashSet::new()),
+                Some(Ok(Event::InitApply(pod))) => {
+                    if let (Some(seen), Some(name)) = (relist.as_mut(), pod.metadata.name.as_ref()) {
+                        seen.insert(name.clone());
+                    }
+                    attach(&pod, &mut followers, &mut ended_at, !initial_listing_done);
+                }
+                Some(Ok(Event::InitDone)) => {
+                    initial_listing_done = true;
+                    if let Some(seen) = relist.take() {
+                        let gone: HashSet<String> = followers
+                            .keys()
+                            .map(|(pod, _)| pod.clone())
+                            .filter(|pod| !seen.contains(pod))
+                            .collect();
+                        for pod in gone {
+                            detach(&pod, &mut followers);
+                        }
+                    }
+                }
+                Some(Ok(Event::Apply(pod))) => {
+                    attach(&pod, &mut followers, &mut ended_at, false);
+                }
+                Some(Ok(Event::Delete(pod))) => {
+                    if let Some(name) = pod.metadata.name.as_deref() {
+                        detach(name, &mut followers);
+                        ended_at.retain(|(p, _), _| p != name);
+                    }
+                }
+                // The watcher retries with backoff; surface nothing but a log.
+                Some(Err(e)) => tracing::warn!("Aggregated log pod watch error: {}", e),
+                None => break,
+            }
+        }
+    }
+
+    root.cancel();
+    flush(&mut batch);
+    tracing::info!("Aggregated log stream on {} stopped", event_name);
+}
+
+/// Stop a log stream
+#[command]
+pub async fn stop_log_stream(
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    stream_id: String,
+) -> Result<(), KubeliError> {
+    if log_manager.stop_stream(&stream_id).await {
+        log_manager.remove_stream(&stream_id).await;
+        tracing::info!("Stopped log stream {}", stream_id);
+        Ok(())
+    } else {
+        Err(KubeliError::unknown(format!(
+            "Stream {} not found",
+            stream_id
+        )))
+    }
+}
+
+/// Search the local log archive by source, time range and text
+#[command]
+pub async fn search_log_archive(
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    query: LogArchiveQuery,
+) -> Result<Vec<ArchivedLogLine>, KubeliError> {
+    log_manager
+        .archive()?
+        .search(query)
+        .await
+        .map_err(|e| KubeliError::unknown(format!("Failed to search log archive: {}", e)))
+}
+
+/// List the pods and containers with archived logs
+#[command]
+pub async fn list_log_archive_sources(
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    context: Option<String>,
+) -> Result<Vec<ArchivedLogSource>, KubeliError> {
+    log_manager
+        .archive()?
+        .list_sources(context)
+        .await
+        .map_err(|e| KubeliError::unknown(format!("Failed to list archived logs: {}", e)))
+}
+
+/// Get size and retention limit of the log archive
+#[command]
+pub async fn get_log_archive_stats(
+    log_manager: State<'_, Arc<LogStreamManager>>,
+) -> Result<LogArchiveStats, KubeliError> {
+    log_manager
+        .archive()?
+        .stats()
+        .await
+        .map_err(|e| KubeliError::unknown(format!("Failed to read log archive stats: {}", e)))
+}
+
+/// Change the size cap of the log archive; the oldest lines beyond it are
+/// dropped right away
+#[command]
+pub async fn set_log_archive_max_size(
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    max_bytes: u64,
+) -> Result<LogArchiveStats, KubeliError> {
+    log_manager
+        .archive()?
+        .set_max_bytes(max_bytes)
+        .await
+        .map_err(|e| KubeliError::unknown(format!("Failed to update log archive limit: {}", e)))
+}
+
+/// Delete archived logs, optionally only those of a context, namespace or pod
+#[command]
+pub async fn clear_log_archive(
+    log_manager: State<'_, Arc<LogStreamManager>>,
+    context: Option<String>,
+    namespace: Option<String>,
+    pod: Option<String>,
+) -> Result<usize, KubeliError> {
+    log_manager
+        .archive()?
+        .clear(context, namespace, pod)
+        .await
+        .map_err(|e| KubeliError::unknown(format!("Failed to clear log archive: {}", e)))
+}
+
+/// Get container names from a pod
+#[command]
+pub async fn get_pod_containers(
+    state: State<'_, AppState>,
+    namespace: String,
+    pod_name: String,
+    context: Option<String>,
+) -> Result<Vec<String>, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+
+    let pods: Api<Pod> = Api::namespaced(client, &namespace);
+    let pod = pods.get(&pod_name).await?;
+
+    let spec = pod.spec.ok_or(KubeliError::unknown("Pod has no spec"))?;
+    let containers: Vec<String> = spec.containers.iter().map(|c| c.name.clone()).collect();
+
+    // Also include init containers if any
+    let mut all_containers = containers;
+    if let Some(init_containers) = spec.init_containers {
+        for ic in init_containers {
+            all_containers.push(format!("init:{}", ic.name));
+        }
+    }
+
+    Ok(all_containers)
+}
+
+/// Download logs from a pod as plain text (the default), CSV or NDJSON.
+/// The options' filter applies to every format.
+#[command]
+pub async fn download_pod_logs(
+    state: State<'_, AppState>,
+    options: LogOptions,
+    format: Option<LogExportFormat>,
+) -> Result<String, KubeliError> {
+    let filter = LogFilter::compile(options.filter.as_ref())?;
+    let format = format.unwrap_or_default();
+    let client = state.k8s.get_client_for(options.context.as_deref()).await?;
+
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+
+    let mut log_params = LogParams {
+        follow: false,
+        timestamps: options.timestamps.unwrap_or(true),
+        tail_lines: None, // Get all logs for download
+        since_seconds: options.since_seconds,
+        previous: options.previous.unwrap_or(false),
+        ..Default::default()
+    };
+
+    if let Some(container) = &options.container {
+        log_params.container = Some(container.clone());
+    }
+
+    let logs = pods.logs(&options.pod_name, &log_params).await?;
+
+    tracing::info!(
+        "Downloaded logs from {}/{} ({} bytes)",
+        options.namespace,
+        options.pod_name,
+        logs.len()
+    );
+
+    // Unfiltered text is returned untouched, byte for byte
+    if format == LogExportFormat::Text && options.filter.is_none() {
+        return Ok(logs);
+    }
+
+    let container_name = options
+        .container
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let entries: Vec<LogEntry> = logs
+        .lines()
+        .map(|line| parse_log_line(line, &container_name, &options.pod_name, &options.namespace))
+        .filter(|entry| filter.matches(entry))
+        .collect();
+
+    Ok(export::render(&entries, format))
+}
+
+/// Parse a log line into a LogEntry
+fn parse_log_line(line: &str, container: &str, pod: &str, namespace: &str) -> LogEntry {
+    // Try to parse timestamp from beginning of line (RFC3339 format)
+    // Format: 2024-01-01T12:00:00.000000000Z message
+    let (timestamp, message) = if line.len() > 30 && line.chars().nth(4) == Some('-') {
+        let parts: Vec<&str> = line.splitn(2, ' ').collect();
+        if parts.len() == 2 {
+            (Some(parts[0].to_string()), parts[1].to_string())
+        } else {
+            (None, line.to_string())
+        }
+    } else {
+        (None, line.to_string())
+    };
+
+    let structured = parse_structured(&message);
+
+    LogEntry {
+        timestamp,
+        message,
+        container: container.to_string(),
+        pod: pod.to_string(),
+        namespace: namespace.to_string(),
+        level: structured.level,
+        msg: structured.msg,
+        fields: structured.fields,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options_with(since_seconds: Option<i64>, tail_lines: Option<i64>) -> LogOptions {
+        LogOptions {
+            namespace: "default".to_string(),
+            pod_name: "my-pod".to_string(),
+            since_seconds,
+            tail_lines,
+            ..Default::default()
+        }
+    }
+
+    // Regression: the 100-line default was applied unconditionally, so a
+    // resume asked for since_seconds AND tailLines=100. The kubelet honours
+    // both, returning only the last 100 lines of the gap and silently dropping
+    // everything before them.
+    #[test]
+    fn resume_does_not_get_a_tail_default() {
+        let params = stream_log_params(&options_with(Some(300), None));
+
+        assert_eq!(params.since_seconds, Some(300));
+        assert_eq!(params.tail_lines, None);
+    }
+
+    #[test]
+    fn normal_start_gets_the_tail_default() {
+        let params = stream_log_params(&options_with(None, None));
+
+        assert_eq!(params.tail_lines, Some(100));
+        assert_eq!(params.since_seconds, None);
+    }
+
+    #[test]
+    fn an_explicit_tail_is_respected() {
+        let params = stream_log_params(&options_with(None, Some(500)));
+        assert_eq!(params.tail_lines, Some(500));
+
+        // Even together with since_seconds, if the caller really asks for both
+        let both = stream_log_params(&options_with(Some(60), Some(500)));
+        assert_eq!(both.tail_lines, Some(500));
+        assert_eq!(both.since_seconds, Some(60));
+    }
+
+    // A stream that drops mid-run is recoverable; only a connection that never
+    // established is an Error. The frontend branches on this tag to decide
+    // between an error banner and a reconnect prompt.
+    #[test]
+    fn ended_is_a_distinct_event_from_error() {
+        let ended = serde_json::to_value(LogEvent::Ended {
+            stream_id: "logs-1".to_string(),
+            reason: Some("connection reset".to_string()),
+        })
+        .unwrap();
+
+        assert_eq!(ended["type"], "Ended");
+        assert_eq!(ended["data"]["stream_id"], "logs-1");
+        assert_eq!(ended["data"]["reason"], "connection reset");
+    }
+
+    #[test]
+    fn clean_end_of_stream_carries_no_reason() {
+        let ended = serde_json::to_value(LogEvent::Ended {
+            stream_id: "logs-1".to_string(),
+            reason: None,
+        })
+        .unwrap();
+
+        // null rather than a placeholder string: the viewer distinguishes a
+        // container that exited from a connection that was cut
+        assert!(ended["data"]["reason"].is_null());
+    }
+
+    #[test]
+    fn parses_timestamped_log_line() {
+        let entry = parse_log_line(
+            "2024-01-01T12:00:00.000000000Z hello world",
+            "app",
+            "demo-pod",
+            "default",
+        );
+
+        assert_eq!(
+            entry.timestamp.as_deref(),
+            Some("2024-01-01T12:00:00.000000000Z")
+        );
+        assert_eq!(entry.message, "hello world");
+        assert_eq!(entry.container, "app");
+        assert_eq!(entry.pod, "demo-pod");
+        assert_eq!(entry.namespace, "default");
+    }
+
+    #[test]
+    fn parses_plain_log_line() {
+        let entry = parse_log_line("plain log", "app", "demo-pod", "default");
+
+        assert!(entry.timestamp.is_none());
+        assert_eq!(entry.message, "plain log");
+    }
+
+    #[test]
+    fn parses_log_line_with_spaces_in_message() {
+        let entry = parse_log_line(
+            "2024-06-15T08:30:45.123456789Z INFO Starting server on port 8080",
+            "nginx",
+            "web-pod",
+            "production",
+        );
+
+        assert_eq!(
+            entry.timestamp.as_deref(),
+            Some("2024-06-15T08:30:45.123456789Z")
+        );
+        assert_eq!(entry.message, "INFO Starting server on port 8080");
+    }
+
+    #[test]
+    fn parses_empty_log_line() {
+        let entry = parse_log_line("", "app", "demo-pod", "default");
+
+        assert!(entry.timestamp.is_none());
+        assert_eq!(entry.message, "");
+    }
+
+    #[test]
+    fn parses_short_log_line() {
+        let entry = parse_log_line("abc", "app", "demo-pod", "default");
+
+        assert!(entry.timestamp.is_none());
+        assert_eq!(entry.message, "abc");
+    }
+
+    #[test]
+    fn log_entry_serialization() {
+        let entry = LogEntry {
+            timestamp: Some("2024-01-01T00:00:00Z".to_string()),
+            message: "Test message".to_string(),
+            container: "app".to_string(),
+            pod: "test-pod".to_string(),
+            namespace: "default".to_string(),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"message\":\"Test message\""));
+        assert!(json.contains("\"container\":\"app\""));
+    }
+
+    #[test]
+    fn log_options_default() {
+        let options = LogOptions::default();
+
+        assert!(options.namespace.is_empty());
+        assert!(options.pod_name.is_empty());
+        assert!(options.container.is_none());
+        assert!(options.follow.is_none());
+        assert!(options.tail_lines.is_none());
+    }
+
+    #[test]
+    fn log_event_line_serialization() {
+        let entry = LogEntry {
+            timestamp: None,
+            message: "test".to_string(),
+            container: "app".to_string(),
+            pod: "pod".to_string(),
+            namespace: "ns".to_string(),
+            ..Default::default()
+        };
+        let event = LogEvent::Line(entry);
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"type\":\"Line\""));
+    }
+
+    #[test]
+    fn log_event_error_serialization() {
+        let event = LogEvent::Error(KubeliError::unknown("Connection failed"));
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"type\":\"Error\""));
+        assert!(json.contains("Connection failed"));
+    }
+
+    #[test]
+    fn log_event_started_serialization() {
+        let event = LogEvent::Started {
+            stream_id: "stream-123".to_string(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"type\":\"Started\""));
+        assert!(json.contains("stream-123"));
+    }
+
+    #[test]
+    fn log_event_stopped_serialization() {
+        let event = LogEvent::Stopped {
+            stream_id: "stream-456".to_string(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"type\":\"Stopped\""));
+        assert!(json.contains("stream-456"));
+    }
+
+    #[tokio::test]
+    async fn log_stream_manager_new() {
+        let manager = LogStreamManager::new();
+
+        // Should start with no active streams
+        assert!(!manager.is_active("non-existent").await);
+    }
+
+    #[tokio::test]
+    async fn log_stream_manager_add_and_check() {
+        let manager = LogStreamManager::new();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+
+        manager
+            .add_stream("stream-1".to_string(), "ctx".to_string(), stop_flag)
+            .await;
+
+        assert!(manager.is_active("stream-1").await);
+        assert!(!manager.is_active("stream-2").await);
+    }
+
+    #[tokio::test]
+    async fn log_stream_manager_stop_stream() {
+        let manager = LogStreamManager::new();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+
+        manager
+            .add_stream("stream-1".to_string(), "ctx".to_string(), stop_flag.clone())
+            .await;
+
+        // Stop flag should be false initially
+        assert!(!stop_flag.load(Ordering::SeqCst));
+
+        // Stop the stream
+        let result = manager.stop_stream("stream-1").await;
+        assert!(result);
+
+        // Stop flag should now be true
+        assert!(stop_flag.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn log_stream_manager_stop_non_existent() {
+        let manager = LogStreamManager::new();
+
+        let result = manager.stop_stream("non-existent").await;
+        assert!(!result);
+    }
+
+    #[tokio::test]
+    async fn log_stream_manager_remove_stream() {
+        let manager = LogStreamManager::new();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+
+        manager
+            .add_stream("stream-1".to_string(), "ctx".to_string(), stop_flag)
+            .await;
+        assert!(manager.is_active("stream-1").await);
+
+        manager.remove_stream("stream-1").await;
+        assert!(!manager.is_active("stream-1").await);
+    }
+
+    #[test]
+    fn log_stream_manager_default() {
+        let manager = LogStreamManager::default();
+        // Just verify it doesn't panic and creates properly
+        assert!(std::mem::size_of_val(&manager) > 0);
+    }
+
+    #[tokio::test]
+    async fn stop_all_flags_every_stream_and_clears_the_map() {
+        let manager = LogStreamManager::new();
+        let flag_a = Arc::new(AtomicBool::new(false));
+        let flag_b = Arc::new(AtomicBool::new(false));
+        manager
+            .add_stream("a".into(), "ctx".into(), flag_a.clone())
+            .await;
+        manager
+            .add_stream("b".into(), "ctx".into(), flag_b.clone())
+            .await;
+
+        manager.stop_all().await;
+
+        assert!(flag_a.load(Ordering::SeqCst));
+        assert!(flag_b.load(Ordering::SeqCst));
+        assert!(!manager.is_active("a").await);
+        assert!(!manager.is_active("b").await);
+    }
+
+    #[tokio::test]
+    async fn stop_context_only_stops_streams_of_that_cluster() {
+        let manager = LogStreamManager::new();
+        let prod = Arc::new(AtomicBool::new(false));
+        let staging = Arc::new(AtomicBool::new(false));
+        manager
+            .add_stream("a".into(), "prod".into(), prod.clone())
+            .await;
+        manager
+            .add_stream("b".into(), "staging".into(), staging.clone())
+            .await;
+
+        manager.stop_context("prod").await;
+
+        assert!(prod.load(Ordering::SeqCst));
+        assert!(!staging.load(Ordering::SeqCst));
+        assert!(!manager.is_active("a").await);
+        assert!(manager.is_active("b").await);
+    }
+
+    fn pod_with_containers(states: &[(&str, &str)]) -> Pod {
+        use k8s_openapi::api::core::v1::{
+            ContainerState, ContainerStateRunning, ContainerStateTerminated, ContainerStateWaiting,
+            ContainerStatus, PodStatus,
+        };
+        let statuses = states
+            .iter()
+            .map(|(name, state)| ContainerStatus {
+                name: name.to_string(),
+                state: Some(match *state {
+                    "running" => ContainerState {
+                        running: Some(ContainerStateRunning::default()),
+                        ..Default::default()
+                    },
+                    "terminated" => ContainerState {
+                        terminated: Some(ContainerStateTerminated::default()),
+                        ..Default::default()
+                    },
+                    _ => ContainerState {
+                        waiting: Some(ContainerStateWaiting::default()),
+                        ..Default::default()
+                    },
+                }),
+                ..Default::default()
+            })
+            .collect();
+        let mut pod = Pod::default();
+        pod.metadata.name = Some("web-1".to_string());
+        pod.status = Some(PodStatus {
+            container_statuses: Some(statuses),
+            ..Default::default()
+        });
+        pod
+    }
+
+    #[test]
+    fn only_started_containers_are_followed() {
+        let pod = pod_with_containers(&[
+            ("app", "running"),
+            ("sidecar", "waiting"),
+            ("migrate", "terminated"),
+        ]);
+        assert_eq!(loggable_containers(&pod, None), vec!["app", "migrate"]);
+        assert_eq!(loggable_containers(&pod, Some("app")), vec!["app"]);
+        assert!(loggable_containers(&pod, Some("sidecar")).is_empty());
+        assert!(loggable_containers(&Pod::default(), None).is_empty());
+    }
+
+    #[test]
+    fn attach_and_detach_events_name_pod_and_container() {
+        let json = serde_json::to_value(LogEvent::Attached {
+            pod: "web-1".to_string(),
+            container: "app".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json["type"], "Attached");
+        assert_eq!(json["data"]["pod"], "web-1");
+        assert_eq!(json["data"]["container"], "app");
+
+        let json = serde_json::to_value(LogEvent::Detached {
+            pod: "web-1".to_string(),
+            container: "app".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json["type"], "Detached");
+    }
+
+    #[test]
+    fn aggregated_options_accept_a_workload_reference() {
+        let options: AggregatedLogOptions = serde_json::from_value(serde_json::json!({
+            "namespace": "shop",
+            "workload": { "kind": "Deployment", "name": "web" }
+        }))
+        .unwrap();
+        assert_eq!(options.namespace, "shop");
+        assert!(options.label_selector.is_none());
+        let workload = options.workload.unwrap();
+        assert_eq!(
+            (workload.kind.as_str(), workload.name.as_str()),
+            ("Deployment", "web")
+        );
+    }
+
+    #[tokio::test]
+    async fn recorder_archives_batches_in_order() {
+        let archive: SharedLogArchive =
+            Arc::new(archive::LogArchive::new(":memory:".into()).unwrap());
+        let recorder = LogRecorder::new(Arc::clone(&archive), "ctx".to_string());
+        for i in 0..50 {
+            recorder.record(&[parse_log_line(
+                &format!("line {}", i),
+                "app",
+                "web",
+                "default",
+            )]);
+        }
+        drop(recorder);
+
+        let lines = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let lines = archive.search(LogArchiveQuery::default()).await.unwrap();
+                if lines.len() == 50 {
+                    break lines;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("recorder did not archive every batch");
+        let messages: Vec<String> = lines.into_iter().map(|l| l.entry.message).collect();
+        let expected: Vec<String> = (0..50).map(|i| format!("line {}", i)).collect();
+        assert_eq!(messages, expected);
+    }
+}
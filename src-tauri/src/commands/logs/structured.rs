@@ -0,0 +1,233 @@
+//! Structured log parsing.
+//!
+//! Most workloads log JSON or logfmt. Parsing those lines once on the
+//! backend gives the viewer, the filter and exports the same `fields`,
+//! `level` and `msg` without each re-implementing format detection.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Normalized log severity, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl LogLevel {
+    /// Map the many spellings loggers use onto one level
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "trace" | "trc" => Some(Self::Trace),
+            "debug" | "dbg" | "d" => Some(Self::Debug),
+            "info" | "inf" | "information" | "notice" | "i" => Some(Self::Info),
+            "warn" | "warning" | "wrn" | "w" => Some(Self::Warn),
+            "error" | "err" | "eror" | "e" => Some(Self::Error),
+            "fatal" | "critical" | "crit" | "panic" | "emerg" | "alert" | "f" => Some(Self::Fatal),
+            _ => None,
+        }
+    }
+
+    /// Numeric levels as written by pino and bunyan (10 = trace ... 60 = fatal)
+    fn from_number(n: f64) -> Option<Self> {
+        match n as i64 {
+            ..=10 => Some(Self::Trace),
+            11..=20 => Some(Self::Debug),
+            21..=30 => Some(Self::Info),
+            31..=40 => Some(Self::Warn),
+            41..=50 => Some(Self::Error),
+            _ => Some(Self::Fatal),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Fatal => "fatal",
+        }
+    }
+}
+
+/// Keys loggers commonly use for the level in structured output
+const LEVEL_KEYS: [&str; 4] = ["level", "lvl", "severity", "loglevel"];
+/// Keys loggers commonly use for the human-readable message
+const MSG_KEYS: [&str; 3] = ["msg", "message", "log"];
+
+/// Result of parsing one log message
+#[derive(Debug, Default, PartialEq)]
+pub struct StructuredLine {
+    pub fields: BTreeMap<String, Value>,
+    pub level: Option<LogLevel>,
+    pub msg: Option<String>,
+}
+
+/// Parse a log message (without the kubelet timestamp) as JSON or logfmt.
+/// Plain text lines get no fields, but still a level when one of the first
+/// few words names it (`ERROR ...`, `[warn] ...`, `2024-01-01 INFO ...`).
+pub fn parse_structured(message: &str) -> StructuredLine {
+    let fields = parse_json_object(message)
+        .or_else(|| parse_logfmt(message))
+        .unwrap_or_default();
+    if fields.is_empty() {
+        return StructuredLine {
+            level: detect_plain_level(message),
+            ..Default::default()
+        };
+    }
+
+    let lookup = |keys: &[&str]| {
+        keys.iter().find_map(|key| {
+            fields
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .map(|(_, v)| v)
+        })
+    };
+    let level = lookup(&LEVEL_KEYS).and_then(|value| match value {
+        Value::String(s) => LogLevel::parse(s),
+        Value::Number(n) => n.as_f64().and_then(LogLevel::from_number),
+        _ => None,
+    });
+    let msg = lookup(&MSG_KEYS).and_then(|value| value.as_str().map(str::to_string));
+
+    StructuredLine { fields, level, msg }
+}
+
+fn parse_json_object(message: &str) -> Option<BTreeMap<String, Value>> {
+    let trimmed = message.trim();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    match serde_json::from_str(trimmed) {
+        Ok(Value::Object(map)) => Some(map.into_iter().collect()),
+        _ => None,
+    }
+}
+
+/// Parse a logfmt line (`key=value key2="quoted value"`). Only lines made
+/// entirely of at least two pairs count, so prose that happens to contain
+/// an `=` stays plain text.
+fn parse_logfmt(message: &str) -> Option<BTreeMap<String, Value>> {
+    let mut fields = BTreeMap::new();
+    let mut chars = message.trim().chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            key.push(c);
+        }
+        if key.is_empty() || chars.next() != Some('=') {
+            return None;
+        }
+
+        let mut value = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next()? {
+                    '"' => break,
+                    '\\' => match chars.next()? {
+                        'n' => value.push('\n'),
+                        't' => value.push('\t'),
+                        other => value.push(other),
+                    },
+                    c => value.push(c),
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                value.push(c);
+            }
+        }
+        fields.insert(key, Value::String(value));
+    }
+
+    (fields.len() >= 2).then_some(fields)
+}
+
+fn detect_plain_level(message: &str) -> Option<LogLevel> {
+    message
+        .split_whitespace()
+        .take(4)
+        .map(|token| token.trim_matches(|c: char| !c.is_ascii_alphabetic()))
+        // Single letters are ignored, otherwise ordinary words like "a" or
+        // "I" would be read as levels.
+        .filter(|word| word.len() > 1)
+        .find_map(LogLevel::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_lines() {
+        let line = parse_structured(r#"{"level":"error","msg":"boom","status":503}"#);
+        assert_eq!(line.level, Some(LogLevel::Error));
+        assert_eq!(line.msg.as_deref(), Some("boom"));
+        assert_eq!(line.fields.get("status"), Some(&Value::from(503)));
+    }
+
+    #[test]
+    fn parses_json_with_alternative_keys_and_numeric_levels() {
+        let line = parse_structured(r#"{"severity":"WARNING","message":"slow"}"#);
+        assert_eq!(line.level, Some(LogLevel::Warn));
+        assert_eq!(line.msg.as_deref(), Some("slow"));
+
+        let pino = parse_structured(r#"{"level":50,"msg":"failed"}"#);
+        assert_eq!(pino.level, Some(LogLevel::Error));
+    }
+
+    #[test]
+    fn parses_logfmt_lines() {
+        let line = parse_structured(r#"time=2024-01-01 level=debug msg="cache \"warm\"" hits=3"#);
+        assert_eq!(line.level, Some(LogLevel::Debug));
+        assert_eq!(line.msg.as_deref(), Some(r#"cache "warm""#));
+        assert_eq!(line.fields.get("hits"), Some(&Value::from("3")));
+        assert_eq!(line.fields.len(), 4);
+    }
+
+    #[test]
+    fn prose_with_equals_is_not_logfmt() {
+        assert!(parse_logfmt("retrying with backoff=2s").is_none());
+        assert!(parse_logfmt("single=pair").is_none());
+        assert!(parse_logfmt(r#"a=1 b="unterminated"#).is_none());
+    }
+
+    #[test]
+    fn plain_lines_keep_a_detected_level() {
+        let line = parse_structured("2024-01-01 12:00:00 ERROR failed");
+        assert!(line.fields.is_empty());
+        assert!(line.msg.is_none());
+        assert_eq!(line.level, Some(LogLevel::Error));
+
+        assert_eq!(
+            parse_structured("[INFO] server started").level,
+            Some(LogLevel::Info)
+        );
+        assert_eq!(parse_structured("a plain sentence").level, None);
+        assert_eq!(
+            parse_structured("    at com.example.Main(Main.java:1)").level,
+            None
+        );
+    }
+
+    #[test]
+    fn json_arrays_are_not_structured() {
+        assert_eq!(parse_structured("[1,2,3]"), StructuredLine::default());
+    }
+}
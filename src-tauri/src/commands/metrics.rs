@@ -217,8 +217,13 @@ fn format_memory(bytes: u64) -> String {
 pub async fn get_node_metrics(
     state: State<'_, AppState>,
     node_name: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<NodeMetrics>, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     // No pre-check probe: the real query below fails with a classifiable
     // 404 when metrics-server is missing - probing first doubles latency.
@@ -335,8 +340,13 @@ pub async fn get_pod_metrics(
     state: State<'_, AppState>,
     namespace: Option<String>,
     pod_name: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<PodMetrics>, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     // No pre-check probe: the real query below fails with a classifiable
     // 404 when metrics-server is missing.
@@ -441,8 +451,13 @@ pub async fn get_pod_metrics(
 #[command]
 pub async fn get_cluster_metrics_summary(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<ClusterMetricsSummary, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let metrics_available = check_metrics_available(&client).await;
     let timestamp = chrono::Utc::now().to_rfc3339();
@@ -664,8 +679,15 @@ pub async fn get_cluster_metrics_summary(
 
 /// Check if metrics-server is installed
 #[command]
-pub async fn check_metrics_server(state: State<'_, AppState>) -> Result<bool, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+pub async fn check_metrics_server(
+    state: State<'_, AppState>,
+    context: Option<String>,
+) -> Result<bool, String> {
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     Ok(check_metrics_available(&client).await)
 }
@@ -725,8 +747,13 @@ mod kubelet_stats {
 pub async fn get_pod_metrics_direct(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<PodMetrics>, String> {
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let client = state
+        .k8s
+        .get_client_for(context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     // List all nodes to query each kubelet
     use k8s_openapi::api::core::v1::Node;
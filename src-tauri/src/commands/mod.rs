@@ -1,10 +1,15 @@
+pub mod apply;
 pub mod argocd;
+pub mod bulk;
 pub mod certificates;
 pub mod cluster_settings;
 pub mod clusters;
 pub mod debug;
+pub mod drain;
+pub mod file_copy;
 pub mod flux;
 pub mod graph;
+pub mod graph_export;
 pub mod helm;
 pub mod kubeconfig;
 pub mod logs;
@@ -12,6 +17,8 @@ pub mod mcp;
 pub mod metrics;
 pub mod network;
 pub mod portforward;
+pub mod portforward_profiles;
 pub mod resources;
+pub mod rollout;
 pub mod shell;
 pub mod watch;
@@ -7,7 +7,7 @@ use kube::runtime::watcher::{watcher, Config, Event};
 use rand::RngExt;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
-use std::net::SocketAddr;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 use tauri::{command, AppHandle, Emitter, State};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -21,8 +21,10 @@ struct PortForwardSession {
     /// Becomes true once the forward task has exited and dropped its listener,
     /// i.e. the local port is free again.
     finished: watch::Receiver<bool>,
-    local_port: u16,
-    target_port: u16,
+    /// Local address every port of the forward listens on
+    bind_address: IpAddr,
+    /// Never empty; the first entry is the primary port
+    ports: Vec<ForwardedPort>,
     target_type: PortForwardTargetType,
     /// Immutable owner used to route health-watch events after cluster switches.
     cluster_context: String,
@@ -59,7 +61,9 @@ pub enum PortForwardStatus {
 pub enum PortForwardEvent {
     Started {
         forward_id: String,
+        /// Local port of the primary (first) port
         local_port: u16,
+        ports: Vec<ForwardedPort>,
     },
     Connected {
         forward_id: String,
@@ -88,24 +92,100 @@ pub enum PortForwardEvent {
     },
 }
 
+/// A port requested for a forward
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PortForwardPortSpec {
+    /// Pod port, or service port for services: a number or a port name
+    pub port: IntOrString,
+    /// Random free port when unset
+    #[serde(default)]
+    pub local_port: Option<u16>,
+}
+
+/// One port of a running forward
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForwardedPort {
+    /// Port as requested (e.g. the service port name "grpc")
+    pub requested: IntOrString,
+    pub local_port: u16,
+    /// Resolved container port on the pod
+    pub target_port: u16,
+}
+
 /// Options for starting a port forward
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortForwardOptions {
     pub namespace: String,
     pub name: String,
     pub target_type: PortForwardTargetType,
-    pub target_port: u16,
+    /// Single-port shorthand, used when `ports` is empty
+    #[serde(default)]
+    pub target_port: Option<u16>,
+    #[serde(default)]
     pub local_port: Option<u16>,
+    /// Ports to forward, named or numbered; one listener each
+    #[serde(default)]
+    pub ports: Vec<PortForwardPortSpec>,
+    /// Local address to listen on, e.g. "0.0.0.0" inside a devcontainer.
+    /// Loopback when unset.
+    #[serde(default)]
+    pub bind_address: Option<String>,
+    /// Allow a non-loopback `bind_address`. It exposes the forwarded port to
+    /// the network, so it has to be asked for explicitly.
+    #[serde(default)]
+    pub allow_external_bind: bool,
     /// Cluster this forward is meant for. Set it when the caller decided on a
     /// cluster earlier and the active one may have changed since (restarting a
-    /// forward after an OIDC refresh, for example): the start then fails
-    /// instead of silently tunnelling to whichever cluster is active now.
-    /// `None` means "whatever is active", which is right for a fresh start the
-    /// user just triggered.
+    /// forward after an OIDC refresh, for example): the forward then tunnels
+    /// through that context's own client, and the start fails if it is no
+    /// longer connected instead of silently tunnelling to whichever cluster is
+    /// active now. `None` means "whatever is active", which is right for a
+    /// fresh start the user just triggered.
     #[serde(default)]
     pub expected_context: Option<String>,
 }
 
+impl PortForwardOptions {
+    /// The requested ports: `ports`, or the single `target_port`
+    fn port_specs(&self) -> Result<Vec<PortForwardPortSpec>, String> {
+        if !self.ports.is_empty() {
+            return Ok(self.ports.clone());
+        }
+        let port = self
+            .target_port
+            .ok_or_else(|| "Port forward needs at least one port".to_string())?;
+        Ok(vec![PortForwardPortSpec {
+            port: IntOrString::Int(port.into()),
+            local_port: self.local_port,
+        }])
+    }
+
+    fn bind_ip(&self) -> Result<IpAddr, String> {
+        let ip: IpAddr = match self.bind_address.as_deref().map(str::trim) {
+            None | Some("") => return Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+            Some(address) => address
+                .parse()
+                .map_err(|_| format!("Invalid bind address: {}", address))?,
+        };
+        if ip.is_loopback() {
+            return Ok(ip);
+        }
+        if !self.allow_external_bind {
+            return Err(format!(
+                "Bind address {} is reachable from the network; allow external binding to use it",
+                ip
+            ));
+        }
+        tracing::warn!(
+            "Port forward to {}/{} listens on {}, reachable from the network",
+            self.namespace,
+            self.name,
+            ip
+        );
+        Ok(ip)
+    }
+}
+
 /// Port forward info returned to frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortForwardInfo {
@@ -114,13 +194,36 @@ pub struct PortForwardInfo {
     pub namespace: String,
     pub name: String,
     pub target_type: PortForwardTargetType,
+    /// Primary (first) port
     pub target_port: u16,
     pub local_port: u16,
+    pub ports: Vec<ForwardedPort>,
+    pub bind_address: String,
     pub status: PortForwardStatus,
     pub pod_name: Option<String>,
     pub pod_uid: Option<String>,
 }
 
+impl PortForwardSession {
+    fn info(&self, forward_id: &str, status: PortForwardStatus) -> PortForwardInfo {
+        let primary = &self.ports[0];
+        PortForwardInfo {
+            forward_id: forward_id.to_string(),
+            cluster_context: self.cluster_context.clone(),
+            namespace: self.namespace.clone(),
+            name: self.name.clone(),
+            target_type: self.target_type.clone(),
+            target_port: primary.target_port,
+            local_port: primary.local_port,
+            ports: self.ports.clone(),
+            bind_address: self.bind_address.to_string(),
+            status,
+            pod_name: Some(self.pod_name.clone()),
+            pod_uid: Some(self.pod_uid.clone()),
+        }
+    }
+}
+
 /// Manager for active port forward sessions
 pub struct PortForwardManager {
     sessions: RwLock<HashMap<String, PortForwardSession>>,
@@ -142,18 +245,7 @@ impl PortForwardManager {
         let sessions = self.sessions.read().await;
         if let Some(s) = sessions.get(id) {
             let status = s.status.read().await.clone();
-            Some(PortForwardInfo {
-                forward_id: id.to_string(),
-                cluster_context: s.cluster_context.clone(),
-                namespace: s.namespace.clone(),
-                name: s.name.clone(),
-                target_type: s.target_type.clone(),
-                target_port: s.target_port,
-                local_port: s.local_port,
-                status,
-                pod_name: Some(s.pod_name.clone()),
-                pod_uid: Some(s.pod_uid.clone()),
-            })
+            Some(s.info(id, status))
         } else {
             None
         }
@@ -176,7 +268,7 @@ impl PortForwardManager {
         sessions.remove(id)
     }
 
-    async fn is_active(&self, id: &str) -> bool {
+    pub(crate) async fn is_active(&self, id: &str) -> bool {
         let sessions = self.sessions.read().await;
         sessions.contains_key(id)
     }
@@ -198,25 +290,16 @@ impl PortForwardManager {
         let mut result = Vec::new();
         for (id, s) in sessions.iter() {
             let status = s.status.read().await.clone();
-            result.push(PortForwardInfo {
-                forward_id: id.clone(),
-                cluster_context: s.cluster_context.clone(),
-                namespace: s.namespace.clone(),
-                name: s.name.clone(),
-                target_type: s.target_type.clone(),
-                target_port: s.target_port,
-                local_port: s.local_port,
-                status,
-                pod_name: Some(s.pod_name.clone()),
-                pod_uid: Some(s.pod_uid.clone()),
-            });
+            result.push(s.info(id, status));
         }
         result
     }
 
     async fn is_port_in_use(&self, port: u16) -> bool {
         let sessions = self.sessions.read().await;
-        sessions.values().any(|s| s.local_port == port)
+        sessions
+            .values()
+            .any(|s| s.ports.iter().any(|p| p.local_port == port))
     }
 
     /// Update a session's pod target without changing forward_id
@@ -265,13 +348,18 @@ impl PortForwardManager {
     async fn get_reconnect_info(
         &self,
         id: &str,
-    ) -> Option<(String, u16, u16, Arc<RwLock<PortForwardStatus>>)> {
+    ) -> Option<(
+        String,
+        IpAddr,
+        Vec<ForwardedPort>,
+        Arc<RwLock<PortForwardStatus>>,
+    )> {
         let sessions = self.sessions.read().await;
         sessions.get(id).map(|s| {
             (
                 s.namespace.clone(),
-                s.local_port,
-                s.target_port,
+                s.bind_address,
+                s.ports.clone(),
                 s.status.clone(),
             )
         })
@@ -303,7 +391,7 @@ async fn cleanup_session(
 /// Bind a listener on a random high port (30000-60000). Returns the bound
 /// listener so the caller keeps ownership of the port; a check-then-bind-later
 /// pattern would let another process grab it in between.
-async fn bind_available_port() -> Result<TcpListener, String> {
+async fn bind_available_port(address: IpAddr) -> Result<TcpListener, String> {
     // Generate random ports upfront to avoid Send issues with rng
     let random_ports: Vec<u16> = {
         let mut rng = rand::rng();
@@ -312,14 +400,14 @@ async fn bind_available_port() -> Result<TcpListener, String> {
 
     // Try random ports first
     for port in random_ports {
-        if let Ok(listener) = TcpListener::bind(format!("127.0.0.1:{}", port)).await {
+        if let Ok(listener) = TcpListener::bind((address, port)).await {
             return Ok(listener);
         }
     }
 
     // Fallback: try sequential from 30000
     for port in 30000..65535 {
-        if let Ok(listener) = TcpListener::bind(format!("127.0.0.1:{}", port)).await {
+        if let Ok(listener) = TcpListener::bind((address, port)).await {
             return Ok(listener);
         }
     }
@@ -348,6 +436,27 @@ pub async fn portforward_start(
     pf_watch_manager: State<'_, Arc<PortForwardWatchManager>>,
     forward_id: String,
     options: PortForwardOptions,
+) -> Result<PortForwardInfo, String> {
+    start_forward(
+        &app,
+        &state,
+        &pf_manager,
+        &pf_watch_manager,
+        forward_id,
+        options,
+    )
+    .await
+}
+
+/// Start a port forward session outside a command invocation (e.g. profiles
+/// auto-started on connect). Same contract as `portforward_start`.
+pub(crate) async fn start_forward(
+    app: &AppHandle,
+    state: &AppState,
+    pf_manager: &Arc<PortForwardManager>,
+    pf_watch_manager: &Arc<PortForwardWatchManager>,
+    forward_id: String,
+    options: PortForwardOptions,
 ) -> Result<PortForwardInfo, String> {
     if pf_manager.is_active(&forward_id).await {
         return Err(format!("Port forward {} already exists", forward_id));
@@ -357,7 +466,7 @@ pub async fn portforward_start(
     // switch and tag this tunnel with the wrong owner (see get_connection).
     let (client, cluster_context) = state
         .k8s
-        .get_connection()
+        .get_connection_for(options.expected_context.as_deref())
         .await
         .map_err(|e| e.to_string())?;
 
@@ -365,27 +474,35 @@ pub async fn portforward_start(
     // cluster before this call and a switch may have landed in between.
     ensure_expected_context(options.expected_context.as_deref(), &cluster_context)?;
 
-    // Bind the listener once and hand it to the forward task; re-binding
-    // inside the task would leave a window where another process takes the
+    let specs = options.port_specs()?;
+    let bind_address = options.bind_ip()?;
+
+    // Bind the listeners once and hand them to the forward task; re-binding
+    // inside the task would leave a window where another process takes a
     // port and the failure only surfaces asynchronously.
-    let listener = match options.local_port {
-        Some(port) => {
-            if pf_manager.is_port_in_use(port).await {
-                return Err(format!("Port {} is already in use", port));
+    let mut listeners = Vec::with_capacity(specs.len());
+    for spec in &specs {
+        let listener = match spec.local_port {
+            Some(port) => {
+                if pf_manager.is_port_in_use(port).await {
+                    return Err(format!("Port {} is already in use", port));
+                }
+                TcpListener::bind((bind_address, port))
+                    .await
+                    .map_err(|_| format!("Port {} is not available", port))?
             }
-            TcpListener::bind(format!("127.0.0.1:{}", port))
-                .await
-                .map_err(|_| format!("Port {} is not available", port))?
-        }
-        None => bind_available_port().await?,
-    };
-    let local_port = listener
-        .local_addr()
-        .map_err(|e| format!("Failed to read local address: {}", e))?
-        .port();
+            None => bind_available_port(bind_address).await?,
+        };
+        let local_port = listener
+            .local_addr()
+            .map_err(|e| format!("Failed to read local address: {}", e))?
+            .port();
+        listeners.push((listener, local_port));
+    }
 
-    // Verify target exists and get pod + resolved target port + UID + selector
-    let (pod_name, pod_uid, resolved_target_port, service_selector) = match &options.target_type {
+    // Verify target exists and get pod + UID + selector, then resolve every
+    // requested port to a container port on that pod
+    let (pod_name, pod_uid, service, service_selector, target_pod) = match &options.target_type {
         PortForwardTargetType::Pod => {
             let pods: Api<Pod> = Api::namespaced(client.clone(), &options.namespace);
             let pod = pods
@@ -393,7 +510,7 @@ pub async fn portforward_start(
                 .await
                 .map_err(|e| format!("Failed to get pod: {}", e))?;
             let uid = pod.metadata.uid.clone().unwrap_or_default();
-            (options.name.clone(), uid, options.target_port, None)
+            (options.name.clone(), uid, None, None, pod)
         }
         PortForwardTargetType::Service => {
             let services: Api<Service> = Api::namespaced(client.clone(), &options.namespace);
@@ -406,7 +523,8 @@ pub async fn portforward_start(
                 .spec
                 .as_ref()
                 .and_then(|s| s.selector.as_ref())
-                .ok_or_else(|| "Service has no selector".to_string())?;
+                .ok_or_else(|| "Service has no selector".to_string())?
+                .clone();
 
             let label_selector: String = selector
                 .iter()
@@ -440,13 +558,22 @@ pub async fn portforward_start(
 
             let uid = target_pod.metadata.uid.clone().unwrap_or_default();
 
-            let resolved_port = resolve_service_target_port(&svc, &target_pod, options.target_port)
-                .unwrap_or(options.target_port);
-
-            (pod_name, uid, resolved_port, Some(selector.clone()))
+            (pod_name, uid, Some(svc), Some(selector), target_pod)
         }
     };
 
+    let mut ports = Vec::with_capacity(specs.len());
+    let mut bound = Vec::with_capacity(specs.len());
+    for (spec, (listener, local_port)) in specs.into_iter().zip(listeners) {
+        let target_port = resolve_requested_port(service.as_ref(), &target_pod, &spec.port)?;
+        ports.push(ForwardedPort {
+            requested: spec.port,
+            local_port,
+            target_port,
+        });
+        bound.push((listener, target_port));
+    }
+
     let cancel = CancellationToken::new();
     let (finished_tx, finished_rx) = watch::channel(false);
     let status = Arc::new(RwLock::new(PortForwardStatus::Connecting));
@@ -454,30 +581,18 @@ pub async fn portforward_start(
     let session = PortForwardSession {
         cancel: cancel.clone(),
         finished: finished_rx,
-        local_port,
-        target_port: resolved_target_port,
+        bind_address,
+        ports: ports.clone(),
         target_type: options.target_type.clone(),
         cluster_context: cluster_context.clone(),
         namespace: options.namespace.clone(),
         name: options.name.clone(),
         pod_name: pod_name.clone(),
         pod_uid: pod_uid.clone(),
-        service_selector: service_selector.clone(),
+        service_selector,
         status: status.clone(),
     };
-
-    let info = PortForwardInfo {
-        forward_id: forward_id.clone(),
-        cluster_context: cluster_context.clone(),
-        namespace: options.namespace.clone(),
-        name: options.name.clone(),
-        target_type: options.target_type.clone(),
-        target_port: resolved_target_port,
-        local_port,
-        status: PortForwardStatus::Connecting,
-        pod_name: Some(pod_name.clone()),
-        pod_uid: Some(pod_uid.clone()),
-    };
+    let info = session.info(&forward_id, PortForwardStatus::Connecting);
 
     // All fallible setup is done; inserting last means a failed start never
     // leaves a phantom session in the manager.
@@ -485,49 +600,48 @@ pub async fn portforward_start(
 
     let event_name = format!("portforward-{}", forward_id);
     let forward_id_clone = forward_id.clone();
-    let pf_manager_clone = Arc::clone(&pf_manager);
-    let pf_watch_manager_clone = Arc::clone(&pf_watch_manager);
+    let pf_manager_clone = Arc::clone(pf_manager);
+    let pf_watch_manager_clone = Arc::clone(pf_watch_manager);
     let namespace = options.namespace.clone();
-    let target_port = resolved_target_port;
 
     let _ = app.emit(
         &event_name,
         PortForwardEvent::Started {
             forward_id: forward_id.clone(),
-            local_port,
+            local_port: info.local_port,
+            ports: ports.clone(),
         },
     );
 
     // Start the pod health watcher for this namespace
-    Arc::clone(&pf_watch_manager)
+    Arc::clone(pf_watch_manager)
         .ensure_namespace_watcher(
             client.clone(),
             app.clone(),
             cluster_context,
             options.namespace.clone(),
-            Arc::clone(&pf_manager),
+            Arc::clone(pf_manager),
         )
         .await;
 
     let status_for_check = status.clone();
+    let app = app.clone();
     tokio::spawn(async move {
         run_port_forward(
             client,
             app.clone(),
             &event_name,
             &forward_id_clone,
-            listener,
+            bound,
             cancel,
             status,
-            local_port,
-            target_port,
             &namespace,
             &pod_name,
         )
         .await;
 
-        // Listener is dropped once run_port_forward returns; unblock waiters
-        // (stop command, reconnect) that need the port free.
+        // Listeners are dropped once run_port_forward returns; unblock
+        // waiters (stop command, reconnect) that need the ports free.
         let _ = finished_tx.send(true);
 
         // Only cleanup if not being reconnected by the watcher
@@ -549,30 +663,38 @@ pub async fn portforward_start(
     });
 
     tracing::info!(
-        "Started port forward {} (localhost:{} -> {}:{}/pod-port:{})",
+        "Started port forward {} ({} -> {}/{} ports {})",
         forward_id,
-        local_port,
+        bind_address,
         options.namespace,
         options.name,
-        resolved_target_port
+        describe_ports(&ports)
     );
 
     Ok(info)
 }
 
-/// Run the port forward session. Takes ownership of the pre-bound listener
-/// and drops it on return, freeing the local port.
+/// "local->target" pairs for logs, e.g. "41587->8080, 41588->9090"
+fn describe_ports(ports: &[ForwardedPort]) -> String {
+    ports
+        .iter()
+        .map(|p| format!("{}->{}", p.local_port, p.target_port))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Run the port forward session. Takes ownership of the pre-bound listeners,
+/// each paired with the pod port it forwards to, and drops them on return,
+/// freeing the local ports.
 #[allow(clippy::too_many_arguments)]
 async fn run_port_forward(
     client: kube::Client,
     app: AppHandle,
     event_name: &str,
     forward_id: &str,
-    listener: TcpListener,
+    listeners: Vec<(TcpListener, u16)>,
     cancel: CancellationToken,
     status: Arc<RwLock<PortForwardStatus>>,
-    local_port: u16,
-    target_port: u16,
     namespace: &str,
     pod_name: &str,
 ) {
@@ -584,22 +706,63 @@ async fn run_port_forward(
         },
     );
 
-    tracing::info!(
-        "Port forward {} listening on 127.0.0.1:{}",
-        forward_id,
-        local_port
-    );
-
     let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
 
+    // One accept loop per port; all of them end together on cancel
+    futures::future::join_all(listeners.into_iter().map(|(listener, target_port)| {
+        accept_connections(
+            listener,
+            target_port,
+            pods.clone(),
+            pod_name,
+            cancel.clone(),
+            forward_id,
+            app.clone(),
+            event_name,
+        )
+    }))
+    .await;
+    tracing::info!("Port forward {} stopped", forward_id);
+
+    // Only emit Disconnected if not being reconnected by the watcher
+    let current_status = status.read().await.clone();
+    if current_status != PortForwardStatus::Reconnecting {
+        *status.write().await = PortForwardStatus::Disconnected;
+        let _ = app.emit(
+            event_name,
+            PortForwardEvent::Disconnected {
+                forward_id: forward_id.to_string(),
+            },
+        );
+    }
+}
+
+/// Accept connections on one listener of a forward until it is cancelled
+#[allow(clippy::too_many_arguments)]
+async fn accept_connections(
+    listener: TcpListener,
+    target_port: u16,
+    pods: Api<Pod>,
+    pod_name: &str,
+    cancel: CancellationToken,
+    forward_id: &str,
+    app: AppHandle,
+    event_name: &str,
+) {
+    if let Ok(addr) = listener.local_addr() {
+        tracing::info!(
+            "Port forward {} listening on {} -> pod port {}",
+            forward_id,
+            addr,
+            target_port
+        );
+    }
+
     loop {
         tokio::select! {
             // Stop must cut the accept loop immediately, not on the next
             // poll tick.
-            _ = cancel.cancelled() => {
-                tracing::info!("Port forward {} stopped by user", forward_id);
-                break;
-            }
+            _ = cancel.cancelled() => break,
             result = listener.accept() => {
                 match result {
                     Ok((tcp_stream, addr)) => {
@@ -634,18 +797,6 @@ async fn run_port_forward(
             }
         }
     }
-
-    // Only emit Disconnected if not being reconnected by the watcher
-    let current_status = status.read().await.clone();
-    if current_status != PortForwardStatus::Reconnecting {
-        *status.write().await = PortForwardStatus::Disconnected;
-        let _ = app.emit(
-            event_name,
-            PortForwardEvent::Disconnected {
-                forward_id: forward_id.to_string(),
-            },
-        );
-    }
 }
 
 /// Handle a single port forward connection
@@ -759,6 +910,42 @@ fn resolve_service_target_port(
     }
 }
 
+/// Resolve a requested port to the container port on `pod`. Pod targets take
+/// a container port number or name; service targets take a service port
+/// number or name, which then goes through the service's `targetPort`.
+fn resolve_requested_port(
+    service: Option<&Service>,
+    pod: &Pod,
+    requested: &IntOrString,
+) -> Result<u16, String> {
+    let number = |value: i32| u16::try_from(value).map_err(|_| format!("Invalid port {}", value));
+    let Some(service) = service else {
+        return match requested {
+            IntOrString::Int(port) => number(*port),
+            IntOrString::String(name) => name
+                .parse::<u16>()
+                .ok()
+                .or_else(|| find_named_container_port(pod, name))
+                .ok_or_else(|| format!("Pod has no port named {}", name)),
+        };
+    };
+
+    let service_port = match requested {
+        IntOrString::Int(port) => number(*port)?,
+        IntOrString::String(name) => match name.parse::<u16>() {
+            Ok(port) => port,
+            Err(_) => service
+                .spec
+                .as_ref()
+                .and_then(|spec| spec.ports.as_ref())
+                .and_then(|ports| ports.iter().find(|p| p.name.as_deref() == Some(name)))
+                .ok_or_else(|| format!("Service has no port named {}", name))
+                .and_then(|p| number(p.port))?,
+        },
+    };
+    Ok(resolve_service_target_port(service, pod, service_port).unwrap_or(service_port))
+}
+
 fn find_named_container_port(pod: &Pod, name: &str) -> Option<u16> {
     pod.spec.as_ref()?.containers.iter().find_map(|container| {
         container.ports.as_ref()?.iter().find_map(|port| {
@@ -1300,7 +1487,7 @@ async fn reconnect_forward(
     new_pod_uid: &str,
 ) {
     let reconnect_info = pf_manager.get_reconnect_info(forward_id).await;
-    let (_, local_port, target_port, status) = match reconnect_info {
+    let (_, bind_address, ports, status) = match reconnect_info {
         Some(info) => info,
         None => return,
     };
@@ -1337,29 +1524,41 @@ async fn reconnect_forward(
     let pf_watch_manager_clone = Arc::clone(pf_watch_manager);
 
     tokio::spawn(async move {
-        match TcpListener::bind(format!("127.0.0.1:{}", local_port)).await {
-            Ok(listener) => {
+        let mut listeners = Vec::with_capacity(ports.len());
+        let mut bind_error = None;
+        for port in &ports {
+            match TcpListener::bind((bind_address, port.local_port)).await {
+                Ok(listener) => listeners.push((listener, port.target_port)),
+                Err(e) => {
+                    bind_error = Some(format!("Failed to bind to port {}: {}", port.local_port, e));
+                    break;
+                }
+            }
+        }
+
+        match bind_error {
+            None => {
                 run_port_forward(
                     client_clone,
                     app_clone.clone(),
                     &event_name_clone,
                     &forward_id_clone,
-                    listener,
+                    listeners,
                     new_cancel,
                     status_clone,
-                    local_port,
-                    target_port,
                     &namespace_clone,
                     &pod_name_clone,
                 )
                 .await;
             }
-            Err(e) => {
+            Some(message) => {
+                // Release the ports that did bind before reporting
+                drop(listeners);
                 let _ = app_clone.emit(
                     &event_name_clone,
                     PortForwardEvent::Error {
                         forward_id: forward_id_clone.clone(),
-                        message: format!("Failed to bind to port {}: {}", local_port, e),
+                        message,
                     },
                 );
                 *status_clone.write().await = PortForwardStatus::Error;
@@ -1549,8 +1748,12 @@ mod tests {
         PortForwardSession {
             cancel: CancellationToken::new(),
             finished: watch::channel(false).1,
-            local_port,
-            target_port,
+            bind_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            ports: vec![ForwardedPort {
+                requested: IntOrString::Int(target_port.into()),
+                local_port,
+                target_port,
+            }],
             target_type,
             cluster_context: "cluster-a".to_string(),
             namespace: namespace.to_string(),
@@ -1574,8 +1777,12 @@ mod tests {
         PortForwardSession {
             cancel: CancellationToken::new(),
             finished: watch::channel(false).1,
-            local_port,
-            target_port,
+            bind_address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            ports: vec![ForwardedPort {
+                requested: IntOrString::Int(target_port.into()),
+                local_port,
+                target_port,
+            }],
             target_type: PortForwardTargetType::Service,
             cluster_context: "cluster-a".to_string(),
             namespace: namespace.to_string(),
@@ -1751,6 +1958,108 @@ mod tests {
         assert_eq!(find_named_container_port(&pod, "http"), None);
     }
 
+    // ── resolve_requested_port tests ──
+
+    fn name_service_ports(svc: &mut Service, names: &[&str]) {
+        let ports = svc.spec.as_mut().unwrap().ports.as_mut().unwrap();
+        for (port, name) in ports.iter_mut().zip(names) {
+            port.name = Some(name.to_string());
+        }
+    }
+
+    #[test]
+    fn requested_named_service_ports_resolve_through_target_port() {
+        let mut svc = make_service(vec![
+            (80, Some(IntOrString::String("web".into()))),
+            (9000, Some(IntOrString::Int(9090))),
+        ]);
+        name_service_ports(&mut svc, &["http", "grpc"]);
+        let pod = make_pod("p", "u", vec![("web", 8080)]);
+
+        let resolve = |port: IntOrString| resolve_requested_port(Some(&svc), &pod, &port);
+        assert_eq!(resolve(IntOrString::String("http".into())), Ok(8080));
+        assert_eq!(resolve(IntOrString::String("grpc".into())), Ok(9090));
+        assert_eq!(resolve(IntOrString::Int(9000)), Ok(9090));
+        assert!(resolve(IntOrString::String("metrics".into()))
+            .unwrap_err()
+            .contains("metrics"));
+    }
+
+    #[test]
+    fn requested_pod_ports_take_container_port_names() {
+        let pod = make_pod("p", "u", vec![("metrics", 9100)]);
+        let resolve = |port: IntOrString| resolve_requested_port(None, &pod, &port);
+        assert_eq!(resolve(IntOrString::String("metrics".into())), Ok(9100));
+        assert_eq!(resolve(IntOrString::Int(5432)), Ok(5432));
+        assert!(resolve(IntOrString::String("http".into())).is_err());
+        assert!(resolve(IntOrString::Int(70000)).is_err());
+    }
+
+    // ── PortForwardOptions tests ──
+
+    fn options_from(json: serde_json::Value) -> PortForwardOptions {
+        let mut base = serde_json::json!({
+            "namespace": "default",
+            "name": "api",
+            "target_type": "service",
+        });
+        base.as_object_mut()
+            .unwrap()
+            .extend(json.as_object().unwrap().clone());
+        serde_json::from_value(base).unwrap()
+    }
+
+    #[test]
+    fn single_target_port_is_shorthand_for_one_port_spec() {
+        let options = options_from(serde_json::json!({ "target_port": 80, "local_port": 8080 }));
+        assert_eq!(
+            options.port_specs().unwrap(),
+            vec![PortForwardPortSpec {
+                port: IntOrString::Int(80),
+                local_port: Some(8080),
+            }]
+        );
+        assert_eq!(options.bind_ip().unwrap(), IpAddr::V4(Ipv4Addr::LOCALHOST));
+    }
+
+    #[test]
+    fn multi_port_options_accept_names_and_a_bind_address() {
+        let options = options_from(serde_json::json!({
+            "ports": [{ "port": "http" }, { "port": 9090, "local_port": 19090 }],
+            "bind_address": "0.0.0.0",
+            "allow_external_bind": true,
+        }));
+        let specs = options.port_specs().unwrap();
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs[0].port, IntOrString::String("http".into()));
+        assert_eq!(specs[1].local_port, Some(19090));
+        assert_eq!(
+            options.bind_ip().unwrap(),
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+        );
+
+        let bad = options_from(serde_json::json!({ "target_port": 80, "bind_address": "lan" }));
+        assert!(bad.bind_ip().is_err());
+        assert!(options_from(serde_json::json!({})).port_specs().is_err());
+    }
+
+    #[test]
+    fn external_bind_addresses_need_an_explicit_opt_in() {
+        let exposed =
+            options_from(serde_json::json!({ "target_port": 80, "bind_address": "0.0.0.0" }));
+        assert!(exposed.bind_ip().is_err());
+        let lan =
+            options_from(serde_json::json!({ "target_port": 80, "bind_address": "192.168.1.5" }));
+        assert!(lan.bind_ip().is_err());
+
+        let loopback =
+            options_from(serde_json::json!({ "target_port": 80, "bind_address": "::1" }));
+        assert_eq!(
+            loopback.bind_ip().unwrap(),
+            IpAddr::V6(std::net::Ipv6Addr::LOCALHOST)
+        );
+    }
+
     // ── PortForwardManager tests ──
 
     #[tokio::test]
@@ -2009,8 +2318,9 @@ mod tests {
 
         let info = mgr.get_reconnect_info("fwd-1").await.unwrap();
         assert_eq!(info.0, "default"); // namespace
-        assert_eq!(info.1, 41587); // local_port
-        assert_eq!(info.2, 8080); // target_port
+        assert_eq!(info.1, IpAddr::V4(Ipv4Addr::LOCALHOST)); // bind address
+        assert_eq!(info.2[0].local_port, 41587);
+        assert_eq!(info.2[0].target_port, 8080);
 
         // Status should be accessible
         let status = info.3.read().await.clone();
@@ -2333,6 +2643,8 @@ mod tests {
             target_type: PortForwardTargetType::Service,
             target_port: 80,
             local_port: 41587,
+            ports: vec![],
+            bind_address: "127.0.0.1".to_string(),
             status: PortForwardStatus::Reconnecting,
             pod_name: Some("pod-abc".to_string()),
             pod_uid: Some("uid-123".to_string()),
@@ -2356,6 +2668,12 @@ mod tests {
             target_type: PortForwardTargetType::Service,
             target_port: 3000, // RESOLVED port, not service port 80
             local_port: 44789,
+            ports: vec![ForwardedPort {
+                requested: IntOrString::Int(80),
+                local_port: 44789,
+                target_port: 3000,
+            }],
+            bind_address: "127.0.0.1".to_string(),
             status: PortForwardStatus::Connected,
             pod_name: Some("demo-frontend-abc".to_string()),
             pod_uid: Some("uid-frontend-123".to_string()),
@@ -2384,8 +2702,8 @@ mod tests {
 
         let info = mgr.get_reconnect_info("fwd-1").await.unwrap();
         // Reconnection must use the resolved port 3000, not the original 80
-        assert_eq!(info.1, 44789); // local_port
-        assert_eq!(info.2, 3000); // target_port (resolved)
+        assert_eq!(info.2[0].local_port, 44789);
+        assert_eq!(info.2[0].target_port, 3000); // resolved
     }
 
     // ── Status transition tests ──
@@ -0,0 +1,274 @@
+use crate::commands::portforward::{
+    start_forward, PortForwardEvent, PortForwardManager, PortForwardOptions, PortForwardPortSpec,
+    PortForwardTargetType, PortForwardWatchManager,
+};
+use crate::k8s::AppState;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::{command, AppHandle, Emitter, Manager};
+use tauri_plugin_store::StoreExt;
+
+const PROFILES_STORE: &str = "portforward-profiles.json";
+
+/// Named port-forward profile persisted to portforward-profiles.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortForwardProfile {
+    pub name: String,
+    pub context: String,
+    pub namespace: String,
+    /// Pod or service name
+    pub target: String,
+    pub target_type: PortForwardTargetType,
+    /// Forwarded together as one multi-port forward
+    pub ports: Vec<PortForwardPortSpec>,
+    /// Local listen address; loopback when unset
+    #[serde(default)]
+    pub bind_address: Option<String>,
+    /// Allow a non-loopback `bind_address` (see `PortForwardOptions`)
+    #[serde(default)]
+    pub allow_external_bind: bool,
+    /// Start when `connect_cluster` succeeds for `context`
+    #[serde(default)]
+    pub auto_start: bool,
+    /// Profiles sharing a group are started together
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+impl PortForwardProfile {
+    /// Forward id of the profile. Stable, so starting a running profile
+    /// again is a no-op and its events always arrive on the same channel.
+    fn forward_id(&self) -> String {
+        format!("profile-{}", self.name)
+    }
+
+    fn options(&self) -> PortForwardOptions {
+        PortForwardOptions {
+            namespace: self.namespace.clone(),
+            name: self.target.clone(),
+            target_type: self.target_type.clone(),
+            target_port: None,
+            local_port: None,
+            ports: self.ports.clone(),
+            bind_address: self.bind_address.clone(),
+            allow_external_bind: self.allow_external_bind,
+            // Always through the profile's own cluster, never the active one
+            expected_context: Some(self.context.clone()),
+        }
+    }
+}
+
+fn load_profiles(app: &AppHandle) -> Result<Vec<PortForwardProfile>, String> {
+    let store = app
+        .store(PROFILES_STORE)
+        .map_err(|e| format!("Failed to open port forward profiles store: {}", e))?;
+    let mut profiles: Vec<PortForwardProfile> = store
+        .values()
+        .into_iter()
+        .filter_map(|value| match serde_json::from_value(value) {
+            Ok(profile) => Some(profile),
+            Err(e) => {
+                tracing::warn!("Skipping unreadable port forward profile: {}", e);
+                None
+            }
+        })
+        .collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(profiles)
+}
+
+/// Start the given profiles. Failures are reported on each profile's
+/// `portforward-{id}` channel instead of aborting the rest.
+async fn start_profiles(app: &AppHandle, profiles: Vec<PortForwardProfile>) -> usize {
+    let state = app.state::<AppState>();
+    let pf_manager = app.state::<Arc<PortForwardManager>>();
+    let pf_watch_manager = app.state::<Arc<PortForwardWatchManager>>();
+
+    let mut started = 0;
+    for profile in &profiles {
+        let forward_id = profile.forward_id();
+        if pf_manager.is_active(&forward_id).await {
+            continue;
+        }
+        match start_forward(
+            app,
+            &state,
+            &pf_manager,
+            &pf_watch_manager,
+            forward_id.clone(),
+            profile.options(),
+        )
+        .await
+        {
+            Ok(_) => started += 1,
+            Err(message) => {
+                tracing::warn!(
+                    "Port forward profile {} failed to start: {}",
+                    profile.name,
+                    message
+                );
+                let _ = app.emit(
+                    &format!("portforward-{}", forward_id),
+                    PortForwardEvent::Error {
+                        forward_id,
+                        message,
+                    },
+                );
+            }
+        }
+    }
+    started
+}
+
+/// Start the auto-start profiles of a context. Called once `connect_cluster`
+/// has a working client for it.
+pub(crate) async fn start_auto_profiles(app: AppHandle, context: String) {
+    let profiles = match load_profiles(&app) {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            tracing::warn!("{}", e);
+            return;
+        }
+    };
+    let profiles: Vec<_> = profiles
+        .into_iter()
+        .filter(|profile| profile.auto_start && profile.context == context)
+        .collect();
+    if profiles.is_empty() {
+        return;
+    }
+    let started = start_profiles(&app, profiles).await;
+    tracing::info!(
+        "Auto-started {} port forwards for context '{}'",
+        started,
+        context
+    );
+}
+
+/// List saved port forward profiles
+#[command]
+pub async fn portforward_profiles_list(app: AppHandle) -> Result<Vec<PortForwardProfile>, String> {
+    load_profiles(&app)
+}
+
+/// Create or replace a port forward profile (keyed by name)
+#[command]
+pub async fn portforward_profile_save(
+    app: AppHandle,
+    profile: PortForwardProfile,
+) -> Result<(), String> {
+    if profile.name.trim().is_empty() {
+        return Err("Profile name must not be empty".to_string());
+    }
+    if profile.ports.is_empty() {
+        return Err("Profile needs at least one port".to_string());
+    }
+
+    let store = app
+        .store(PROFILES_STORE)
+        .map_err(|e| format!("Failed to open port forward profiles store: {}", e))?;
+    store.set(
+        profile.name.clone(),
+        serde_json::to_value(&profile)
+            .map_err(|e| format!("Failed to serialize port forward profile: {}", e))?,
+    );
+    store
+        .save()
+        .map_err(|e| format!("Failed to save port forward profiles: {}", e))?;
+
+    tracing::info!("Saved port forward profile '{}'", profile.name);
+    Ok(())
+}
+
+/// Delete a port forward profile. Forwards it started keep running.
+#[command]
+pub async fn portforward_profile_delete(app: AppHandle, name: String) -> Result<(), String> {
+    let store = app
+        .store(PROFILES_STORE)
+        .map_err(|e| format!("Failed to open port forward profiles store: {}", e))?;
+    store.delete(&name);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save port forward profiles: {}", e))?;
+
+    tracing::info!("Deleted port forward profile '{}'", name);
+    Ok(())
+}
+
+/// Start one profile. Returns how many forwards were started (0 when it
+/// was already running).
+#[command]
+pub async fn portforward_profile_start(app: AppHandle, name: String) -> Result<usize, String> {
+    let profile = load_profiles(&app)?
+        .into_iter()
+        .find(|profile| profile.name == name)
+        .ok_or_else(|| format!("Port forward profile '{}' not found", name))?;
+    Ok(start_profiles(&app, vec![profile]).await)
+}
+
+/// Start every profile of a group. Returns how many forwards were started.
+#[command]
+pub async fn portforward_profile_start_group(
+    app: AppHandle,
+    group: String,
+) -> Result<usize, String> {
+    let profiles: Vec<_> = load_profiles(&app)?
+        .into_iter()
+        .filter(|profile| profile.group.as_deref() == Some(group.as_str()))
+        .collect();
+    if profiles.is_empty() {
+        return Err(format!("No port forward profiles in group '{}'", group));
+    }
+    Ok(start_profiles(&app, profiles).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    #[test]
+    fn profile_defaults_to_manual_start_without_group() {
+        let profile: PortForwardProfile = serde_json::from_str(
+            r#"{"name":"db","context":"prod","namespace":"data","target":"postgres",
+                "target_type":"service","ports":[{"port":5432}]}"#,
+        )
+        .unwrap();
+        assert!(!profile.auto_start);
+        assert!(profile.group.is_none());
+        assert!(profile.bind_address.is_none());
+        assert_eq!(profile.ports[0].port, IntOrString::Int(5432));
+        assert_eq!(profile.ports[0].local_port, None);
+    }
+
+    #[test]
+    fn profile_becomes_one_forward_on_its_own_cluster() {
+        let profile = PortForwardProfile {
+            name: "api".into(),
+            context: "prod".into(),
+            namespace: "default".into(),
+            target: "api".into(),
+            target_type: PortForwardTargetType::Service,
+            ports: vec![
+                PortForwardPortSpec {
+                    port: IntOrString::String("http".into()),
+                    local_port: Some(18080),
+                },
+                PortForwardPortSpec {
+                    port: IntOrString::String("grpc".into()),
+                    local_port: None,
+                },
+            ],
+            bind_address: None,
+            allow_external_bind: false,
+            auto_start: true,
+            group: Some("morning".into()),
+        };
+        assert_eq!(profile.forward_id(), "profile-api");
+
+        let options = profile.options();
+        assert_eq!(options.expected_context.as_deref(), Some("prod"));
+        assert_eq!(options.ports, profile.ports);
+        assert!(options.target_port.is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::commands::apply::{server_side_apply, ConflictResolution};
 use crate::error::KubeliError;
 use crate::k8s::AppState;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
@@ -46,7 +47,7 @@ fn btree_to_hashmap(
 /// Converts a Kubernetes LabelSelector into the query syntax accepted by
 /// ListParams and watcher Config, preserving both matchLabels and
 /// matchExpressions.
-fn label_selector_to_query(selector: &LabelSelector) -> String {
+pub(crate) fn label_selector_to_query(selector: &LabelSelector) -> String {
     let mut parts = Vec::new();
 
     if let Some(labels) = &selector.match_labels {
@@ -401,13 +402,46 @@ pub struct ListOptions {
     pub limit: Option<u32>,
 }
 
+impl ListOptions {
+    /// Whether the list asks for a whole namespace (or the whole cluster),
+    /// which a shared informer can answer. Selectors and limits still go to
+    /// the API server.
+    fn is_plain(&self) -> bool {
+        let unset = |selector: &Option<String>| selector.as_deref().unwrap_or_default().is_empty();
+        unset(&self.label_selector) && unset(&self.field_selector) && self.limit.is_none()
+    }
+}
+
+/// Objects from the shared informer cache when a synced informer covers the
+/// list. None means the caller must LIST against the API server.
+async fn list_from_cache<K>(
+    state: &AppState,
+    options: &ListOptions,
+    context: Option<&str>,
+) -> Option<Vec<K>>
+where
+    K: kube::Resource<DynamicType = ()> + Clone + Send + Sync + 'static,
+{
+    if !options.is_plain() {
+        return None;
+    }
+    let context = match context {
+        Some(context) => context.to_string(),
+        None => state.k8s.get_current_context().await?,
+    };
+    state
+        .cache
+        .cached_list(&context, options.namespace.as_deref())
+}
+
 /// List all pods in a namespace or all namespaces
 #[command]
 pub async fn list_pods(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<PodInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -426,10 +460,12 @@ pub async fn list_pods(
         Api::all(client)
     };
 
-    let pod_list = pods.list(&list_params).await?;
+    let items = match list_from_cache(&state, &options, context.as_deref()).await {
+        Some(items) => items,
+        None => pods.list(&list_params).await?.items,
+    };
 
-    let pod_infos: Vec<PodInfo> = pod_list
-        .items
+    let pod_infos: Vec<PodInfo> = items
         .into_iter()
         .map(|pod| {
             let metadata = pod.metadata;
@@ -519,8 +555,9 @@ pub fn deployment_to_info(deployment: Deployment) -> DeploymentInfo {
 pub async fn list_deployments(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<DeploymentInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -536,13 +573,12 @@ pub async fn list_deployments(
         Api::all(client)
     };
 
-    let deployment_list = deployments.list(&list_params).await?;
+    let items = match list_from_cache(&state, &options, context.as_deref()).await {
+        Some(items) => items,
+        None => deployments.list(&list_params).await?.items,
+    };
 
-    let deployment_infos: Vec<DeploymentInfo> = deployment_list
-        .items
-        .into_iter()
-        .map(deployment_to_info)
-        .collect();
+    let deployment_infos: Vec<DeploymentInfo> = items.into_iter().map(deployment_to_info).collect();
 
     tracing::info!("Listed {} deployments", deployment_infos.len());
     Ok(deployment_infos)
@@ -599,8 +635,9 @@ pub fn service_to_info(service: Service) -> ServiceInfo {
 pub async fn list_services(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<ServiceInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -616,13 +653,12 @@ pub async fn list_services(
         Api::all(client)
     };
 
-    let service_list = services.list(&list_params).await?;
+    let items = match list_from_cache(&state, &options, context.as_deref()).await {
+        Some(items) => items,
+        None => services.list(&list_params).await?.items,
+    };
 
-    let service_infos: Vec<ServiceInfo> = service_list
-        .items
-        .into_iter()
-        .map(service_to_info)
-        .collect();
+    let service_infos: Vec<ServiceInfo> = items.into_iter().map(service_to_info).collect();
 
     tracing::info!("Listed {} services", service_infos.len());
     Ok(service_infos)
@@ -633,8 +669,9 @@ pub async fn list_services(
 pub async fn list_configmaps(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<ConfigMapInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -682,8 +719,9 @@ pub async fn list_configmaps(
 pub async fn list_secrets(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<SecretInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -729,8 +767,11 @@ pub async fn list_secrets(
 
 /// List all nodes
 #[command]
-pub async fn list_nodes(state: State<'_, AppState>) -> Result<Vec<NodeInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+pub async fn list_nodes(
+    state: State<'_, AppState>,
+    context: Option<String>,
+) -> Result<Vec<NodeInfo>, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let nodes: Api<Node> = Api::all(client);
     let list_params = ListParams::default();
@@ -915,8 +956,9 @@ pub async fn get_pod(
     state: State<'_, AppState>,
     namespace: String,
     name: String,
+    context: Option<String>,
 ) -> Result<PodInfo, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
     let pod = pods.get(&name).await?;
@@ -999,8 +1041,9 @@ pub async fn reveal_env_var(
     namespace: String,
     secret_name: String,
     key: String,
+    context: Option<String>,
 ) -> Result<String, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let secrets: Api<Secret> = Api::namespaced(client, &namespace);
     let secret = secrets.get(&secret_name).await?;
@@ -1018,8 +1061,9 @@ pub async fn delete_pod(
     state: State<'_, AppState>,
     namespace: String,
     name: String,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let pods: Api<Pod> = Api::namespaced(client, &namespace);
     pods.delete(&name, &Default::default()).await?;
@@ -1030,7 +1074,7 @@ pub async fn delete_pod(
 
 /// Strip metadata.managedFields from a resource JSON value.
 /// managedFields is verbose internal bookkeeping that clutters the YAML view.
-fn strip_managed_fields(value: &mut Value) {
+pub(crate) fn strip_managed_fields(value: &mut Value) {
     if let Some(metadata) = value.get_mut("metadata").and_then(|m| m.as_object_mut()) {
         metadata.remove("managedFields");
     }
@@ -1124,6 +1168,55 @@ fn parse_custom_resource_type(resource_type: &str) -> Option<DynamicResourceDesc
     })
 }
 
+/// Summarize a dynamic object the way `list_custom_resources` shows it
+pub(crate) fn dynamic_to_info(
+    resource: &DynamicObject,
+    kind: &str,
+    api_version: &str,
+) -> CustomResourceInfo {
+    CustomResourceInfo {
+        name: resource.metadata.name.clone().unwrap_or_default(),
+        uid: resource.metadata.uid.clone().unwrap_or_default(),
+        namespace: resource.metadata.namespace.clone(),
+        kind: kind.to_string(),
+        api_version: api_version.to_string(),
+        status: summarize_dynamic_status(resource),
+        created_at: resource
+            .metadata
+            .creation_timestamp
+            .as_ref()
+            .map(|timestamp| timestamp.0.to_string()),
+        labels: btree_to_hashmap(resource.metadata.labels.clone()),
+    }
+}
+
+/// Resolve a group/version/kind to its ApiResource via API discovery, which
+/// also tells whether the kind is namespaced. Guessing the plural breaks
+/// kinds like StorageClass ("storageclasss"), so the heuristic is only the
+/// offline fallback, with the scope left unknown.
+pub(crate) async fn discover_api_resource(
+    client: &kube::Client,
+    group: &str,
+    version: &str,
+    kind: &str,
+) -> (ApiResource, Option<bool>) {
+    let gvk = kube::core::GroupVersionKind::gvk(group, version, kind);
+    match kube::discovery::oneshot::pinned_kind(client, &gvk).await {
+        Ok((ar, caps)) => (ar, Some(caps.scope == kube::discovery::Scope::Namespaced)),
+        Err(e) => {
+            tracing::warn!("Discovery failed for {}/{} {}: {}", group, version, kind, e);
+            let ar = ApiResource {
+                group: group.to_string(),
+                version: version.to_string(),
+                kind: kind.to_string(),
+                api_version: build_api_version(group, version),
+                plural: get_plural(kind),
+            };
+            (ar, None)
+        }
+    }
+}
+
 fn summarize_dynamic_status(obj: &DynamicObject) -> Option<String> {
     let status = obj.data.get("status")?;
 
@@ -1191,8 +1284,9 @@ pub async fn get_resource_yaml(
     resource_type: String,
     name: String,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<ResourceYaml, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     // Only helm-release needs special handling (secret decoding).
     // Everything else goes through the generic dynamic API.
@@ -1454,6 +1548,13 @@ fn resolve_dynamic_resource_type(resource_type: &str) -> Option<DynamicResourceD
     })
 }
 
+/// API resource and scope of a resource type string as `delete_resource`
+/// takes it ("pods", "deployment", custom resource types...)
+pub(crate) fn resolve_api_resource(resource_type: &str) -> Option<(ApiResource, bool)> {
+    resolve_dynamic_resource_type(resource_type)
+        .map(|descriptor| (build_api_resource(&descriptor), descriptor.namespaced))
+}
+
 /// Fetch any resource type dynamically using kube discovery
 async fn get_resource_yaml_dynamic(
     client: kube::Client,
@@ -1497,13 +1598,16 @@ async fn get_resource_yaml_dynamic(
     })
 }
 
-/// Apply/update a resource from YAML
+/// Apply/update a resource from YAML. Fields owned by other managers are
+/// reported as a conflict unless `conflict_resolution` forces or drops them.
 #[command]
 pub async fn apply_resource_yaml(
     state: State<'_, AppState>,
     yaml_content: String,
+    conflict_resolution: Option<ConflictResolution>,
+    context: Option<String>,
 ) -> Result<String, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     // Parse YAML to get resource metadata
     let value: Value = serde_yaml::from_str(&yaml_content)?;
@@ -1523,22 +1627,7 @@ pub async fn apply_resource_yaml(
         ("", api_version)
     };
 
-    // Resolve the plural via API discovery - guessing it breaks kinds like
-    // StorageClass ("storageclasss"). Fall back to the heuristic offline.
-    let gvk = kube::core::GroupVersionKind::gvk(group, version, kind);
-    let ar = match kube::discovery::oneshot::pinned_kind(&client, &gvk).await {
-        Ok((ar, _caps)) => ar,
-        Err(e) => {
-            tracing::warn!("Discovery failed for {}/{} {}: {}", group, version, kind, e);
-            ApiResource {
-                group: group.to_string(),
-                version: version.to_string(),
-                kind: kind.to_string(),
-                api_version: api_version.to_string(),
-                plural: get_plural(kind),
-            }
-        }
-    };
+    let (ar, _namespaced) = discover_api_resource(&client, group, version, kind).await;
 
     // Create dynamic API
     let api: Api<DynamicObject> = if let Some(ns) = namespace {
@@ -1548,11 +1637,9 @@ pub async fn apply_resource_yaml(
     };
 
     // Apply using server-side apply
-    let patch_params = PatchParams::apply("kubeli").force();
     let json_str = serde_json::to_string(&value)?;
-    let patch = Patch::Apply(serde_json::from_str::<DynamicObject>(&json_str)?);
-
-    api.patch(name, &patch_params, &patch).await?;
+    let object = serde_json::from_str::<DynamicObject>(&json_str)?;
+    server_side_apply(&api, name, &object, conflict_resolution.unwrap_or_default()).await?;
 
     tracing::info!("Applied {} {}", kind, name);
     Ok(format!("{} {} applied successfully", kind, name))
@@ -1565,8 +1652,9 @@ pub async fn delete_resource(
     resource_type: String,
     name: String,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let descriptor = resolve_dynamic_resource_type(&resource_type)
         .ok_or_else(|| format!("Unsupported resource type: {}", resource_type))?;
@@ -1589,8 +1677,9 @@ pub async fn delete_resource(
 pub async fn list_custom_resources(
     state: State<'_, AppState>,
     query: CustomResourceQuery,
+    context: Option<String>,
 ) -> Result<Vec<CustomResourceInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let descriptor = DynamicResourceDescriptor {
         group: query.group.clone(),
@@ -1615,21 +1704,8 @@ pub async fn list_custom_resources(
     let list = api.list(&ListParams::default()).await?;
     let infos: Vec<CustomResourceInfo> = list
         .items
-        .into_iter()
-        .map(|resource| CustomResourceInfo {
-            name: resource.metadata.name.clone().unwrap_or_default(),
-            uid: resource.metadata.uid.clone().unwrap_or_default(),
-            namespace: resource.metadata.namespace.clone(),
-            kind: descriptor.kind.clone(),
-            api_version: api_version.clone(),
-            status: summarize_dynamic_status(&resource),
-            created_at: resource
-                .metadata
-                .creation_timestamp
-                .as_ref()
-                .map(|timestamp| timestamp.0.to_string()),
-            labels: btree_to_hashmap(resource.metadata.labels.clone()),
-        })
+        .iter()
+        .map(|resource| dynamic_to_info(resource, &descriptor.kind, &api_version))
         .collect();
 
     tracing::info!(
@@ -1649,8 +1725,9 @@ pub async fn scale_deployment(
     name: String,
     namespace: String,
     replicas: i32,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<Deployment> = Api::namespaced(client, &namespace);
 
@@ -1680,7 +1757,7 @@ pub enum ImagePatchTarget {
 /// with the single entry below. A strategic merge patch merges by the
 /// container's `name` instead, leaving sibling containers and every other
 /// field on the patched one intact.
-fn container_image_patch(
+pub(crate) fn container_image_patch(
     container_name: &str,
     image: &str,
     init_container: bool,
@@ -1706,6 +1783,7 @@ fn container_image_patch(
 
 /// Sets the image of a single container in a workload's pod template.
 #[command]
+#[allow(clippy::too_many_arguments)]
 pub async fn set_container_image(
     state: State<'_, AppState>,
     resource_type: ImagePatchTarget,
@@ -1714,13 +1792,14 @@ pub async fn set_container_image(
     container_name: String,
     image: String,
     init_container: bool,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
     let image = image.trim();
     if image.is_empty() {
         return Err(KubeliError::unknown("Image must not be empty"));
     }
 
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
     let patch = container_image_patch(&container_name, image, init_container);
 
     let params = PatchParams::default();
@@ -1755,8 +1834,9 @@ async fn set_cronjob_suspend(
     name: &str,
     namespace: &str,
     suspend: bool,
+    context: Option<&str>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context).await?;
 
     let api: Api<CronJob> = Api::namespaced(client, namespace);
     let patch = serde_json::json!({
@@ -1775,8 +1855,9 @@ pub async fn suspend_cronjob(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    set_cronjob_suspend(state, &name, &namespace, true).await
+    set_cronjob_suspend(state, &name, &namespace, true, context.as_deref()).await
 }
 
 #[command]
@@ -1784,8 +1865,9 @@ pub async fn resume_cronjob(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    set_cronjob_suspend(state, &name, &namespace, false).await
+    set_cronjob_suspend(state, &name, &namespace, false, context.as_deref()).await
 }
 
 /// Build the manual Job a CronJob trigger creates, like
@@ -1843,8 +1925,9 @@ pub async fn get_cronjob_job_yaml(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<String, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let cronjobs: Api<CronJob> = Api::namespaced(client, &namespace);
     let cronjob = cronjobs.get(&name).await?;
@@ -1862,8 +1945,9 @@ pub async fn trigger_cronjob(
     state: State<'_, AppState>,
     name: String,
     namespace: String,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let cronjobs: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
     let cronjob = cronjobs.get(&name).await?;
@@ -1897,14 +1981,17 @@ pub struct NamespaceInfo {
 #[command]
 pub async fn list_namespaces(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<NamespaceInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let namespaces: Api<Namespace> = Api::all(client);
-    let list = namespaces.list(&ListParams::default()).await?;
+    let items = match list_from_cache(&state, &ListOptions::default(), context.as_deref()).await {
+        Some(items) => items,
+        None => namespaces.list(&ListParams::default()).await?.items,
+    };
 
-    let result: Vec<NamespaceInfo> = list
-        .items
+    let result: Vec<NamespaceInfo> = items
         .into_iter()
         .map(|ns| {
             let status = ns
@@ -2011,8 +2098,9 @@ pub struct EventInfo {
 pub async fn list_events(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<EventInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2087,8 +2175,9 @@ pub struct LeaseInfo {
 pub async fn list_leases(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<LeaseInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2186,8 +2275,9 @@ pub fn replicaset_to_info(rs: ReplicaSet) -> ReplicaSetInfo {
 pub async fn list_replicasets(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<ReplicaSetInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2203,9 +2293,12 @@ pub async fn list_replicasets(
         Api::all(client)
     };
 
-    let list = api.list(&list_params).await?;
+    let items = match list_from_cache(&state, &options, context.as_deref()).await {
+        Some(items) => items,
+        None => api.list(&list_params).await?.items,
+    };
 
-    let infos: Vec<ReplicaSetInfo> = list.items.into_iter().map(replicaset_to_info).collect();
+    let infos: Vec<ReplicaSetInfo> = items.into_iter().map(replicaset_to_info).collect();
 
     tracing::info!("Listed {} replicasets", infos.len());
     Ok(infos)
@@ -2271,8 +2364,9 @@ pub fn daemonset_to_info(ds: DaemonSet) -> DaemonSetInfo {
 pub async fn list_daemonsets(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<DaemonSetInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2288,9 +2382,12 @@ pub async fn list_daemonsets(
         Api::all(client)
     };
 
-    let list = api.list(&list_params).await?;
+    let items = match list_from_cache(&state, &options, context.as_deref()).await {
+        Some(items) => items,
+        None => api.list(&list_params).await?.items,
+    };
 
-    let infos: Vec<DaemonSetInfo> = list.items.into_iter().map(daemonset_to_info).collect();
+    let infos: Vec<DaemonSetInfo> = items.into_iter().map(daemonset_to_info).collect();
 
     tracing::info!("Listed {} daemonsets", infos.len());
     Ok(infos)
@@ -2346,8 +2443,9 @@ pub fn statefulset_to_info(sts: StatefulSet) -> StatefulSetInfo {
 pub async fn list_statefulsets(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<StatefulSetInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2363,9 +2461,12 @@ pub async fn list_statefulsets(
         Api::all(client)
     };
 
-    let list = api.list(&list_params).await?;
+    let items = match list_from_cache(&state, &options, context.as_deref()).await {
+        Some(items) => items,
+        None => api.list(&list_params).await?.items,
+    };
 
-    let infos: Vec<StatefulSetInfo> = list.items.into_iter().map(statefulset_to_info).collect();
+    let infos: Vec<StatefulSetInfo> = items.into_iter().map(statefulset_to_info).collect();
 
     tracing::info!("Listed {} statefulsets", infos.len());
     Ok(infos)
@@ -2403,8 +2504,9 @@ pub struct JobInfo {
 pub async fn list_jobs(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<JobInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2512,8 +2614,9 @@ pub struct CronJobInfo {
 pub async fn list_cronjobs(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<CronJobInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2615,8 +2718,9 @@ pub struct IngressInfo {
 pub async fn list_ingresses(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<IngressInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2791,8 +2895,9 @@ pub struct EndpointSliceInfo {
 pub async fn list_endpoint_slices(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<EndpointSliceInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -2949,8 +3054,9 @@ fn convert_network_policy_peer(
 pub async fn list_network_policies(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<NetworkPolicyInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -3061,8 +3167,9 @@ pub struct IngressClassInfo {
 pub async fn list_ingress_classes(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<IngressClassInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -3223,8 +3330,9 @@ fn hpa_metric_status(metric: MetricStatus) -> HPAMetricStatus {
 pub async fn list_hpas(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<HPAInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -3332,8 +3440,9 @@ fn quantity_map_to_string_map(
 pub async fn list_limit_ranges(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<LimitRangeInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -3406,8 +3515,9 @@ pub struct ResourceQuotaInfo {
 pub async fn list_resource_quotas(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<ResourceQuotaInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -3473,8 +3583,9 @@ pub struct PDBInfo {
 pub async fn list_pdbs(
     state: State<'_, AppState>,
     options: ListOptions,
+    context: Option<String>,
 ) -> Result<Vec<PDBInfo>, KubeliError> {
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let mut list_params = ListParams::default();
     if let Some(label_selector) = &options.label_selector {
@@ -3571,9 +3682,10 @@ pub struct PVInfo {
 #[command]
 pub async fn list_persistent_volumes(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<PVInfo>, KubeliError> {
     tracing::info!("Listing persistent volumes");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<PersistentVolume> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -3639,12 +3751,13 @@ pub struct PVCInfo {
 pub async fn list_persistent_volume_claims(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<PVCInfo>, KubeliError> {
     tracing::info!(
         "Listing persistent volume claims in namespace: {:?}",
         namespace
     );
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<PersistentVolumeClaim> = match &namespace {
         Some(ns) if !ns.is_empty() => Api::namespaced(client.clone(), ns),
@@ -3714,9 +3827,10 @@ pub struct StorageClassInfo {
 #[command]
 pub async fn list_storage_classes(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<StorageClassInfo>, KubeliError> {
     tracing::info!("Listing storage classes");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<StorageClass> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -3779,9 +3893,10 @@ pub struct CSIDriverInfo {
 #[command]
 pub async fn list_csi_drivers(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<CSIDriverInfo>, KubeliError> {
     tracing::info!("Listing CSI drivers");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<CSIDriver> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -3840,9 +3955,12 @@ pub struct CSINodeInfo {
 
 /// List CSI Nodes
 #[command]
-pub async fn list_csi_nodes(state: State<'_, AppState>) -> Result<Vec<CSINodeInfo>, KubeliError> {
+pub async fn list_csi_nodes(
+    state: State<'_, AppState>,
+    context: Option<String>,
+) -> Result<Vec<CSINodeInfo>, KubeliError> {
     tracing::info!("Listing CSI nodes");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<CSINode> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -3899,9 +4017,10 @@ pub struct VolumeAttachmentInfo {
 #[command]
 pub async fn list_volume_attachments(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<VolumeAttachmentInfo>, KubeliError> {
     tracing::info!("Listing volume attachments");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<VolumeAttachment> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -3974,9 +4093,10 @@ pub struct ServiceAccountInfo {
 pub async fn list_service_accounts(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<ServiceAccountInfo>, KubeliError> {
     tracing::info!("Listing service accounts in namespace: {:?}", namespace);
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<ServiceAccount> = match &namespace {
         Some(ns) if !ns.is_empty() => Api::namespaced(client.clone(), ns),
@@ -4049,9 +4169,10 @@ pub struct RoleInfo {
 pub async fn list_roles(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<RoleInfo>, KubeliError> {
     tracing::info!("Listing roles in namespace: {:?}", namespace);
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<Role> = match &namespace {
         Some(ns) if !ns.is_empty() => Api::namespaced(client.clone(), ns),
@@ -4125,9 +4246,10 @@ pub struct RoleBindingInfo {
 pub async fn list_role_bindings(
     state: State<'_, AppState>,
     namespace: Option<String>,
+    context: Option<String>,
 ) -> Result<Vec<RoleBindingInfo>, KubeliError> {
     tracing::info!("Listing role bindings in namespace: {:?}", namespace);
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<RoleBinding> = match &namespace {
         Some(ns) if !ns.is_empty() => Api::namespaced(client.clone(), ns),
@@ -4191,9 +4313,10 @@ pub struct ClusterRoleInfo {
 #[command]
 pub async fn list_cluster_roles(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<ClusterRoleInfo>, KubeliError> {
     tracing::info!("Listing cluster roles");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<ClusterRole> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -4267,9 +4390,10 @@ pub struct ClusterRoleBindingInfo {
 #[command]
 pub async fn list_cluster_role_bindings(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<ClusterRoleBindingInfo>, KubeliError> {
     tracing::info!("Listing cluster role bindings");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<ClusterRoleBinding> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -4341,9 +4465,12 @@ pub struct CRDInfo {
 }
 
 #[command]
-pub async fn list_crds(state: State<'_, AppState>) -> Result<Vec<CRDInfo>, KubeliError> {
+pub async fn list_crds(
+    state: State<'_, AppState>,
+    context: Option<String>,
+) -> Result<Vec<CRDInfo>, KubeliError> {
     tracing::info!("Listing CRDs");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<CustomResourceDefinition> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -4419,9 +4546,10 @@ pub struct PriorityClassInfo {
 #[command]
 pub async fn list_priority_classes(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<PriorityClassInfo>, KubeliError> {
     tracing::info!("Listing priority classes");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<PriorityClass> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -4467,9 +4595,10 @@ pub struct RuntimeClassInfo {
 #[command]
 pub async fn list_runtime_classes(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<RuntimeClassInfo>, KubeliError> {
     tracing::info!("Listing runtime classes");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<RuntimeClass> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -4551,9 +4680,10 @@ pub struct MutatingWebhookInfo {
 #[command]
 pub async fn list_mutating_webhooks(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<MutatingWebhookInfo>, KubeliError> {
     tracing::info!("Listing mutating webhook configurations");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<MutatingWebhookConfiguration> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
@@ -4644,9 +4774,10 @@ pub struct ValidatingWebhookInfo {
 #[command]
 pub async fn list_validating_webhooks(
     state: State<'_, AppState>,
+    context: Option<String>,
 ) -> Result<Vec<ValidatingWebhookInfo>, KubeliError> {
     tracing::info!("Listing validating webhook configurations");
-    let client = state.k8s.get_client().await?;
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
 
     let api: Api<ValidatingWebhookConfiguration> = Api::all(client.clone());
     let list = api.list(&ListParams::default()).await?;
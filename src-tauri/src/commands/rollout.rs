@@ -0,0 +1,974 @@
+use crate::commands::apply::{diff_json, FieldChange};
+use crate::commands::watch::WatchManager;
+use crate::error::KubeliError;
+use crate::k8s::AppState;
+use futures::StreamExt;
+use k8s_openapi::api::apps::v1::{
+    ControllerRevision, DaemonSet, Deployment, ReplicaSet, StatefulSet,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta};
+use kube::api::{Api, ListParams, Patch, PatchParams};
+use kube::runtime::watcher::{watcher, Config, Event};
+use kube::runtime::WatchStreamExt;
+use kube::{Resource, ResourceExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tauri::{command, AppHandle, Emitter, State};
+use tokio_util::sync::CancellationToken;
+
+/// Pod template annotation `kubectl rollout restart` sets
+const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+/// Revision of a Deployment's ReplicaSet
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+
+/// Workload kinds with rollouts
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RolloutTarget {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+}
+
+impl RolloutTarget {
+    fn kind(self) -> &'static str {
+        match self {
+            RolloutTarget::Deployment => "Deployment",
+            RolloutTarget::StatefulSet => "StatefulSet",
+            RolloutTarget::DaemonSet => "DaemonSet",
+        }
+    }
+}
+
+/// One revision in a workload's rollout history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolloutRevision {
+    pub revision: i64,
+    /// ReplicaSet (Deployment) or ControllerRevision holding the revision
+    pub name: String,
+    pub created_at: Option<String>,
+    pub change_cause: Option<String>,
+    pub images: Vec<String>,
+    /// The revision the workload runs now
+    pub current: bool,
+    /// Pod template of the revision as YAML
+    pub template: String,
+    /// Pod template changes from the previous revision
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RolloutPhase {
+    Progressing,
+    Complete,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RolloutStatus {
+    pub phase: RolloutPhase,
+    pub message: String,
+}
+
+impl RolloutStatus {
+    fn progressing(message: impl Into<String>) -> Self {
+        Self {
+            phase: RolloutPhase::Progressing,
+            message: message.into(),
+        }
+    }
+
+    fn complete(message: impl Into<String>) -> Self {
+        Self {
+            phase: RolloutPhase::Complete,
+            message: message.into(),
+        }
+    }
+
+    fn failed(message: impl Into<String>) -> Self {
+        Self {
+            phase: RolloutPhase::Failed,
+            message: message.into(),
+        }
+    }
+}
+
+/// Rollout status events, emitted on `rollout-status-{watch_id}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum RolloutStatusEvent {
+    Status(RolloutStatus),
+    Error(KubeliError),
+}
+
+/// Rollout state of a Deployment, following `kubectl rollout status`
+pub(crate) fn deployment_rollout_status(deployment: &Deployment) -> RolloutStatus {
+    let status = deployment.status.clone().unwrap_or_default();
+    if deployment.metadata.generation > status.observed_generation {
+        return RolloutStatus::progressing("Waiting for deployment spec update to be observed");
+    }
+    let deadline_exceeded = status.conditions.iter().flatten().any(|condition| {
+        condition.type_ == "Progressing"
+            && condition.reason.as_deref() == Some("ProgressDeadlineExceeded")
+    });
+    if deadline_exceeded {
+        return RolloutStatus::failed(format!(
+            "Deployment {} exceeded its progress deadline",
+            deployment.name_any()
+        ));
+    }
+
+    let desired = deployment
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.replicas)
+        .unwrap_or(1);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let replicas = status.replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    if updated < desired {
+        RolloutStatus::progressing(format!(
+            "Waiting for rollout to finish: {} of {} new replicas have been updated",
+            updated, desired
+        ))
+    } else if replicas > updated {
+        RolloutStatus::progressing(format!(
+            "Waiting for rollout to finish: {} old replicas are pending termination",
+            replicas - updated
+        ))
+    } else if available < updated {
+        RolloutStatus::progressing(format!(
+            "Waiting for rollout to finish: {} of {} updated replicas are available",
+            available, updated
+        ))
+    } else {
+        RolloutStatus::complete(format!(
+            "Deployment {} successfully rolled out",
+            deployment.name_any()
+        ))
+    }
+}
+
+/// Rollout state of a StatefulSet, following `kubectl rollout status`
+pub(crate) fn statefulset_rollout_status(statefulset: &StatefulSet) -> RolloutStatus {
+    let status = statefulset.status.clone().unwrap_or_default();
+    if status.observed_generation.unwrap_or(0) == 0
+        || statefulset.metadata.generation > status.observed_generation
+    {
+        return RolloutStatus::progressing("Waiting for statefulset spec update to be observed");
+    }
+    let spec = statefulset.spec.clone().unwrap_or_default();
+    let strategy = spec.update_strategy.unwrap_or_default();
+    if strategy.type_.as_deref() == Some("OnDelete") {
+        return RolloutStatus::complete("OnDelete update strategy: pods update when deleted");
+    }
+
+    let replicas = spec.replicas.unwrap_or(1);
+    let ready = status.ready_replicas.unwrap_or(0);
+    let updated = status.updated_replicas.unwrap_or(0);
+    if ready < replicas {
+        return RolloutStatus::progressing(format!(
+            "Waiting for {} pods to be ready",
+            replicas - ready
+        ));
+    }
+
+    let partition = strategy
+        .rolling_update
+        .and_then(|rolling| rolling.partition)
+        .unwrap_or(0);
+    if partition > 0 {
+        return if updated < replicas - partition {
+            RolloutStatus::progressing(format!(
+                "Waiting for partitioned roll out to finish: {} of {} new pods have been updated",
+                updated,
+                replicas - partition
+            ))
+        } else {
+            RolloutStatus::complete(format!(
+                "Partitioned roll out complete: {} new pods have been updated",
+                updated
+            ))
+        };
+    }
+
+    let revision = status.update_revision.unwrap_or_default();
+    if status.current_revision.as_deref() != Some(revision.as_str()) {
+        RolloutStatus::progressing(format!(
+            "Waiting for rolling update to complete: {} of {} pods at revision {}",
+            updated, replicas, revision
+        ))
+    } else {
+        RolloutStatus::complete(format!(
+            "Rolling update complete: {} pods at revision {}",
+            status.current_replicas.unwrap_or(replicas),
+            revision
+        ))
+    }
+}
+
+/// Rollout state of a DaemonSet, following `kubectl rollout status`
+pub(crate) fn daemonset_rollout_status(daemonset: &DaemonSet) -> RolloutStatus {
+    let strategy = daemonset
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.update_strategy.as_ref())
+        .and_then(|strategy| strategy.type_.as_deref());
+    if strategy == Some("OnDelete") {
+        return RolloutStatus::complete("OnDelete update strategy: pods update when deleted");
+    }
+    let status = daemonset.status.clone().unwrap_or_default();
+    if daemonset.metadata.generation > status.observed_generation {
+        return RolloutStatus::progressing("Waiting for daemon set spec update to be observed");
+    }
+
+    let desired = status.desired_number_scheduled;
+    let updated = status.updated_number_scheduled.unwrap_or(0);
+    let available = status.number_available.unwrap_or(0);
+    if updated < desired {
+        RolloutStatus::progressing(format!(
+            "Waiting for rollout to finish: {} of {} new pods have been updated",
+            updated, desired
+        ))
+    } else if available < desired {
+        RolloutStatus::progressing(format!(
+            "Waiting for rollout to finish: {} of {} updated pods are available",
+            available, desired
+        ))
+    } else {
+        RolloutStatus::complete(format!(
+            "Daemon set {} successfully rolled out",
+            daemonset.name_any()
+        ))
+    }
+}
+
+async fn patch_workload(
+    client: &kube::Client,
+    target: RolloutTarget,
+    namespace: &str,
+    name: &str,
+    patch: &Patch<Value>,
+) -> Result<(), KubeliError> {
+    let params = PatchParams::default();
+    match target {
+        RolloutTarget::Deployment => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            api.patch(name, &params, patch).await?;
+        }
+        RolloutTarget::StatefulSet => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            api.patch(name, &params, patch).await?;
+        }
+        RolloutTarget::DaemonSet => {
+            let api: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+            api.patch(name, &params, patch).await?;
+        }
+    }
+    Ok(())
+}
+
+/// A paused Deployment would not roll out the new template, so kubectl
+/// refuses to restart it and so do we
+fn ensure_restartable(deployment: &Deployment) -> Result<(), KubeliError> {
+    if deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.paused)
+        .unwrap_or(false)
+    {
+        return Err(KubeliError::unknown(format!(
+            "Deployment {} is paused; resume it before restarting",
+            deployment.name_any()
+        )));
+    }
+    Ok(())
+}
+
+/// Restart all pods of a workload by stamping its pod template, as
+/// `kubectl rollout restart` does
+#[command]
+pub async fn rollout_restart(
+    state: State<'_, AppState>,
+    target: RolloutTarget,
+    name: String,
+    namespace: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    if matches!(target, RolloutTarget::Deployment) {
+        let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+        ensure_restartable(&api.get(&name).await?)?;
+    }
+    let patch = serde_json::json!({
+        "spec": { "template": { "metadata": { "annotations": {
+            RESTARTED_AT_ANNOTATION: chrono::Utc::now().to_rfc3339()
+        } } } }
+    });
+    patch_workload(&client, target, &namespace, &name, &Patch::Strategic(patch)).await?;
+
+    tracing::info!("Restarted {} {}/{}", target.kind(), namespace, name);
+    Ok(())
+}
+
+async fn set_rollout_paused(
+    state: State<'_, AppState>,
+    target: RolloutTarget,
+    name: &str,
+    namespace: &str,
+    paused: bool,
+    context: Option<&str>,
+) -> Result<(), KubeliError> {
+    // StatefulSets and DaemonSets have no spec.paused
+    if target != RolloutTarget::Deployment {
+        return Err(KubeliError::unknown(format!(
+            "{} rollouts cannot be paused, only Deployment rollouts",
+            target.kind()
+        )));
+    }
+    let client = state.k8s.get_client_for(context).await?;
+    let patch = serde_json::json!({ "spec": { "paused": paused } });
+    patch_workload(&client, target, namespace, name, &Patch::Merge(patch)).await?;
+
+    tracing::info!("Set deployment {}/{} paused={}", namespace, name, paused);
+    Ok(())
+}
+
+#[command]
+pub async fn rollout_pause(
+    state: State<'_, AppState>,
+    target: RolloutTarget,
+    name: String,
+    namespace: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    set_rollout_paused(state, target, &name, &namespace, true, context.as_deref()).await
+}
+
+#[command]
+pub async fn rollout_resume(
+    state: State<'_, AppState>,
+    target: RolloutTarget,
+    name: String,
+    namespace: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    set_rollout_paused(state, target, &name, &namespace, false, context.as_deref()).await
+}
+
+/// A stored revision: a ReplicaSet of a Deployment or a ControllerRevision
+/// of a StatefulSet or DaemonSet
+struct Revision {
+    revision: i64,
+    meta: ObjectMeta,
+    /// Pod template, without the labels and directives the controller adds
+    template: Value,
+}
+
+struct WorkloadRevisions {
+    /// Oldest first
+    revisions: Vec<Revision>,
+    current: Option<i64>,
+    paused: bool,
+}
+
+fn controlled_by(meta: &ObjectMeta, uid: &str) -> bool {
+    meta.owner_references
+        .iter()
+        .flatten()
+        .any(|owner| owner.controller == Some(true) && owner.uid == uid)
+}
+
+/// `matchLabels` as a list selector. Revisions carry the workload's selector
+/// labels, so this narrows the listing; ownership is still checked after.
+fn match_labels_selector(selector: &LabelSelector) -> String {
+    selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Pod template of a ReplicaSet, without the pod-template-hash label
+fn replicaset_template(replicaset: &ReplicaSet) -> Option<Value> {
+    let template = replicaset.spec.as_ref()?.template.as_ref()?;
+    let mut template = serde_json::to_value(template).ok()?;
+    if let Some(labels) = template
+        .pointer_mut("/metadata/labels")
+        .and_then(Value::as_object_mut)
+    {
+        labels.remove("pod-template-hash");
+    }
+    Some(template)
+}
+
+/// Pod template of a ControllerRevision. Its data is the patch the
+/// controller would apply: `{"spec":{"template":{..., "$patch":"replace"}}}`.
+fn controller_revision_template(data: &Value) -> Option<Value> {
+    let mut template = data.pointer("/spec/template")?.clone();
+    template.as_object_mut()?.remove("$patch");
+    Some(template)
+}
+
+async fn list_revisions(
+    client: &kube::Client,
+    target: RolloutTarget,
+    namespace: &str,
+    name: &str,
+) -> Result<WorkloadRevisions, KubeliError> {
+    let mut paused = false;
+    let mut update_revision = None;
+    let (uid, selector) = match target {
+        RolloutTarget::Deployment => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+            let deployment = api.get(name).await?;
+            let spec = deployment.spec.unwrap_or_default();
+            paused = spec.paused.unwrap_or(false);
+            (
+                deployment.metadata.uid,
+                match_labels_selector(&spec.selector),
+            )
+        }
+        RolloutTarget::StatefulSet => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+            let statefulset = api.get(name).await?;
+            update_revision = statefulset.status.and_then(|status| status.update_revision);
+            let spec = statefulset.spec.unwrap_or_default();
+            (
+                statefulset.metadata.uid,
+                match_labels_selector(&spec.selector),
+            )
+        }
+        RolloutTarget::DaemonSet => {
+            let api: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+            let daemonset = api.get(name).await?;
+            let spec = daemonset.spec.unwrap_or_default();
+            (
+                daemonset.metadata.uid,
+                match_labels_selector(&spec.selector),
+            )
+        }
+    };
+    let uid = uid.unwrap_or_default();
+    let params = ListParams::default().labels(&selector);
+
+    let mut revisions: Vec<Revision> = match target {
+        RolloutTarget::Deployment => {
+            let api: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+            api.list(&params)
+                .await?
+                .items
+                .into_iter()
+                .filter(|replicaset| controlled_by(&replicaset.metadata, &uid))
+                .filter_map(|replicaset| {
+                    let revision = replicaset
+                        .annotations()
+                        .get(REVISION_ANNOTATION)?
+                        .parse()
+                        .ok()?;
+                    let template = replicaset_template(&replicaset)?;
+                    Some(Revision {
+                        revision,
+                        meta: replicaset.metadata,
+                        template,
+                    })
+                })
+                .collect()
+        }
+        RolloutTarget::StatefulSet | RolloutTarget::DaemonSet => {
+            let api: Api<ControllerRevision> = Api::namespaced(client.clone(), namespace);
+            api.list(&params)
+                .await?
+                .items
+                .into_iter()
+                .filter(|revision| controlled_by(&revision.metadata, &uid))
+                .filter_map(|revision| {
+                    let template = controller_revision_template(&revision.data.as_ref()?.0)?;
+                    Some(Revision {
+                        revision: revision.revision,
+                        meta: revision.metadata,
+                        template,
+                    })
+                })
+                .collect()
+        }
+    };
+    revisions.sort_by_key(|revision| revision.revision);
+
+    // A StatefulSet names its current revision; otherwise the newest is live
+    let current = update_revision
+        .and_then(|update| {
+            revisions
+                .iter()
+                .find(|revision| revision.meta.name.as_deref() == Some(update.as_str()))
+        })
+        .or(revisions.last())
+        .map(|revision| revision.revision);
+
+    Ok(WorkloadRevisions {
+        revisions,
+        current,
+        paused,
+    })
+}
+
+fn template_images(template: &Value) -> Vec<String> {
+    template
+        .pointer("/spec/containers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|container| container["image"].as_str().map(String::from))
+        .collect()
+}
+
+/// Rollout history of a workload, oldest revision first, each with the
+/// pod template changes it made
+#[command]
+pub async fn rollout_history(
+    state: State<'_, AppState>,
+    target: RolloutTarget,
+    name: String,
+    namespace: String,
+    context: Option<String>,
+) -> Result<Vec<RolloutRevision>, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let history = list_revisions(&client, target, &namespace, &name).await?;
+
+    let mut previous: Option<&Value> = None;
+    let mut revisions = Vec::with_capacity(history.revisions.len());
+    for revision in &history.revisions {
+        revisions.push(RolloutRevision {
+            revision: revision.revision,
+            name: revision.meta.name.clone().unwrap_or_default(),
+            created_at: revision
+                .meta
+                .creation_timestamp
+                .as_ref()
+                .map(|t| t.0.to_string()),
+            change_cause: revision
+                .meta
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(CHANGE_CAUSE_ANNOTATION).cloned()),
+            images: template_images(&revision.template),
+            current: history.current == Some(revision.revision),
+            template: serde_yaml::to_string(&revision.template)?,
+            changes: diff_json(previous, &revision.template),
+        });
+        previous = Some(&revision.template);
+    }
+
+    tracing::info!(
+        "Listed {} revisions of {} {}/{}",
+        revisions.len(),
+        target.kind(),
+        namespace,
+        name
+    );
+    Ok(revisions)
+}
+
+/// Roll a workload back to `to_revision`, or to the revision before the
+/// current one. Returns the revision whose pod template is now live.
+#[command]
+pub async fn rollout_undo(
+    state: State<'_, AppState>,
+    target: RolloutTarget,
+    name: String,
+    namespace: String,
+    to_revision: Option<i64>,
+    context: Option<String>,
+) -> Result<i64, KubeliError> {
+    let client = state.k8s.get_client_for(context.as_deref()).await?;
+    let history = list_revisions(&client, target, &namespace, &name).await?;
+    if history.paused {
+        return Err(KubeliError::unknown(format!(
+            "Deployment {} is paused; resume it before rolling back",
+            name
+        )));
+    }
+
+    let current = history.current.unwrap_or_default();
+    let revision = match to_revision.filter(|revision| *revision != 0) {
+        Some(wanted) => history
+            .revisions
+            .iter()
+            .find(|revision| revision.revision == wanted)
+            .ok_or_else(|| format!("Revision {} of {} not found", wanted, name))?,
+        None => history
+            .revisions
+            .iter()
+            .rev()
+            .find(|revision| revision.revision < current)
+            .ok_or_else(|| format!("{} has no previous revision", name))?,
+    };
+    if revision.revision == current {
+        tracing::info!("{} is already at revision {}", name, current);
+        return Ok(current);
+    }
+
+    // Replace the template wholesale; a merge would keep fields the old
+    // revision did not have
+    let mut template = revision.template.clone();
+    template["$patch"] = Value::from("replace");
+    let patch = serde_json::json!({ "spec": { "template": template } });
+    patch_workload(&client, target, &namespace, &name, &Patch::Strategic(patch)).await?;
+
+    tracing::info!(
+        "Rolled back {} {}/{} to revision {}",
+        target.kind(),
+        namespace,
+        name,
+        revision.revision
+    );
+    Ok(revision.revision)
+}
+
+async fn stream_rollout_status<K>(
+    app: AppHandle,
+    manager: Arc<WatchManager>,
+    watch_id: String,
+    api: Api<K>,
+    name: String,
+    status_of: fn(&K) -> RolloutStatus,
+    token: Arc<CancellationToken>,
+) where
+    K: Resource<DynamicType = ()>
+        + Clone
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + serde::de::DeserializeOwned
+        + 'static,
+{
+    let event_name = format!("rollout-status-{}", watch_id);
+    let config = Config::default().fields(&format!("metadata.name={}", name));
+    let mut events = watcher(api, config).default_backoff().boxed();
+    let mut last: Option<RolloutStatus> = None;
+    // Backoff retries keep failing the same way; report each failure once
+    let mut last_error: Option<String> = None;
+
+    loop {
+        let event = tokio::select! {
+            _ = token.cancelled() => break,
+            event = events.next() => match event {
+                Some(event) => event,
+                None => break,
+            },
+        };
+        if event.is_ok() {
+            last_error = None;
+        }
+        let status = match event {
+            Ok(Event::Apply(obj) | Event::InitApply(obj)) => status_of(&obj),
+            Ok(Event::Delete(_)) => RolloutStatus::failed(format!("{} was deleted", name)),
+            Ok(Event::InitDone) if last.is_none() => {
+                RolloutStatus::failed(format!("{} not found", name))
+            }
+            Ok(Event::Init | Event::InitDone) => continue,
+            Err(e) => {
+                let message = e.to_string();
+                if last_error.as_deref() != Some(message.as_str()) {
+                    let _ = app.emit(&event_name, RolloutStatusEvent::Error(e.into()));
+                    last_error = Some(message);
+                }
+                continue;
+            }
+        };
+        if last.as_ref() == Some(&status) {
+            continue;
+        }
+        let finished = status.phase != RolloutPhase::Progressing;
+        if let Err(e) = app.emit(&event_name, RolloutStatusEvent::Status(status.clone())) {
+            tracing::error!("Failed to emit rollout status: {}", e);
+            break;
+        }
+        if finished {
+            break;
+        }
+        last = Some(status);
+    }
+
+    manager.remove_session_if_owned(&watch_id, &token).await;
+    tracing::info!("Rollout status watch {} ended", watch_id);
+}
+
+/// Stream the rollout status of a workload until it completes, fails (a
+/// Deployment past its progressDeadlineSeconds) or `stop_watch` is called
+#[command]
+#[allow(clippy::too_many_arguments)]
+pub async fn watch_rollout_status(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    watch_manager: State<'_, Arc<WatchManager>>,
+    target: RolloutTarget,
+    name: String,
+    namespace: String,
+    watch_id: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    let (client, context) = state
+        .k8s
+        .get_connection_for(context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
+    let manager = Arc::clone(watch_manager.inner());
+    let token = manager.add_session(watch_id.clone(), context).await;
+
+    let id = watch_id.clone();
+    match target {
+        RolloutTarget::Deployment => tokio::spawn(stream_rollout_status(
+            app,
+            manager,
+            id,
+            Api::<Deployment>::namespaced(client, &namespace),
+            name,
+            deployment_rollout_status,
+            token,
+        )),
+        RolloutTarget::StatefulSet => tokio::spawn(stream_rollout_status(
+            app,
+            manager,
+            id,
+            Api::<StatefulSet>::namespaced(client, &namespace),
+            name,
+            statefulset_rollout_status,
+            token,
+        )),
+        RolloutTarget::DaemonSet => tokio::spawn(stream_rollout_status(
+            app,
+            manager,
+            id,
+            Api::<DaemonSet>::namespaced(client, &namespace),
+            name,
+            daemonset_rollout_status,
+            token,
+        )),
+    };
+
+    tracing::info!("Started rollout status watch: {}", watch_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{
+        DaemonSetStatus, DeploymentCondition, DeploymentSpec, DeploymentStatus,
+        RollingUpdateStatefulSetStrategy, StatefulSetSpec, StatefulSetStatus,
+        StatefulSetUpdateStrategy,
+    };
+    use serde_json::json;
+
+    fn deployment(generation: i64, replicas: i32, status: DeploymentStatus) -> Deployment {
+        Deployment {
+            metadata: ObjectMeta {
+                name: Some("web".into()),
+                generation: Some(generation),
+                ..Default::default()
+            },
+            spec: Some(DeploymentSpec {
+                replicas: Some(replicas),
+                ..Default::default()
+            }),
+            status: Some(status),
+        }
+    }
+
+    #[test]
+    fn deployment_status_walks_through_a_rollout() {
+        let unobserved = deployment(
+            2,
+            3,
+            DeploymentStatus {
+                observed_generation: Some(1),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            deployment_rollout_status(&unobserved).phase,
+            RolloutPhase::Progressing
+        );
+
+        let updating = deployment(
+            2,
+            3,
+            DeploymentStatus {
+                observed_generation: Some(2),
+                replicas: Some(4),
+                updated_replicas: Some(1),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            deployment_rollout_status(&updating).message,
+            "Waiting for rollout to finish: 1 of 3 new replicas have been updated"
+        );
+
+        let terminating = deployment(
+            2,
+            3,
+            DeploymentStatus {
+                observed_generation: Some(2),
+                replicas: Some(4),
+                updated_replicas: Some(3),
+                available_replicas: Some(3),
+                ..Default::default()
+            },
+        );
+        assert!(deployment_rollout_status(&terminating)
+            .message
+            .contains("1 old replicas are pending termination"));
+
+        let done = deployment(
+            2,
+            3,
+            DeploymentStatus {
+                observed_generation: Some(2),
+                replicas: Some(3),
+                updated_replicas: Some(3),
+                available_replicas: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            deployment_rollout_status(&done).phase,
+            RolloutPhase::Complete
+        );
+    }
+
+    #[test]
+    fn deployment_past_its_progress_deadline_fails() {
+        let stuck = deployment(
+            2,
+            3,
+            DeploymentStatus {
+                observed_generation: Some(2),
+                updated_replicas: Some(1),
+                conditions: Some(vec![DeploymentCondition {
+                    type_: "Progressing".into(),
+                    status: "False".into(),
+                    reason: Some("ProgressDeadlineExceeded".into()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+        );
+        let status = deployment_rollout_status(&stuck);
+        assert_eq!(status.phase, RolloutPhase::Failed);
+        assert!(status.message.contains("progress deadline"));
+    }
+
+    #[test]
+    fn paused_deployments_cannot_be_restarted() {
+        let mut web = deployment(1, 3, DeploymentStatus::default());
+        assert!(ensure_restartable(&web).is_ok());
+
+        web.spec.as_mut().unwrap().paused = Some(true);
+        let err = ensure_restartable(&web).unwrap_err();
+        assert!(err.to_string().contains("resume it before restarting"));
+    }
+
+    #[test]
+    fn statefulset_status_honours_partitions_and_revisions() {
+        let statefulset = |partition: i32, updated: i32, current_revision: &str| StatefulSet {
+            metadata: ObjectMeta {
+                generation: Some(1),
+                ..Default::default()
+            },
+            spec: Some(StatefulSetSpec {
+                replicas: Some(4),
+                update_strategy: Some(StatefulSetUpdateStrategy {
+                    type_: Some("RollingUpdate".into()),
+                    rolling_update: Some(RollingUpdateStatefulSetStrategy {
+                        partition: Some(partition),
+                        ..Default::default()
+                    }),
+                }),
+                ..Default::default()
+            }),
+            status: Some(StatefulSetStatus {
+                observed_generation: Some(1),
+                replicas: 4,
+                ready_replicas: Some(4),
+                updated_replicas: Some(updated),
+                current_revision: Some(current_revision.into()),
+                update_revision: Some("db-2".into()),
+                ..Default::default()
+            }),
+        };
+
+        assert_eq!(
+            statefulset_rollout_status(&statefulset(2, 1, "db-1")).phase,
+            RolloutPhase::Progressing
+        );
+        assert_eq!(
+            statefulset_rollout_status(&statefulset(2, 2, "db-1")).phase,
+            RolloutPhase::Complete
+        );
+        assert_eq!(
+            statefulset_rollout_status(&statefulset(0, 2, "db-1")).message,
+            "Waiting for rolling update to complete: 2 of 4 pods at revision db-2"
+        );
+        assert_eq!(
+            statefulset_rollout_status(&statefulset(0, 4, "db-2")).phase,
+            RolloutPhase::Complete
+        );
+    }
+
+    #[test]
+    fn daemonset_waits_for_updated_and_available_pods() {
+        let daemonset = |updated: i32, available: i32| DaemonSet {
+            status: Some(DaemonSetStatus {
+                desired_number_scheduled: 3,
+                updated_number_scheduled: Some(updated),
+                number_available: Some(available),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(daemonset_rollout_status(&daemonset(1, 3))
+            .message
+            .contains("1 of 3 new pods"));
+        assert!(daemonset_rollout_status(&daemonset(3, 2))
+            .message
+            .contains("2 of 3 updated pods are available"));
+        assert_eq!(
+            daemonset_rollout_status(&daemonset(3, 3)).phase,
+            RolloutPhase::Complete
+        );
+    }
+
+    #[test]
+    fn revision_templates_drop_controller_bookkeeping() {
+        let data = json!({"spec": {"template": {
+            "$patch": "replace",
+            "metadata": {"labels": {"app": "db"}},
+            "spec": {"containers": [{"name": "db", "image": "postgres:16"}]}
+        }}});
+        let template = controller_revision_template(&data).unwrap();
+        assert!(template.get("$patch").is_none());
+        assert_eq!(template_images(&template), ["postgres:16"]);
+        assert!(controller_revision_template(&json!({})).is_none());
+
+        let replicaset: ReplicaSet = serde_json::from_value(json!({
+            "metadata": {"name": "web-abc"},
+            "spec": {
+                "selector": {"matchLabels": {"app": "web"}},
+                "template": {
+                    "metadata": {"labels": {"app": "web", "pod-template-hash": "abc"}},
+                    "spec": {"containers": [{"name": "web", "image": "web:1"}]}
+                }
+            }
+        }))
+        .unwrap();
+        let template = replicaset_template(&replicaset).unwrap();
+        assert_eq!(template["metadata"]["labels"], json!({"app": "web"}));
+    }
+}
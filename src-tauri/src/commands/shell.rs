@@ -2,7 +2,8 @@ use crate::k8s::AppState;
 use futures::SinkExt;
 use k8s_openapi::api::core::v1::Pod;
 use kube::api::{
-    Api, AttachParams, AttachedProcess, DeleteParams, ListParams, PostParams, TerminalSize,
+    Api, AttachParams, AttachedProcess, DeleteParams, ListParams, Patch, PatchParams, PostParams,
+    TerminalSize,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -18,6 +19,8 @@ struct ShellSession {
     input_tx: mpsc::Sender<ShellInput>,
     /// For node shell sessions, track the debug pod name for cleanup
     debug_pod: Option<DebugPodInfo>,
+    /// Context the session is connected to, so a disconnect only ends its own
+    context: String,
 }
 
 /// Info about a debug pod created for node shell access
@@ -63,6 +66,8 @@ pub struct ShellOptions {
     pub pod_name: String,
     pub container: Option<String>,
     pub command: Option<Vec<String>>,
+    /// Cluster context to exec in; the active cluster when omitted
+    pub context: Option<String>,
 }
 
 /// Manager for active shell sessions
@@ -107,9 +112,32 @@ impl ShellSessionManager {
         sessions.contains_key(id)
     }
 
-    async fn get_debug_pod_info(&self, id: &str) -> Option<DebugPodInfo> {
+    /// Debug pod of a node shell session, with the context it runs in.
+    async fn get_debug_pod_info(&self, id: &str) -> Option<(DebugPodInfo, String)> {
         let sessions = self.sessions.read().await;
-        sessions.get(id).and_then(|s| s.debug_pod.clone())
+        sessions
+            .get(id)
+            .and_then(|s| s.debug_pod.clone().map(|pod| (pod, s.context.clone())))
+    }
+
+    /// Stop the shell sessions of one context, deleting their node-shell
+    /// debug pods best effort with that context's client. Sessions on other
+    /// clusters keep running.
+    pub async fn stop_context(&self, context: &str, client: Option<kube::Client>) {
+        let debug_pods: Vec<DebugPodInfo> = {
+            let mut sessions = self.sessions.write().await;
+            let mut pods = Vec::new();
+            sessions.retain(|_, s| {
+                if s.context != context {
+                    return true;
+                }
+                s.stop_flag.store(true, Ordering::SeqCst);
+                pods.extend(s.debug_pod.clone());
+                false
+            });
+            pods
+        };
+        delete_debug_pods(client, debug_pods);
     }
 
     /// Stop every shell session. Node-shell debug pods are deleted best
@@ -128,16 +156,21 @@ impl ShellSessionManager {
             sessions.clear();
             pods
         };
+        delete_debug_pods(client, debug_pods);
+    }
+}
 
-        if let Some(client) = client {
-            if !debug_pods.is_empty() {
-                tokio::spawn(async move {
-                    for info in debug_pods {
-                        let pods: Api<Pod> = Api::namespaced(client.clone(), &info.namespace);
-                        let _ = pods.delete(&info.pod_name, &DeleteParams::default()).await;
-                    }
-                });
-            }
+/// Delete node-shell debug pods in the background. Without a client (the
+/// cluster is already gone) the pods' activeDeadlineSeconds reaps them.
+fn delete_debug_pods(client: Option<kube::Client>, debug_pods: Vec<DebugPodInfo>) {
+    if let Some(client) = client {
+        if !debug_pods.is_empty() {
+            tokio::spawn(async move {
+                for info in debug_pods {
+                    let pods: Api<Pod> = Api::namespaced(client.clone(), &info.namespace);
+                    let _ = pods.delete(&info.pod_name, &DeleteParams::default()).await;
+                }
+            });
         }
     }
 }
@@ -162,7 +195,11 @@ pub async fn shell_start(
         return Err(format!("Session {} already exists", session_id));
     }
 
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
 
     let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
 
@@ -200,6 +237,7 @@ pub async fn shell_start(
         stop_flag: stop_flag.clone(),
         input_tx,
         debug_pod: None,
+        context,
     };
     shell_manager.add_session(session_id.clone(), session).await;
 
@@ -479,6 +517,8 @@ pub async fn shell_list_sessions(
 pub struct NodeShellOptions {
     pub node_name: String,
     pub image: Option<String>,
+    /// Cluster context of the node; the active cluster when omitted
+    pub context: Option<String>,
 }
 
 const DEFAULT_NODE_SHELL_IMAGE: &str = "docker.io/alpine:3.21";
@@ -525,7 +565,11 @@ pub async fn node_shell_start(
         return Err(format!("Session {} already exists", session_id));
     }
 
-    let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
     let event_name = format!("shell-{}", session_id);
 
     // Verify the node exists
@@ -686,6 +730,7 @@ pub async fn node_shell_start(
             pod_name: debug_pod_name.clone(),
             namespace: NODE_SHELL_NAMESPACE.to_string(),
         }),
+        context,
     };
     shell_manager.add_session(session_id.clone(), session).await;
 
@@ -780,8 +825,12 @@ pub async fn node_shell_cleanup(
     // First close the shell session
     if shell_manager.stop_session(&session_id).await {
         // Get debug pod info before removing session
-        if let Some(debug_info) = shell_manager.get_debug_pod_info(&session_id).await {
-            let client = state.k8s.get_client().await.map_err(|e| e.to_string())?;
+        if let Some((debug_info, context)) = shell_manager.get_debug_pod_info(&session_id).await {
+            let client = state
+                .k8s
+                .get_client_for(Some(&context))
+                .await
+                .map_err(|e| e.to_string())?;
             let pods: Api<Pod> = Api::namespaced(client, &debug_info.namespace);
             match pods
                 .delete(&debug_info.pod_name, &DeleteParams::default())
@@ -803,9 +852,303 @@ pub async fn node_shell_cleanup(
     Ok(())
 }
 
+/// Options for starting a shell in an ephemeral debug container
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugContainerOptions {
+    pub namespace: String,
+    pub pod_name: String,
+    /// Debug image; `DEFAULT_DEBUG_IMAGE` when omitted
+    pub image: Option<String>,
+    /// Container whose process namespace the debug container joins; the
+    /// pod's first container when omitted
+    pub target_container: Option<String>,
+    /// Entrypoint of the debug container; `sh` when omitted
+    pub command: Option<Vec<String>>,
+    /// Cluster context of the pod; the active cluster when omitted
+    pub context: Option<String>,
+}
+
+const DEFAULT_DEBUG_IMAGE: &str = "docker.io/busybox:1.37";
+const DEBUG_CONTAINER_PREFIX: &str = "kubeli-debug-";
+
+/// First `kubeli-debug-N` name not taken by any container of the pod.
+/// Ephemeral containers can never be removed once added, so every debug
+/// session needs a fresh name.
+fn next_debug_container_name(pod: &Pod) -> String {
+    let spec = pod.spec.as_ref();
+    let taken: Vec<&str> = spec
+        .map(|s| {
+            s.containers
+                .iter()
+                .map(|c| c.name.as_str())
+                .chain(s.init_containers.iter().flatten().map(|c| c.name.as_str()))
+                .chain(
+                    s.ephemeral_containers
+                        .iter()
+                        .flatten()
+                        .map(|c| c.name.as_str()),
+                )
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut index = 0;
+    loop {
+        let name = format!("{}{}", DEBUG_CONTAINER_PREFIX, index);
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+        index += 1;
+    }
+}
+
+/// Outcome of polling an ephemeral container's status
+#[derive(Debug, PartialEq)]
+enum DebugContainerState {
+    Pending,
+    Running,
+    Failed(String),
+}
+
+fn debug_container_state(pod: &Pod, name: &str) -> DebugContainerState {
+    let Some(status) = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.ephemeral_container_statuses.as_ref())
+        .and_then(|statuses| statuses.iter().find(|s| s.name == name))
+    else {
+        return DebugContainerState::Pending;
+    };
+    let Some(state) = &status.state else {
+        return DebugContainerState::Pending;
+    };
+    if state.running.is_some() {
+        return DebugContainerState::Running;
+    }
+    if let Some(terminated) = &state.terminated {
+        return DebugContainerState::Failed(format!(
+            "Debug container exited ({})",
+            terminated
+                .reason
+                .clone()
+                .unwrap_or_else(|| format!("exit code {}", terminated.exit_code))
+        ));
+    }
+    if let Some(waiting) = &state.waiting {
+        // Image pull failures never resolve on their own; surface them
+        // instead of waiting out the timeout
+        let reason = waiting.reason.as_deref().unwrap_or_default();
+        if matches!(
+            reason,
+            "ErrImagePull" | "ImagePullBackOff" | "InvalidImageName" | "CreateContainerError"
+        ) {
+            return DebugContainerState::Failed(match &waiting.message {
+                Some(message) => format!("{}: {}", reason, message),
+                None => reason.to_string(),
+            });
+        }
+    }
+    DebugContainerState::Pending
+}
+
+/// Inject an ephemeral debug container into a running pod and attach a shell
+/// to it. The container shares the target container's process namespace, so
+/// distroless workloads can be inspected with the debug image's tooling.
+#[command]
+pub async fn debug_container_start(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    shell_manager: State<'_, Arc<ShellSessionManager>>,
+    session_id: String,
+    options: DebugContainerOptions,
+) -> Result<(), String> {
+    if shell_manager.is_active(&session_id).await {
+        return Err(format!("Session {} already exists", session_id));
+    }
+
+    let (client, context) = state
+        .k8s
+        .get_connection_for(options.context.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    let event_name = format!("shell-{}", session_id);
+
+    let pods: Api<Pod> = Api::namespaced(client, &options.namespace);
+    let pod = pods
+        .get(&options.pod_name)
+        .await
+        .map_err(|e| format!("Failed to get pod: {}", e))?;
+
+    let target = options.target_container.clone().or_else(|| {
+        pod.spec
+            .as_ref()
+            .and_then(|s| s.containers.first())
+            .map(|c| c.name.clone())
+    });
+    if let (Some(target), Some(spec)) = (&target, &pod.spec) {
+        if !spec.containers.iter().any(|c| &c.name == target) {
+            return Err(format!(
+                "Container {} not found in pod {}",
+                target, options.pod_name
+            ));
+        }
+    }
+
+    let container_name = next_debug_container_name(&pod);
+    let image = options
+        .image
+        .clone()
+        .unwrap_or_else(|| DEFAULT_DEBUG_IMAGE.to_string());
+    let command = options
+        .command
+        .clone()
+        .unwrap_or_else(|| vec!["sh".to_string()]);
+
+    let _ = app.emit(
+        &event_name,
+        ShellEvent::Output(format!(
+            "Adding debug container {} ({}) to pod {}...\r\n",
+            container_name, image, options.pod_name
+        )),
+    );
+
+    // stdinOnce: the debug process gets EOF when the session detaches and
+    // exits, rather than idling in the pod for its remaining lifetime
+    let patch = serde_json::json!({
+        "spec": {
+            "ephemeralContainers": [{
+                "name": container_name,
+                "image": image,
+                "command": command,
+                "targetContainerName": target,
+                "stdin": true,
+                "stdinOnce": true,
+                "tty": true,
+                "terminationMessagePolicy": "File",
+            }]
+        }
+    });
+    pods.patch_ephemeral_containers(
+        &options.pod_name,
+        &PatchParams::default(),
+        &Patch::Strategic(&patch),
+    )
+    .await
+    .map_err(|e| format!("Failed to add debug container: {}", e))?;
+
+    tracing::info!(
+        "Added debug container {} to {}/{}",
+        container_name,
+        options.namespace,
+        options.pod_name
+    );
+
+    let _ = app.emit(
+        &event_name,
+        ShellEvent::Output("Waiting for debug container to start...\r\n".to_string()),
+    );
+
+    let deadline = tokio::time::Instant::now() + tokio::time::Duration::from_secs(60);
+    loop {
+        if tokio::time::Instant::now() > deadline {
+            return Err("Timeout waiting for debug container to start".to_string());
+        }
+
+        let pod = pods
+            .get(&options.pod_name)
+            .await
+            .map_err(|e| format!("Failed to check debug container status: {}", e))?;
+        match debug_container_state(&pod, &container_name) {
+            DebugContainerState::Running => break,
+            DebugContainerState::Failed(reason) => return Err(reason),
+            DebugContainerState::Pending => {}
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    }
+
+    // Attach rather than exec: the debug container's entrypoint is the shell
+    let attach_params = AttachParams::interactive_tty().container(&container_name);
+
+    let (input_tx, mut input_rx) = mpsc::channel::<ShellInput>(256);
+    let stop_flag = Arc::new(AtomicBool::new(false));
+
+    let session = ShellSession {
+        stop_flag: stop_flag.clone(),
+        input_tx,
+        debug_pod: None,
+        context,
+    };
+    shell_manager.add_session(session_id.clone(), session).await;
+
+    let session_id_clone = session_id.clone();
+    let shell_manager_clone = Arc::clone(&shell_manager);
+    let event_name_clone = event_name.clone();
+    let pod_name = options.pod_name.clone();
+    let container_name_for_log = container_name.clone();
+
+    let _ = app.emit(
+        &event_name,
+        ShellEvent::Started {
+            session_id: session_id.clone(),
+        },
+    );
+
+    tokio::spawn(async move {
+        let reason = match pods.attach(&pod_name, &attach_params).await {
+            Ok(attached) => {
+                // The shell printed its prompt before we attached, so the
+                // terminal starts out blank
+                let _ = app.emit(
+                    &event_name_clone,
+                    ShellEvent::Output(
+                        "If you don't see a command prompt, try pressing enter.\r\n".to_string(),
+                    ),
+                );
+                run_shell_session(
+                    attached,
+                    app.clone(),
+                    &event_name_clone,
+                    stop_flag,
+                    &mut input_rx,
+                )
+                .await
+            }
+            Err(e) => {
+                let _ = app.emit(
+                    &event_name_clone,
+                    ShellEvent::Error(format!("Failed to attach to debug container: {}", e)),
+                );
+                None
+            }
+        };
+
+        shell_manager_clone.remove_session(&session_id_clone).await;
+        let _ = app.emit(
+            &event_name_clone,
+            ShellEvent::Closed {
+                session_id: session_id_clone,
+                reason,
+            },
+        );
+    });
+
+    tracing::info!(
+        "Started debug session {} for {}/{} in container {}",
+        session_id,
+        options.namespace,
+        options.pod_name,
+        container_name_for_log
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{take_valid_utf8, ShellEvent};
+    use super::{
+        debug_container_state, next_debug_container_name, take_valid_utf8, DebugContainerState,
+        ShellEvent,
+    };
+    use k8s_openapi::api::core::v1::Pod;
 
     // A session cut mid-run is recoverable; only a connection that never
     // established is an Error. The terminal branches on this reason to decide
@@ -864,4 +1207,41 @@ mod tests {
         assert_eq!(out, "a\u{FFFD}");
         assert!(pending.is_empty());
     }
+
+    #[test]
+    fn debug_container_name_skips_existing_containers() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "spec": {
+                "containers": [{ "name": "kubeli-debug-0" }],
+                "ephemeralContainers": [{ "name": "kubeli-debug-1" }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(next_debug_container_name(&pod), "kubeli-debug-2");
+    }
+
+    #[test]
+    fn debug_container_image_pull_failure_is_terminal() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "status": {
+                "ephemeralContainerStatuses": [{
+                    "name": "kubeli-debug-0",
+                    "image": "busybox",
+                    "imageID": "",
+                    "ready": false,
+                    "restartCount": 0,
+                    "state": { "waiting": { "reason": "ImagePullBackOff", "message": "not found" } }
+                }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            debug_container_state(&pod, "kubeli-debug-0"),
+            DebugContainerState::Failed("ImagePullBackOff: not found".to_string())
+        );
+        assert_eq!(
+            debug_container_state(&pod, "kubeli-debug-1"),
+            DebugContainerState::Pending
+        );
+    }
 }
@@ -1,15 +1,18 @@
 use crate::commands::resources::{
-    daemonset_to_info, deployment_to_info, extract_container_info, extract_tolerations,
-    replicaset_to_info, service_to_info, statefulset_to_info, ContainerInfo, NamespaceInfo,
-    PodInfo,
+    daemonset_to_info, deployment_to_info, discover_api_resource, dynamic_to_info,
+    extract_container_info, extract_tolerations, replicaset_to_info, service_to_info,
+    statefulset_to_info, ContainerInfo, CustomResourceInfo, NamespaceInfo, PodInfo,
 };
 use crate::error::KubeliError;
+use crate::k8s::cache::CacheStatus;
 use crate::k8s::AppState;
 use futures::StreamExt;
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::core::v1::{Namespace, Pod, Service};
-use kube::api::Api;
+use kube::api::{Api, DynamicObject};
 use kube::runtime::watcher::{watcher, Config, Event};
+use kube::runtime::WatchStreamExt;
+use kube::Resource;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -33,6 +36,8 @@ pub enum WatchEvent<T> {
 /// remove a session that replaced it under the same id.
 struct WatchSession {
     token: Arc<CancellationToken>,
+    /// Context the watch streams from, so a disconnect only stops its own.
+    context: String,
 }
 
 /// Watch manager to track active watches
@@ -50,13 +55,14 @@ impl WatchManager {
     /// Register a session. If a session with the same id already exists it
     /// is cancelled first - otherwise the old task would keep running with
     /// no one able to stop it.
-    async fn add_session(&self, id: String) -> Arc<CancellationToken> {
+    pub(crate) async fn add_session(&self, id: String, context: String) -> Arc<CancellationToken> {
         let token = Arc::new(CancellationToken::new());
         let mut sessions = self.sessions.write().await;
         if let Some(old) = sessions.insert(
             id,
             WatchSession {
                 token: Arc::clone(&token),
+                context,
             },
         ) {
             old.token.cancel();
@@ -81,7 +87,7 @@ impl WatchManager {
 
     /// Remove the session only if it still belongs to the calling task
     /// (identity via Arc::ptr_eq). Used by watch tasks on exit.
-    async fn remove_session_if_owned(&self, id: &str, token: &Arc<CancellationToken>) {
+    pub(crate) async fn remove_session_if_owned(&self, id: &str, token: &Arc<CancellationToken>) {
         let mut sessions = self.sessions.write().await;
         if let Some(session) = sessions.get(id) {
             if Arc::ptr_eq(&session.token, token) {
@@ -90,8 +96,21 @@ impl WatchManager {
         }
     }
 
-    /// Stop every active watch. Called on disconnect/context switch so no
-    /// watch keeps streaming from the previous cluster.
+    /// Stop the watches streaming from one context. Called when that context
+    /// disconnects or reconnects; watches on other clusters keep running.
+    pub async fn stop_context(&self, context: &str) {
+        let mut sessions = self.sessions.write().await;
+        sessions.retain(|_, session| {
+            if session.context == context {
+                session.token.cancel();
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Stop every active watch.
     pub async fn stop_all(&self) {
         let mut sessions = self.sessions.write().await;
         for session in sessions.values() {
@@ -173,8 +192,8 @@ fn pod_to_info(pod: Pod) -> PodInfo {
 /// listing: Init/InitApply/InitDone become ONE Restarted(Vec) instead of a
 /// flood of Added events (which duplicated existing rows on every watch
 /// resync). Returns None when nothing should be emitted yet.
-fn map_watch_event<K, T>(
-    event: Result<Event<K>, kube::runtime::watcher::Error>,
+fn map_watch_event<K, T, E: Into<KubeliError>>(
+    event: Result<Event<K>, E>,
     init_buffer: &mut Option<Vec<T>>,
     to_info: impl Fn(K) -> T,
 ) -> Option<WatchEvent<T>> {
@@ -197,26 +216,150 @@ fn map_watch_event<K, T>(
         Ok(Event::InitDone) => init_buffer.take().map(WatchEvent::Restarted),
         Ok(Event::Apply(obj)) => Some(WatchEvent::Modified(to_info(obj))),
         Ok(Event::Delete(obj)) => Some(WatchEvent::Deleted(to_info(obj))),
-        Err(e) => Some(WatchEvent::Error(KubeliError::from(e))),
+        Err(e) => Some(WatchEvent::Error(e.into())),
+    }
+}
+
+/// A mapped object with the identity and version it was emitted at
+struct WatchRow<T> {
+    uid: String,
+    resource_version: String,
+    info: T,
+}
+
+fn watch_row<K: Resource, T>(obj: K, to_info: impl Fn(K) -> T) -> WatchRow<T> {
+    let meta = obj.meta();
+    // uid is always set by the API server; namespace/name is only a fallback
+    let uid = meta.uid.clone().unwrap_or_else(|| {
+        format!(
+            "{}/{}",
+            meta.namespace.as_deref().unwrap_or_default(),
+            meta.name.as_deref().unwrap_or_default()
+        )
+    });
+    let resource_version = meta.resource_version.clone().unwrap_or_default();
+    WatchRow {
+        uid,
+        resource_version,
+        info: to_info(obj),
+    }
+}
+
+/// The rows the frontend holds for one watch session, by uid. The watcher
+/// resumes from its last resourceVersion (kept current by bookmarks) after a
+/// dropped connection, but a relist still delivers the whole listing; it is
+/// reconciled against these rows and sent as Added/Modified/Deleted events.
+/// Only the first listing, and a relist forced by 410 Gone, go out as a full
+/// Restarted.
+struct EmittedRows<T> {
+    /// None until the first listing was emitted
+    rows: Option<HashMap<String, (String, T)>>,
+    /// The watch expired (410 Gone): the next listing replaces everything
+    gone: bool,
+}
+
+impl<T> Default for EmittedRows<T> {
+    fn default() -> Self {
+        Self {
+            rows: None,
+            gone: false,
+        }
+    }
+}
+
+impl<T: Clone> EmittedRows<T> {
+    /// Turn a mapped event into the events to emit, tracking what was sent
+    fn reconcile(&mut self, event: WatchEvent<WatchRow<T>>) -> Vec<WatchEvent<T>> {
+        match event {
+            WatchEvent::Added(row) => {
+                self.insert(&row);
+                vec![WatchEvent::Added(row.info)]
+            }
+            WatchEvent::Modified(row) => {
+                self.insert(&row);
+                vec![WatchEvent::Modified(row.info)]
+            }
+            WatchEvent::Deleted(row) => {
+                if let Some(rows) = &mut self.rows {
+                    rows.remove(&row.uid);
+                }
+                vec![WatchEvent::Deleted(row.info)]
+            }
+            WatchEvent::Error(e) => {
+                if e.code == Some(410) {
+                    self.gone = true;
+                }
+                vec![WatchEvent::Error(e)]
+            }
+            WatchEvent::Restarted(listing) => self.resync(listing),
+        }
+    }
+
+    fn insert(&mut self, row: &WatchRow<T>) {
+        self.rows.get_or_insert_with(HashMap::new).insert(
+            row.uid.clone(),
+            (row.resource_version.clone(), row.info.clone()),
+        );
+    }
+
+    fn resync(&mut self, listing: Vec<WatchRow<T>>) -> Vec<WatchEvent<T>> {
+        let gone = std::mem::take(&mut self.gone);
+        let previous = self.rows.take().filter(|_| !gone);
+        let rows: HashMap<String, (String, T)> = listing
+            .iter()
+            .map(|row| {
+                (
+                    row.uid.clone(),
+                    (row.resource_version.clone(), row.info.clone()),
+                )
+            })
+            .collect();
+
+        let Some(mut previous) = previous else {
+            self.rows = Some(rows);
+            return vec![WatchEvent::Restarted(
+                listing.into_iter().map(|row| row.info).collect(),
+            )];
+        };
+
+        let mut events = Vec::new();
+        for row in listing {
+            match previous.remove(&row.uid) {
+                None => events.push(WatchEvent::Added(row.info)),
+                Some((version, _)) if version != row.resource_version => {
+                    events.push(WatchEvent::Modified(row.info))
+                }
+                Some(_) => {}
+            }
+        }
+        events.extend(
+            previous
+                .into_values()
+                .map(|(_, info)| WatchEvent::Deleted(info)),
+        );
+        self.rows = Some(rows);
+        events
     }
 }
 
 /// Drive a watch stream until it ends or the session is cancelled. Uses
 /// select! so cancellation takes effect immediately - the old stop-flag was
 /// only polled after the next event, so quiet watches lingered forever.
-async fn run_watch_loop<K, T>(
+async fn run_watch_loop<K, T, E: Into<KubeliError>>(
     app: AppHandle,
     manager: Arc<WatchManager>,
     watch_id: String,
     event_prefix: &str,
-    stream: impl futures::Stream<Item = Result<Event<K>, kube::runtime::watcher::Error>>,
+    stream: impl futures::Stream<Item = Result<Event<K>, E>>,
     to_info: impl Fn(K) -> T,
     token: Arc<CancellationToken>,
 ) where
+    K: Resource,
     T: Serialize + Clone,
 {
     tokio::pin!(stream);
-    let mut init_buffer: Option<Vec<T>> = None;
+    let mut init_buffer: Option<Vec<WatchRow<T>>> = None;
+    let mut emitted = EmittedRows::default();
     let event_name = format!("{event_prefix}-{watch_id}");
 
     loop {
@@ -227,11 +370,17 @@ async fn run_watch_loop<K, T>(
             }
             event = stream.next() => {
                 let Some(event) = event else { break };
-                if let Some(watch_event) = map_watch_event(event, &mut init_buffer, &to_info) {
-                    if let Err(e) = app.emit(&event_name, &watch_event) {
-                        tracing::error!("Failed to emit watch event: {}", e);
-                        break;
-                    }
+                let to_row = |obj: K| watch_row(obj, &to_info);
+                let Some(watch_event) = map_watch_event(event, &mut init_buffer, to_row) else {
+                    continue;
+                };
+                let emit = emitted
+                    .reconcile(watch_event)
+                    .iter()
+                    .try_for_each(|watch_event| app.emit(&event_name, watch_event));
+                if let Err(e) = emit {
+                    tracing::error!("Failed to emit watch event: {}", e);
+                    break;
                 }
             }
         }
@@ -250,25 +399,30 @@ pub async fn watch_pods(
     namespace: Option<String>,
     label_selector: Option<String>,
     watch_id: String,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await.map_err(KubeliError::from)?;
+    let (client, context) = state
+        .k8s
+        .get_connection_for(context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
     let manager = Arc::clone(watch_manager.inner());
-    let token = manager.add_session(watch_id.clone()).await;
+    let token = manager.add_session(watch_id.clone(), context.clone()).await;
 
     let pods: Api<Pod> = if let Some(ns) = &namespace {
         Api::namespaced(client, ns)
     } else {
         Api::all(client)
     };
+    let (stream, subscription) = state.cache.subscribe(
+        &context,
+        pods,
+        namespace.as_deref(),
+        label_selector.as_deref(),
+    );
 
     let watch_id_clone = watch_id.clone();
     tokio::spawn(async move {
-        let config = label_selector
-            .as_deref()
-            .filter(|selector| !selector.is_empty())
-            .map(|selector| Config::default().labels(selector))
-            .unwrap_or_default();
-        let stream = watcher(pods, config).boxed();
         run_watch_loop(
             app,
             manager,
@@ -279,6 +433,7 @@ pub async fn watch_pods(
             token,
         )
         .await;
+        drop(subscription);
     });
 
     tracing::info!("Started pods watch: {}", watch_id);
@@ -298,25 +453,30 @@ macro_rules! namespaced_watch_command {
             namespace: Option<String>,
             label_selector: Option<String>,
             watch_id: String,
+            context: Option<String>,
         ) -> Result<(), KubeliError> {
-            let client = state.k8s.get_client().await.map_err(KubeliError::from)?;
+            let (client, context) = state
+                .k8s
+                .get_connection_for(context.as_deref())
+                .await
+                .map_err(KubeliError::from)?;
             let manager = Arc::clone(watch_manager.inner());
-            let token = manager.add_session(watch_id.clone()).await;
+            let token = manager.add_session(watch_id.clone(), context.clone()).await;
 
             let api: Api<$kind> = if let Some(ns) = &namespace {
                 Api::namespaced(client, ns)
             } else {
                 Api::all(client)
             };
+            let (stream, subscription) = state.cache.subscribe(
+                &context,
+                api,
+                namespace.as_deref(),
+                label_selector.as_deref(),
+            );
 
             let watch_id_clone = watch_id.clone();
             tokio::spawn(async move {
-                let config = label_selector
-                    .as_deref()
-                    .filter(|selector| !selector.is_empty())
-                    .map(|selector| Config::default().labels(selector))
-                    .unwrap_or_default();
-                let stream = watcher(api, config).boxed();
                 run_watch_loop(
                     app,
                     manager,
@@ -327,6 +487,7 @@ macro_rules! namespaced_watch_command {
                     token,
                 )
                 .await;
+                drop(subscription);
             });
 
             tracing::info!("Started {} watch: {}", $prefix, watch_id);
@@ -387,16 +548,21 @@ pub async fn watch_namespaces(
     state: State<'_, AppState>,
     watch_manager: State<'_, Arc<WatchManager>>,
     watch_id: String,
+    context: Option<String>,
 ) -> Result<(), KubeliError> {
-    let client = state.k8s.get_client().await.map_err(KubeliError::from)?;
+    let (client, context) = state
+        .k8s
+        .get_connection_for(context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
     let manager = Arc::clone(watch_manager.inner());
-    let token = manager.add_session(watch_id.clone()).await;
+    let token = manager.add_session(watch_id.clone(), context.clone()).await;
 
     let namespaces: Api<Namespace> = Api::all(client);
+    let (stream, subscription) = state.cache.subscribe(&context, namespaces, None, None);
 
     let watch_id_clone = watch_id.clone();
     tokio::spawn(async move {
-        let stream = watcher(namespaces, Config::default()).boxed();
         run_watch_loop(
             app,
             manager,
@@ -407,12 +573,106 @@ pub async fn watch_namespaces(
             token,
         )
         .await;
+        drop(subscription);
     });
 
     tracing::info!("Started namespaces watch: {}", watch_id);
     Ok(())
 }
 
+/// Any resource type to watch, identified by group/version/kind
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceWatchQuery {
+    /// API group; empty for the core group
+    #[serde(default)]
+    pub group: String,
+    pub version: String,
+    pub kind: String,
+    /// Ignored for cluster-scoped kinds; all namespaces when omitted
+    pub namespace: Option<String>,
+    pub label_selector: Option<String>,
+    pub field_selector: Option<String>,
+}
+
+/// Watcher config with the non-empty selectors applied
+fn selector_config(label_selector: Option<&str>, field_selector: Option<&str>) -> Config {
+    // Bookmarks keep the resume point current on quiet watches, so a dropped
+    // connection resumes instead of relisting
+    let mut config = Config {
+        bookmarks: true,
+        ..Config::default()
+    };
+    if let Some(labels) = label_selector.filter(|s| !s.is_empty()) {
+        config = config.labels(labels);
+    }
+    if let Some(fields) = field_selector.filter(|s| !s.is_empty()) {
+        config = config.fields(fields);
+    }
+    config
+}
+
+/// Start watching any resource type, including CRDs. The kind is resolved
+/// with API discovery and events carry the same summaries as
+/// `list_custom_resources`, on `resources-watch-{watch_id}`.
+#[command]
+pub async fn watch_resources(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    watch_manager: State<'_, Arc<WatchManager>>,
+    query: ResourceWatchQuery,
+    watch_id: String,
+    context: Option<String>,
+) -> Result<(), KubeliError> {
+    let (client, context) = state
+        .k8s
+        .get_connection_for(context.as_deref())
+        .await
+        .map_err(KubeliError::from)?;
+
+    let (ar, namespaced) =
+        discover_api_resource(&client, &query.group, &query.version, &query.kind).await;
+    // Unknown scope (discovery unavailable): trust the caller's namespace
+    let api: Api<DynamicObject> = match (&query.namespace, namespaced) {
+        (Some(ns), Some(true) | None) => Api::namespaced_with(client, ns, &ar),
+        _ => Api::all_with(client, &ar),
+    };
+
+    let manager = Arc::clone(watch_manager.inner());
+    let token = manager.add_session(watch_id.clone(), context).await;
+
+    let watch_id_clone = watch_id.clone();
+    tokio::spawn(async move {
+        let config = selector_config(
+            query.label_selector.as_deref(),
+            query.field_selector.as_deref(),
+        );
+        // Backoff retries a failed watch instead of hammering the API server
+        let stream = watcher(api, config).default_backoff().boxed();
+        let to_info = |obj: DynamicObject| -> CustomResourceInfo {
+            dynamic_to_info(&obj, &ar.kind, &ar.api_version)
+        };
+        run_watch_loop(
+            app,
+            manager,
+            watch_id_clone,
+            "resources-watch",
+            stream,
+            to_info,
+            token,
+        )
+        .await;
+    });
+
+    tracing::info!(
+        "Started resources watch {} for {}/{} {}",
+        watch_id,
+        query.group,
+        query.version,
+        query.kind
+    );
+    Ok(())
+}
+
 /// Stop watching resources (idempotent — returns Ok even if already stopped)
 #[command]
 pub async fn stop_watch(
@@ -428,6 +688,24 @@ pub async fn stop_watch(
     Ok(())
 }
 
+/// Informers of a cluster and how many lists they answered, for the cache
+/// indicator. `context` defaults to the active cluster.
+#[command]
+pub async fn get_cache_status(
+    state: State<'_, AppState>,
+    context: Option<String>,
+) -> Result<CacheStatus, KubeliError> {
+    let context = match context {
+        Some(context) => context,
+        None => state
+            .k8s
+            .get_current_context()
+            .await
+            .ok_or_else(|| KubeliError::unknown("No cluster context is connected"))?,
+    };
+    Ok(state.cache.status(&context))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -436,6 +714,39 @@ mod tests {
         Ok(Event::Apply(n))
     }
 
+    #[test]
+    fn resource_watch_query_defaults_to_core_group() {
+        let query: ResourceWatchQuery = serde_json::from_value(serde_json::json!({
+            "version": "v1",
+            "kind": "ConfigMap",
+            "namespace": "default",
+            "field_selector": "metadata.name=app-config"
+        }))
+        .unwrap();
+        assert_eq!(query.group, "");
+        assert_eq!(query.kind, "ConfigMap");
+        assert_eq!(
+            query.field_selector.as_deref(),
+            Some("metadata.name=app-config")
+        );
+        assert!(query.label_selector.is_none());
+    }
+
+    #[test]
+    fn selector_config_skips_empty_selectors() {
+        let config = selector_config(Some("app=web"), Some(""));
+        assert_eq!(config.label_selector.as_deref(), Some("app=web"));
+        assert!(config.field_selector.is_none());
+        assert!(config.bookmarks, "resuming relies on watch bookmarks");
+
+        let config = selector_config(None, Some("status.phase=Running"));
+        assert!(config.label_selector.is_none());
+        assert_eq!(
+            config.field_selector.as_deref(),
+            Some("status.phase=Running")
+        );
+    }
+
     #[test]
     fn init_listing_becomes_one_restarted_event() {
         let mut buf: Option<Vec<u32>> = None;
@@ -453,6 +764,72 @@ mod tests {
         assert!(map_watch_event(Ok(Event::InitDone), &mut buf, id).is_none());
     }
 
+    fn row(uid: &str, version: &str, info: u32) -> WatchRow<u32> {
+        WatchRow {
+            uid: uid.to_string(),
+            resource_version: version.to_string(),
+            info,
+        }
+    }
+
+    #[test]
+    fn relist_is_sent_as_a_diff_against_emitted_rows() {
+        let mut emitted = EmittedRows::default();
+        let first = emitted.reconcile(WatchEvent::Restarted(vec![
+            row("a", "1", 1),
+            row("b", "1", 2),
+            row("c", "1", 3),
+        ]));
+        assert!(matches!(first.as_slice(), [WatchEvent::Restarted(items)] if items == &[1, 2, 3]));
+
+        // Unchanged "a", bumped "b", vanished "c", new "d"
+        let events = emitted.reconcile(WatchEvent::Restarted(vec![
+            row("a", "1", 1),
+            row("b", "2", 20),
+            row("d", "3", 4),
+        ]));
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], WatchEvent::Modified(20)));
+        assert!(matches!(events[1], WatchEvent::Added(4)));
+        assert!(matches!(events[2], WatchEvent::Deleted(3)));
+    }
+
+    #[test]
+    fn live_events_update_the_rows_a_relist_is_compared_with() {
+        let mut emitted = EmittedRows::default();
+        emitted.reconcile(WatchEvent::Restarted(vec![row("a", "1", 1)]));
+        emitted.reconcile(WatchEvent::Modified(row("a", "2", 10)));
+        emitted.reconcile(WatchEvent::Modified(row("b", "3", 2)));
+        emitted.reconcile(WatchEvent::Deleted(row("b", "4", 2)));
+
+        let events = emitted.reconcile(WatchEvent::Restarted(vec![row("a", "2", 10)]));
+        assert!(events.is_empty(), "nothing changed since the live events");
+    }
+
+    #[test]
+    fn relist_after_410_gone_is_a_full_restart() {
+        let mut emitted = EmittedRows::default();
+        emitted.reconcile(WatchEvent::Restarted(vec![row("a", "1", 1)]));
+
+        let mut gone = KubeliError::unknown("too old resource version");
+        gone.code = Some(410);
+        emitted.reconcile(WatchEvent::Error(gone));
+        let events = emitted.reconcile(WatchEvent::Restarted(vec![row("b", "9", 2)]));
+        assert!(matches!(events.as_slice(), [WatchEvent::Restarted(items)] if items == &[2]));
+
+        // Back to diffs once the new listing is in place
+        let events = emitted.reconcile(WatchEvent::Restarted(vec![row("b", "9", 2)]));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn errors_before_the_first_listing_do_not_start_diffing() {
+        let mut emitted = EmittedRows::<u32>::default();
+        emitted.reconcile(WatchEvent::Error(KubeliError::unknown("forbidden")));
+        let events = emitted.reconcile(WatchEvent::Restarted(vec![row("a", "1", 1)]));
+        assert!(matches!(events.as_slice(), [WatchEvent::Restarted(_)]));
+    }
+
     #[test]
     fn init_apply_without_init_falls_back_to_added() {
         let mut buf: Option<Vec<u32>> = None;
@@ -779,10 +1156,10 @@ mod tests {
     #[tokio::test]
     async fn add_session_cancels_a_session_it_replaces() {
         let manager = WatchManager::new();
-        let old_token = manager.add_session("w1".into()).await;
+        let old_token = manager.add_session("w1".into(), "ctx-a".into()).await;
         assert!(!old_token.is_cancelled());
 
-        let new_token = manager.add_session("w1".into()).await;
+        let new_token = manager.add_session("w1".into(), "ctx-a".into()).await;
         assert!(
             old_token.is_cancelled(),
             "replaced session must be cancelled"
@@ -793,8 +1170,8 @@ mod tests {
     #[tokio::test]
     async fn task_cannot_remove_a_session_that_replaced_it() {
         let manager = WatchManager::new();
-        let old_token = manager.add_session("w1".into()).await;
-        let new_token = manager.add_session("w1".into()).await;
+        let old_token = manager.add_session("w1".into(), "ctx-a".into()).await;
+        let new_token = manager.add_session("w1".into(), "ctx-a".into()).await;
 
         // Old task exits and tries to clean up - must NOT remove the new session
         manager.remove_session_if_owned("w1", &old_token).await;
@@ -805,10 +1182,23 @@ mod tests {
     #[tokio::test]
     async fn stop_all_cancels_every_session() {
         let manager = WatchManager::new();
-        let t1 = manager.add_session("a".into()).await;
-        let t2 = manager.add_session("b".into()).await;
+        let t1 = manager.add_session("a".into(), "ctx-a".into()).await;
+        let t2 = manager.add_session("b".into(), "ctx-a".into()).await;
         manager.stop_all().await;
         assert!(t1.is_cancelled() && t2.is_cancelled());
         assert!(!manager.stop_session("a").await);
     }
+
+    #[tokio::test]
+    async fn stop_context_leaves_other_clusters_running() {
+        let manager = WatchManager::new();
+        let prod = manager.add_session("a".into(), "prod".into()).await;
+        let staging = manager.add_session("b".into(), "staging".into()).await;
+
+        manager.stop_context("prod").await;
+        assert!(prod.is_cancelled());
+        assert!(!staging.is_cancelled(), "staging watch must survive");
+        assert!(!manager.stop_session("a").await);
+        assert!(manager.stop_session("b").await);
+    }
 }
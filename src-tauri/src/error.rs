@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 /// Structured error kinds for Kubernetes API errors
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +28,9 @@ pub struct KubeliError {
     pub resource: Option<String>,
     pub suggestions: Vec<String>,
     pub retryable: bool,
+    /// Fields a server-side apply would take from other managers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<FieldConflict>,
 }
 
 impl KubeliError {
@@ -41,6 +45,7 @@ impl KubeliError {
             message: message.into(),
             detail: None,
             resource: None,
+            conflicts: Vec::new(),
         }
     }
 
@@ -50,6 +55,44 @@ impl KubeliError {
     }
 }
 
+/// A field owned by another field manager, from a rejected server-side apply
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldConflict {
+    /// Field path as the API server reports it, e.g. `.spec.replicas`
+    pub field: String,
+    /// Manager that currently owns the field
+    pub manager: String,
+    pub message: String,
+}
+
+/// Conflicts of a 409 from server-side apply. None for any other error.
+pub fn field_conflicts(err: &kube::Error) -> Option<Vec<FieldConflict>> {
+    let kube::Error::Api(status) = err else {
+        return None;
+    };
+    if status.code != 409 {
+        return None;
+    }
+    let conflicts: Vec<FieldConflict> = status
+        .details
+        .iter()
+        .flat_map(|details| &details.causes)
+        .filter(|cause| cause.reason == "FieldManagerConflict")
+        .map(|cause| FieldConflict {
+            field: cause.field.clone(),
+            // `conflict with "helm-controller" using apps/v1`
+            manager: cause
+                .message
+                .split('"')
+                .nth(1)
+                .unwrap_or_default()
+                .to_string(),
+            message: cause.message.clone(),
+        })
+        .collect();
+    (!conflicts.is_empty()).then_some(conflicts)
+}
+
 impl std::fmt::Display for KubeliError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.message)
@@ -161,7 +204,7 @@ impl From<kube::Error> for KubeliError {
                 };
 
                 let kind_clone = kind.clone();
-                KubeliError {
+                let mut kubeli = KubeliError {
                     retryable: is_retryable(&kind_clone),
                     suggestions: suggestions_for(&kind_clone),
                     kind,
@@ -169,7 +212,26 @@ impl From<kube::Error> for KubeliError {
                     message,
                     detail: Some(format!("{}", err)),
                     resource: None,
+                    conflicts: Vec::new(),
+                };
+                if let Some(conflicts) = field_conflicts(&err) {
+                    let managers: BTreeSet<&str> =
+                        conflicts.iter().map(|c| c.manager.as_str()).collect();
+                    kubeli.message = format!(
+                        "Apply conflicts with {} field(s) managed by {}",
+                        conflicts.len(),
+                        managers.into_iter().collect::<Vec<_>>().join(", ")
+                    );
+                    kubeli.suggestions = vec![
+                        "Force the apply to take ownership of these fields".to_string(),
+                        "Drop these fields from the manifest to leave them to their manager"
+                            .to_string(),
+                    ];
+                    // Retrying the same apply hits the same owners
+                    kubeli.retryable = false;
+                    kubeli.conflicts = conflicts;
                 }
+                kubeli
             }
             kube::Error::Auth(_) => {
                 // Structural match: any client auth failure (token refresh,
@@ -220,6 +282,7 @@ impl From<kube::Error> for KubeliError {
                     message,
                     detail: Some(err_str),
                     resource: None,
+                    conflicts: Vec::new(),
                 }
             }
         }
@@ -414,6 +477,44 @@ mod tests {
         assert!(server_error.retryable);
     }
 
+    #[test]
+    fn field_conflicts_name_field_and_manager() {
+        let status = kube::core::Status::failure("Apply failed with 1 conflict", "Conflict")
+            .with_code(409)
+            .with_details(kube::core::response::StatusDetails {
+                name: "web".to_string(),
+                group: "apps".to_string(),
+                kind: "deployments".to_string(),
+                uid: String::new(),
+                retry_after_seconds: 0,
+                causes: vec![
+                    kube::core::response::StatusCause {
+                        reason: "FieldManagerConflict".to_string(),
+                        message: "conflict with \"helm\" using apps/v1".to_string(),
+                        field: ".spec.replicas".to_string(),
+                    },
+                    kube::core::response::StatusCause {
+                        reason: "FieldValueInvalid".to_string(),
+                        message: "unrelated".to_string(),
+                        field: ".spec".to_string(),
+                    },
+                ],
+            });
+        let conflicts = field_conflicts(&kube::Error::Api(Box::new(status.clone()))).unwrap();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].field, ".spec.replicas");
+        assert_eq!(conflicts[0].manager, "helm");
+        assert!(field_conflicts(&api_error(409)).is_none());
+        assert!(field_conflicts(&api_error(404)).is_none());
+
+        let kubeli = KubeliError::from(kube::Error::Api(Box::new(status)));
+        assert!(matches!(kubeli.kind, ErrorKind::Conflict));
+        assert!(!kubeli.retryable);
+        assert!(kubeli.message.contains("helm"));
+        assert_eq!(kubeli.conflicts, conflicts);
+    }
+
     #[test]
     fn kube_auth_errors_classify_structurally_as_unauthorized() {
         // No "401"/"unauthorized" in the message — structural match must win,
@@ -0,0 +1,687 @@
+//! Shared informer cache.
+//!
+//! Every open view used to run its own watch, and every refresh a full LIST.
+//! Watch sessions now subscribe to one reflector-backed informer per
+//! cluster, kind, namespace and label selector. The informer keeps the
+//! objects in memory, so a second view of the same kind shares the upstream
+//! watch, and `list_*` commands covered by a synced informer are answered
+//! without touching the API server. An informer lives as long as at least
+//! one watch session subscribes to it.
+
+use crate::error::KubeliError;
+use futures::channel::mpsc as futures_mpsc;
+use futures::{SinkExt, StreamExt};
+use kube::api::Api;
+use kube::runtime::reflector::{self, Store};
+use kube::runtime::watcher::{watcher, Config, Event};
+use kube::runtime::WatchStreamExt;
+use kube::Resource;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+/// Events buffered per informer for slow subscribers. A subscriber that
+/// falls further behind is resynced from the store.
+const EVENT_BUFFER: usize = 1024;
+
+/// Event delivered to watch sessions subscribed to an informer
+pub type CacheEvent<K> = Result<Event<K>, KubeliError>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct InformerKey {
+    context: String,
+    api_version: String,
+    kind: String,
+    namespace: Option<String>,
+    label_selector: Option<String>,
+}
+
+impl InformerKey {
+    fn new<K>(context: &str, namespace: Option<&str>, label_selector: Option<&str>) -> Self
+    where
+        K: Resource<DynamicType = ()>,
+    {
+        Self {
+            context: context.to_string(),
+            api_version: K::api_version(&()).into_owned(),
+            kind: K::kind(&()).into_owned(),
+            namespace: namespace.map(str::to_string),
+            label_selector: label_selector
+                .filter(|selector| !selector.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+/// Health of one informer, shared between its task and the cache
+#[derive(Default)]
+struct InformerStatus {
+    subscribers: AtomicUsize,
+    /// Initial listing done and no relist in progress
+    synced: AtomicBool,
+    /// Set when the upstream watch failed; cleared by the next event
+    last_error: Mutex<Option<String>>,
+    /// Unix millis of the last upstream event
+    last_event_at: AtomicI64,
+    /// `list_*` calls answered from this informer
+    hits: AtomicU64,
+    token: CancellationToken,
+}
+
+impl InformerStatus {
+    fn record(&self, event: &Result<Event<impl Sized>, kube::runtime::watcher::Error>) {
+        self.last_event_at
+            .store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+        let mut last_error = self
+            .last_error
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match event {
+            Ok(Event::Init) => self.synced.store(false, Ordering::SeqCst),
+            Ok(Event::InitDone) => self.synced.store(true, Ordering::SeqCst),
+            Ok(_) => {}
+            Err(e) => {
+                *last_error = Some(e.to_string());
+                return;
+            }
+        }
+        *last_error = None;
+    }
+
+    /// Whether the store reflects the cluster closely enough to serve lists
+    fn is_fresh(&self) -> bool {
+        self.synced.load(Ordering::SeqCst)
+            && self
+                .last_error
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .is_none()
+    }
+}
+
+/// One shared upstream watch and its store
+struct Informer<K>
+where
+    K: Resource<DynamicType = ()> + Clone + 'static,
+{
+    reader: Store<K>,
+    events: broadcast::Sender<CacheEvent<K>>,
+    status: Arc<InformerStatus>,
+}
+
+struct CacheEntry {
+    /// `Arc<Informer<K>>` for the kind in the key
+    informer: Arc<dyn Any + Send + Sync>,
+    status: Arc<InformerStatus>,
+    /// Size of the store, without knowing K
+    object_count: Box<dyn Fn() -> usize + Send + Sync>,
+}
+
+impl CacheEntry {
+    fn new<K>(informer: Arc<Informer<K>>) -> Self
+    where
+        K: Resource<DynamicType = ()> + Clone + Send + Sync + 'static,
+    {
+        let reader = informer.reader.clone();
+        Self {
+            status: Arc::clone(&informer.status),
+            informer,
+            object_count: Box::new(move || reader.state().len()),
+        }
+    }
+}
+
+/// Cache state of one informer, for the frontend's cache indicator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntryInfo {
+    pub context: String,
+    pub api_version: String,
+    pub kind: String,
+    pub namespace: Option<String>,
+    pub label_selector: Option<String>,
+    pub subscribers: usize,
+    pub object_count: usize,
+    pub synced: bool,
+    /// True while the informer relists or its watch is failing; lists are
+    /// then fetched from the API server instead
+    pub stale: bool,
+    pub last_error: Option<String>,
+    pub last_event_at: Option<String>,
+    pub hits: u64,
+}
+
+/// Cache statistics for one context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheStatus {
+    pub entries: Vec<CacheEntryInfo>,
+    /// `list_*` calls answered from memory
+    pub hits: u64,
+    /// `list_*` calls that had to LIST against the API server
+    pub misses: u64,
+}
+
+/// Per-cluster reflector cache shared by watch and list commands
+#[derive(Default)]
+pub struct ResourceCache {
+    informers: Mutex<HashMap<InformerKey, CacheEntry>>,
+    misses: Mutex<HashMap<String, u64>>,
+}
+
+/// Keeps a watch session subscribed to an informer. Dropping the last
+/// subscription stops the upstream watch.
+pub struct CacheSubscription {
+    cache: Arc<ResourceCache>,
+    key: InformerKey,
+    status: Arc<InformerStatus>,
+}
+
+impl Drop for CacheSubscription {
+    fn drop(&mut self) {
+        if self.status.subscribers.fetch_sub(1, Ordering::SeqCst) != 1 {
+            return;
+        }
+        let mut informers = self.cache.lock_informers();
+        // Only remove the informer this subscription belonged to; a new one
+        // may already have replaced it under the same key.
+        if let Some(entry) = informers.get(&self.key) {
+            if Arc::ptr_eq(&entry.status, &self.status)
+                && entry.status.subscribers.load(Ordering::SeqCst) == 0
+            {
+                entry.status.token.cancel();
+                informers.remove(&self.key);
+                tracing::debug!("Stopped informer for {:?}", self.key);
+            }
+        }
+    }
+}
+
+impl ResourceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock_informers(&self) -> std::sync::MutexGuard<'_, HashMap<InformerKey, CacheEntry>> {
+        self.informers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Subscribe to the informer for a kind, starting it on `api` if this is
+    /// the first subscriber. `namespace` must be the namespace `api` is
+    /// scoped to. The stream starts with the current objects as an
+    /// `Init`/`InitApply`/`InitDone` listing, followed by live events.
+    pub fn subscribe<K>(
+        self: &Arc<Self>,
+        context: &str,
+        api: Api<K>,
+        namespace: Option<&str>,
+        label_selector: Option<&str>,
+    ) -> (futures_mpsc::Receiver<CacheEvent<K>>, CacheSubscription)
+    where
+        K: Resource<DynamicType = ()>
+            + Clone
+            + Debug
+            + Send
+            + Sync
+            + serde::de::DeserializeOwned
+            + 'static,
+    {
+        let key = InformerKey::new::<K>(context, namespace, label_selector);
+        let informer = {
+            let mut informers = self.lock_informers();
+            let existing = informers
+                .get(&key)
+                .and_then(|entry| Arc::clone(&entry.informer).downcast::<Informer<K>>().ok());
+            let informer = match existing {
+                Some(informer) => informer,
+                None => {
+                    let informer = Arc::new(start_informer(api, key.label_selector.as_deref()));
+                    informers.insert(key.clone(), CacheEntry::new(Arc::clone(&informer)));
+                    tracing::debug!("Started informer for {:?}", key);
+                    informer
+                }
+            };
+            // Counted under the lock so a concurrent last unsubscribe cannot
+            // stop the informer between lookup and increment
+            informer.status.subscribers.fetch_add(1, Ordering::SeqCst);
+            informer
+        };
+
+        let (tx, rx) = futures_mpsc::channel(EVENT_BUFFER);
+        let receiver = informer.events.subscribe();
+        let token = informer.status.token.clone();
+        tokio::spawn(forward_to_subscriber(
+            informer.reader.clone(),
+            receiver,
+            tx,
+            token,
+        ));
+
+        let subscription = CacheSubscription {
+            cache: Arc::clone(self),
+            key,
+            status: Arc::clone(&informer.status),
+        };
+        (rx, subscription)
+    }
+
+    /// Objects of a kind from a fresh informer without a label selector: the
+    /// one for the namespace, or the all-namespaces one filtered down.
+    /// Returns None (and counts a miss) when no informer covers the list.
+    pub fn cached_list<K>(&self, context: &str, namespace: Option<&str>) -> Option<Vec<K>>
+    where
+        K: Resource<DynamicType = ()> + Clone + Send + Sync + 'static,
+    {
+        let candidates = [
+            InformerKey::new::<K>(context, namespace, None),
+            InformerKey::new::<K>(context, None, None),
+        ];
+        let hit = {
+            let informers = self.lock_informers();
+            candidates.iter().find_map(|key| {
+                let entry = informers.get(key)?;
+                if !entry.status.is_fresh() {
+                    return None;
+                }
+                let informer = Arc::clone(&entry.informer).downcast::<Informer<K>>().ok()?;
+                Some((informer, key.namespace.is_none()))
+            })
+        };
+
+        let Some((informer, needs_filter)) = hit else {
+            *self
+                .misses
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .entry(context.to_string())
+                .or_default() += 1;
+            return None;
+        };
+        informer.status.hits.fetch_add(1, Ordering::Relaxed);
+        Some(
+            informer
+                .reader
+                .state()
+                .into_iter()
+                .filter(|obj| {
+                    !needs_filter
+                        || namespace.is_none()
+                        || obj.meta().namespace.as_deref() == namespace
+                })
+                .map(|obj| (*obj).clone())
+                .collect(),
+        )
+    }
+
+    /// Objects of the informer for exactly this kind and namespace (no label
+    /// selector). Unlike `cached_list` there is no fallback and no hit/miss
+    /// accounting: it is for sessions that hold a subscription on that key
+    /// and read it back after each event.
+    pub fn snapshot<K>(&self, context: &str, namespace: Option<&str>) -> Vec<K>
+    where
+        K: Resource<DynamicType = ()> + Clone + Send + Sync + 'static,
+    {
+        let key = InformerKey::new::<K>(context, namespace, None);
+        let informer = self
+            .lock_informers()
+            .get(&key)
+            .and_then(|entry| Arc::clone(&entry.informer).downcast::<Informer<K>>().ok());
+        informer
+            .map(|informer| {
+                informer
+                    .reader
+                    .state()
+                    .into_iter()
+                    .map(|obj| (*obj).clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Informers and hit/miss counters of one context
+    pub fn status(&self, context: &str) -> CacheStatus {
+        let informers = self.lock_informers();
+        let mut entries: Vec<CacheEntryInfo> = informers
+            .iter()
+            .filter(|(key, _)| key.context == context)
+            .map(|(key, entry)| {
+                let status = &entry.status;
+                let last_event_at = status.last_event_at.load(Ordering::Relaxed);
+                let last_error = status
+                    .last_error
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clone();
+                CacheEntryInfo {
+                    context: key.context.clone(),
+                    api_version: key.api_version.clone(),
+                    kind: key.kind.clone(),
+                    namespace: key.namespace.clone(),
+                    label_selector: key.label_selector.clone(),
+                    subscribers: status.subscribers.load(Ordering::SeqCst),
+                    object_count: (entry.object_count)(),
+                    synced: status.synced.load(Ordering::SeqCst),
+                    stale: !status.is_fresh(),
+                    last_error,
+                    last_event_at: (last_event_at > 0)
+                        .then(|| chrono::DateTime::from_timestamp_millis(last_event_at))
+                        .flatten()
+                        .map(|at| at.to_rfc3339()),
+                    hits: status.hits.load(Ordering::Relaxed),
+                }
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.kind, &a.namespace, &a.label_selector).cmp(&(
+                &b.kind,
+                &b.namespace,
+                &b.label_selector,
+            ))
+        });
+        let hits = entries.iter().map(|entry| entry.hits).sum();
+        let misses = self
+            .misses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(context)
+            .copied()
+            .unwrap_or(0);
+        CacheStatus {
+            entries,
+            hits,
+            misses,
+        }
+    }
+
+    /// Stop the informers of one context. Called when it disconnects.
+    pub fn stop_context(&self, context: &str) {
+        self.lock_informers().retain(|key, entry| {
+            if key.context == context {
+                entry.status.token.cancel();
+                false
+            } else {
+                true
+            }
+        });
+        self.misses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(context);
+    }
+
+    /// Stop every informer.
+    pub fn stop_all(&self) {
+        for entry in self.lock_informers().drain().map(|(_, entry)| entry) {
+            entry.status.token.cancel();
+        }
+        self.misses
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+}
+
+fn start_informer<K>(api: Api<K>, label_selector: Option<&str>) -> Informer<K>
+where
+    K: Resource<DynamicType = ()>
+        + Clone
+        + Debug
+        + Send
+        + Sync
+        + serde::de::DeserializeOwned
+        + 'static,
+{
+    // Explicit: the informer outlives many reconnects, and only bookmarks
+    // let it resume from a recent resourceVersion rather than relisting
+    let mut config = Config {
+        bookmarks: true,
+        ..Config::default()
+    };
+    if let Some(selector) = label_selector {
+        config = config.labels(selector);
+    }
+
+    let (reader, writer) = reflector::store();
+    let (events, _) = broadcast::channel(EVENT_BUFFER);
+    let status = Arc::new(InformerStatus::default());
+
+    let task_events = events.clone();
+    let task_status = Arc::clone(&status);
+    tokio::spawn(async move {
+        let stream = reflector::reflector(writer, watcher(api, config).default_backoff());
+        tokio::pin!(stream);
+        loop {
+            tokio::select! {
+                _ = task_status.token.cancelled() => break,
+                event = stream.next() => {
+                    let Some(event) = event else { break };
+                    task_status.record(&event);
+                    // No receivers is fine: subscribers come and go
+                    let _ = task_events.send(event.map_err(KubeliError::from));
+                }
+            }
+        }
+    });
+
+    Informer {
+        reader,
+        events,
+        status,
+    }
+}
+
+/// Whether an event received after the snapshot is already covered by it.
+/// A subscriber can join in the middle of the informer's listing; those
+/// InitApply/InitDone events are part of the snapshot, up to the next Init.
+fn covered_by_snapshot<K>(event: &CacheEvent<K>, awaiting_init: &mut bool) -> bool {
+    match event {
+        Ok(Event::Init) => {
+            *awaiting_init = false;
+            false
+        }
+        Ok(Event::InitApply(_) | Event::InitDone) => *awaiting_init,
+        _ => false,
+    }
+}
+
+/// Send the store's current objects as one listing
+async fn send_snapshot<K>(reader: &Store<K>, tx: &mut futures_mpsc::Sender<CacheEvent<K>>) -> bool
+where
+    K: Resource<DynamicType = ()> + Clone + 'static,
+{
+    if tx.send(Ok(Event::Init)).await.is_err() {
+        return false;
+    }
+    for obj in reader.state() {
+        if tx.send(Ok(Event::InitApply((*obj).clone()))).await.is_err() {
+            return false;
+        }
+    }
+    tx.send(Ok(Event::InitDone)).await.is_ok()
+}
+
+/// Feed one subscriber: errors while the informer syncs, then the snapshot,
+/// then live events. Ends when the subscriber goes away or the informer stops.
+async fn forward_to_subscriber<K>(
+    reader: Store<K>,
+    mut events: broadcast::Receiver<CacheEvent<K>>,
+    mut tx: futures_mpsc::Sender<CacheEvent<K>>,
+    token: CancellationToken,
+) where
+    K: Resource<DynamicType = ()> + Clone + Send + Sync + 'static,
+{
+    // Wait for the first listing, passing on errors (e.g. Forbidden) so the
+    // view does not spin silently
+    let ready = reader.wait_until_ready();
+    tokio::pin!(ready);
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => return,
+            result = &mut ready => {
+                if result.is_err() {
+                    return;
+                }
+                break;
+            }
+            event = events.recv() => match event {
+                Ok(Err(e)) => {
+                    if tx.send(Err(e)).await.is_err() {
+                        return;
+                    }
+                }
+                Ok(Ok(_)) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            }
+        }
+    }
+
+    if !send_snapshot(&reader, &mut tx).await {
+        return;
+    }
+    let mut awaiting_init = true;
+    loop {
+        let event = tokio::select! {
+            _ = token.cancelled() => return,
+            event = events.recv() => event,
+        };
+        match event {
+            Ok(event) => {
+                if covered_by_snapshot(&event, &mut awaiting_init) {
+                    continue;
+                }
+                if tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                tracing::debug!("Cache subscriber lagged by {} events; resyncing", missed);
+                if !send_snapshot(&reader, &mut tx).await {
+                    return;
+                }
+                awaiting_init = true;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::api::ObjectMeta;
+
+    fn pod(namespace: &str, name: &str) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                namespace: Some(namespace.to_string()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Register an informer whose store is filled by hand instead of a watch
+    fn insert_informer(
+        cache: &ResourceCache,
+        namespace: Option<&str>,
+        pods: Vec<Pod>,
+        synced: bool,
+    ) -> Arc<InformerStatus> {
+        let (reader, mut writer) = reflector::store::<Pod>();
+        writer.apply_watcher_event(&Event::Init);
+        for pod in pods {
+            writer.apply_watcher_event(&Event::InitApply(pod));
+        }
+        writer.apply_watcher_event(&Event::InitDone);
+
+        let status = Arc::new(InformerStatus::default());
+        status.synced.store(synced, Ordering::SeqCst);
+        let informer = Arc::new(Informer {
+            reader,
+            events: broadcast::channel(8).0,
+            status: Arc::clone(&status),
+        });
+        cache.lock_informers().insert(
+            InformerKey::new::<Pod>("prod", namespace, None),
+            CacheEntry::new(informer),
+        );
+        status
+    }
+
+    #[test]
+    fn namespaced_list_is_served_from_the_all_namespaces_informer() {
+        let cache = ResourceCache::new();
+        insert_informer(
+            &cache,
+            None,
+            vec![pod("default", "a"), pod("kube-system", "b")],
+            true,
+        );
+
+        let pods = cache.cached_list::<Pod>("prod", Some("default")).unwrap();
+        assert_eq!(pods.len(), 1);
+        assert_eq!(pods[0].metadata.name.as_deref(), Some("a"));
+        assert_eq!(cache.cached_list::<Pod>("prod", None).unwrap().len(), 2);
+
+        let status = cache.status("prod");
+        assert_eq!(status.hits, 2);
+        assert_eq!(status.entries[0].object_count, 2);
+        assert!(!status.entries[0].stale);
+    }
+
+    #[test]
+    fn stale_or_missing_informers_are_misses() {
+        let cache = ResourceCache::new();
+        let status = insert_informer(&cache, Some("default"), vec![pod("default", "a")], false);
+
+        assert!(cache.cached_list::<Pod>("prod", Some("default")).is_none());
+        assert!(cache.cached_list::<Pod>("prod", Some("other")).is_none());
+        assert!(cache.cached_list::<Pod>("staging", None).is_none());
+        assert_eq!(cache.status("prod").misses, 2);
+        assert!(cache.status("prod").entries[0].stale);
+
+        status.synced.store(true, Ordering::SeqCst);
+        *status.last_error.lock().unwrap() = Some("watch failed".to_string());
+        assert!(cache.cached_list::<Pod>("prod", Some("default")).is_none());
+    }
+
+    #[test]
+    fn stop_context_cancels_only_that_context() {
+        let cache = ResourceCache::new();
+        let status = insert_informer(&cache, None, vec![], true);
+        cache.stop_context("staging");
+        assert!(!status.token.is_cancelled());
+        cache.stop_context("prod");
+        assert!(status.token.is_cancelled());
+        assert!(cache.status("prod").entries.is_empty());
+    }
+
+    #[test]
+    fn partial_listing_after_the_snapshot_is_skipped_until_the_next_init() {
+        let mut awaiting_init = true;
+        let apply = |name| -> CacheEvent<Pod> { Ok(Event::InitApply(pod("default", name))) };
+
+        assert!(covered_by_snapshot(&apply("a"), &mut awaiting_init));
+        assert!(covered_by_snapshot(
+            &Ok::<_, KubeliError>(Event::<Pod>::InitDone),
+            &mut awaiting_init
+        ));
+        assert!(!covered_by_snapshot(
+            &Ok::<_, KubeliError>(Event::Apply(pod("default", "a"))),
+            &mut awaiting_init
+        ));
+        // A real relist after the snapshot goes through in full
+        assert!(!covered_by_snapshot(
+            &Ok::<_, KubeliError>(Event::<Pod>::Init),
+            &mut awaiting_init
+        ));
+        assert!(!covered_by_snapshot(&apply("b"), &mut awaiting_init));
+    }
+}
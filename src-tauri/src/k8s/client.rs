@@ -9,11 +9,13 @@ use kube::{
     config::{KubeConfigOptions, Kubeconfig},
     Api, Client, Config,
 };
+use std::collections::HashMap;
 use std::env;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+use super::cache::ResourceCache;
 use super::config::KubeConfig as ParsedKubeConfig;
 
 /// Builds a kube client, optionally injecting the OIDC token per request.
@@ -85,9 +87,17 @@ pub(crate) fn apply_shared_client_timeouts(config: &mut Config) {
 /// the file may name no current-context.
 pub type SharedConnection = Arc<RwLock<Option<(Client, Option<String>)>>>;
 
+/// Every live client, keyed by the context it was built for. The active
+/// connection is one entry of this registry; the others stay connected in the
+/// background so commands that name their context keep working (and their
+/// watches, log streams and shells keep running) while another cluster is
+/// active.
+pub type ClientRegistry = Arc<RwLock<HashMap<String, Client>>>;
+
 /// Thread-safe Kubernetes client manager
 pub struct KubeClientManager {
     connection: SharedConnection,
+    clients: ClientRegistry,
     kubeconfig: Arc<RwLock<Option<ParsedKubeConfig>>>,
     connection_log: Arc<RwLock<Option<String>>>,
     /// Serializes connect attempts: two racing connect_cluster calls would
@@ -170,9 +180,14 @@ impl KubeClientManager {
         Arc::clone(&self.connection)
     }
 
+    pub fn registry_handle(&self) -> ClientRegistry {
+        Arc::clone(&self.clients)
+    }
+
     pub fn new() -> Self {
         Self {
             connection: Arc::new(RwLock::new(None)),
+            clients: Arc::new(RwLock::new(HashMap::new())),
             kubeconfig: Arc::new(RwLock::new(None)),
             connection_log: Arc::new(RwLock::new(None)),
             connect_lock: Arc::new(tokio::sync::Mutex::new(())),
@@ -189,7 +204,39 @@ impl KubeClientManager {
     /// the stored client/context never point at a cluster that did not pass
     /// the connection test (the UI treats a failed connect as disconnected).
     pub async fn clear_connection(&self) {
-        *self.connection.write().await = None;
+        let previous = self.connection.write().await.take();
+        if let Some((_, Some(context))) = previous {
+            self.clients.write().await.remove(&context);
+        }
+    }
+
+    /// Drop the client of one context. Clears the active connection too when
+    /// that context is the active one; other contexts stay connected.
+    pub async fn remove_context(&self, context_name: &str) {
+        self.clients.write().await.remove(context_name);
+        let mut connection = self.connection.write().await;
+        let is_active =
+            connection.as_ref().and_then(|(_, ctx)| ctx.as_deref()) == Some(context_name);
+        if is_active {
+            *connection = None;
+        }
+    }
+
+    /// Make an already connected context the active one without rebuilding
+    /// its client. Returns false when the context has no live client.
+    pub async fn activate(&self, context_name: &str) -> bool {
+        let Some(client) = self.clients.read().await.get(context_name).cloned() else {
+            return false;
+        };
+        *self.connection.write().await = Some((client, Some(context_name.to_string())));
+        true
+    }
+
+    /// Names of every context with a live client, sorted for stable output.
+    pub async fn connected_contexts(&self) -> Vec<String> {
+        let mut contexts: Vec<String> = self.clients.read().await.keys().cloned().collect();
+        contexts.sort();
+        contexts
     }
 
     /// Initialize the client from the default kubeconfig.
@@ -216,6 +263,12 @@ impl KubeClientManager {
         let client = Client::try_from(config).context("Failed to create Kubernetes client")?;
 
         *self.kubeconfig.write().await = Some(parsed_config);
+        if let Some(ctx) = &current_ctx {
+            self.clients
+                .write()
+                .await
+                .insert(ctx.clone(), client.clone());
+        }
         *self.connection.write().await = Some((client, current_ctx));
 
         Ok(())
@@ -365,6 +418,10 @@ impl KubeClientManager {
             }
         };
 
+        self.clients
+            .write()
+            .await
+            .insert(context_name.to_string(), client.clone());
         *self.connection.write().await = Some((client, Some(context_name.to_string())));
 
         steps.push("Client stored in manager and ready for use".into());
@@ -407,6 +464,36 @@ impl KubeClientManager {
             .ok_or_else(|| anyhow::anyhow!("Kubernetes client not initialized"))
     }
 
+    /// Get the client for `context`, or the active client when `None`.
+    ///
+    /// Commands take an optional context so a view bound to one cluster keeps
+    /// talking to it while another cluster is active.
+    pub async fn get_client_for(&self, context: Option<&str>) -> Result<Client> {
+        match context {
+            None => self.get_client().await,
+            Some(ctx) => self
+                .clients
+                .read()
+                .await
+                .get(ctx)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Cluster context '{}' is not connected", ctx)),
+        }
+    }
+
+    /// Like [`Self::get_connection`], but for `context` when given. Returns
+    /// the resolved context name so sessions can be tagged with the cluster
+    /// they stream from.
+    pub async fn get_connection_for(&self, context: Option<&str>) -> Result<(Client, String)> {
+        match context {
+            None => self.get_connection().await,
+            Some(ctx) => {
+                let client = self.get_client_for(Some(ctx)).await?;
+                Ok((client, ctx.to_string()))
+            }
+        }
+    }
+
     /// Get the current context name
     pub async fn get_current_context(&self) -> Option<String> {
         self.connection
@@ -471,8 +558,8 @@ impl KubeClientManager {
 
     /// List all namespaces (metadata only - names are all we need) with a
     /// hard timeout so a hanging API server cannot stall the caller.
-    pub async fn list_namespaces(&self) -> Result<Vec<String>> {
-        let client = self.get_client().await?;
+    pub async fn list_namespaces(&self, context: Option<&str>) -> Result<Vec<String>> {
+        let client = self.get_client_for(context).await?;
         let namespaces: Api<Namespace> = Api::all(client);
 
         // Page through results (500/page) so huge clusters neither truncate
@@ -509,12 +596,15 @@ impl Default for KubeClientManager {
 /// Global client manager state
 pub struct AppState {
     pub k8s: KubeClientManager,
+    /// Shared informers behind watch and list commands
+    pub cache: Arc<ResourceCache>,
 }
 
 impl std::fmt::Debug for AppState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AppState")
             .field("k8s", &"KubeClientManager")
+            .field("cache", &"ResourceCache")
             .finish()
     }
 }
@@ -523,6 +613,7 @@ impl AppState {
     pub fn new() -> Self {
         Self {
             k8s: KubeClientManager::new(),
+            cache: Arc::new(ResourceCache::new()),
         }
     }
 }
@@ -897,4 +988,49 @@ users:
         assert!(manager.get_connection().await.is_err());
         assert!(manager.get_current_context().await.is_none());
     }
+
+    #[tokio::test]
+    async fn connecting_a_second_context_keeps_the_first_one_reachable() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let manager = KubeClientManager::new();
+        let kubeconfig = test_kubeconfig_two_contexts();
+
+        manager
+            .init_with_context("ctx-a", kubeconfig.clone(), None, None)
+            .await
+            .expect("client creation is offline");
+        manager
+            .init_with_context("ctx-b", kubeconfig, None, None)
+            .await
+            .expect("client creation is offline");
+
+        // ctx-b is active, ctx-a stays addressable by name
+        assert_eq!(
+            manager.get_current_context().await.as_deref(),
+            Some("ctx-b")
+        );
+        assert_eq!(manager.connected_contexts().await, vec!["ctx-a", "ctx-b"]);
+        let a = manager.get_client_for(Some("ctx-a")).await.unwrap();
+        assert_eq!(a.default_namespace(), "ns-a");
+        let active = manager.get_client_for(None).await.unwrap();
+        assert_eq!(active.default_namespace(), "ns-b");
+        assert!(manager.get_client_for(Some("ctx-c")).await.is_err());
+
+        // Switching back reuses the live client without reconnecting
+        assert!(manager.activate("ctx-a").await);
+        assert_eq!(
+            manager.get_current_context().await.as_deref(),
+            Some("ctx-a")
+        );
+
+        // Removing a background context leaves the active one connected
+        manager.remove_context("ctx-b").await;
+        assert!(manager.is_connected().await);
+        assert!(!manager.activate("ctx-b").await);
+
+        // Removing the active context disconnects
+        manager.remove_context("ctx-a").await;
+        assert!(!manager.is_connected().await);
+        assert!(manager.connected_contexts().await.is_empty());
+    }
 }
@@ -11,7 +11,9 @@ use super::store::{OidcTokenStore, OidcTokens};
 pub struct OidcState {
     pub flow_manager: OidcFlowManager,
     pub token_store: OidcTokenStore,
-    pub refresh_stop: std::sync::Mutex<Arc<AtomicBool>>,
+    /// Stop flag of each context's background refresh task, so tearing down
+    /// one connected context leaves the refresh of the others running.
+    pub refresh_stop: std::sync::Mutex<HashMap<String, Arc<AtomicBool>>>,
     /// Serializes token refreshes so concurrent paths (interactive auth, connect,
     /// and the background refresh loop) cannot double-consume a rotating refresh
     /// token. See [`OidcState::refresh`].
@@ -24,29 +26,33 @@ pub struct OidcState {
 }
 
 impl OidcState {
-    /// Signal any running refresh task to stop and install a fresh stop flag,
-    /// atomically under a single lock. Returns the new flag for the task that is
-    /// about to be spawned to observe. This closes the cancel-then-arm TOCTOU
-    /// window that a separate cancel + read pair would leave open.
-    pub fn arm_refresh(&self) -> Arc<AtomicBool> {
+    /// Signal the running refresh task of `context` to stop and install a
+    /// fresh stop flag, atomically under a single lock. Returns the new flag
+    /// for the task that is about to be spawned to observe. This closes the
+    /// cancel-then-arm TOCTOU window that a separate cancel + read pair would
+    /// leave open.
+    pub fn arm_refresh(&self, context: &str) -> Arc<AtomicBool> {
         let mut guard = self
             .refresh_stop
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        guard.store(true, Ordering::Relaxed);
         let fresh = Arc::new(AtomicBool::new(false));
-        *guard = Arc::clone(&fresh);
+        if let Some(previous) = guard.insert(context.to_string(), Arc::clone(&fresh)) {
+            previous.store(true, Ordering::Relaxed);
+        }
         fresh
     }
 
-    /// Signal any running refresh task to stop (used on disconnect).
-    pub fn cancel_refresh(&self) {
+    /// Signal the refresh task of `context` to stop (used on disconnect).
+    /// Refresh tasks of other contexts keep running.
+    pub fn cancel_refresh(&self, context: &str) {
         let mut guard = self
             .refresh_stop
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        guard.store(true, Ordering::Relaxed);
-        *guard = Arc::new(AtomicBool::new(false));
+        if let Some(flag) = guard.remove(context) {
+            flag.store(true, Ordering::Relaxed);
+        }
     }
 
     /// Remember the full exec config (TLS/CA settings included) detected from the
@@ -160,7 +166,7 @@ impl Default for OidcState {
         Self {
             flow_manager: OidcFlowManager::default(),
             token_store: OidcTokenStore::default(),
-            refresh_stop: std::sync::Mutex::new(Arc::new(AtomicBool::new(false))),
+            refresh_stop: std::sync::Mutex::new(HashMap::new()),
             refresh_lock: tokio::sync::Mutex::new(()),
             configs: std::sync::Mutex::new(HashMap::new()),
         }
@@ -305,7 +311,7 @@ mod tests {
     #[test]
     fn cancel_refresh_recovers_from_poisoned_mutex() {
         let state = OidcState::default();
-        let flag = state.arm_refresh();
+        let flag = state.arm_refresh("dev");
 
         // Poison refresh_stop by panicking while holding the lock.
         let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -319,7 +325,23 @@ mod tests {
 
         // Regression: cancel_refresh used `if let Ok(...)`, silently doing
         // nothing on poison — the running refresh task was never signalled.
-        state.cancel_refresh();
+        state.cancel_refresh("dev");
         assert!(flag.load(Ordering::Relaxed), "stop flag must be signalled");
     }
+
+    #[test]
+    fn cancel_refresh_only_stops_the_given_context() {
+        let state = OidcState::default();
+        let dev = state.arm_refresh("dev");
+        let prod = state.arm_refresh("prod");
+
+        state.cancel_refresh("dev");
+        assert!(dev.load(Ordering::Relaxed));
+        assert!(!prod.load(Ordering::Relaxed), "prod keeps refreshing");
+
+        // Re-arming a context stops its previous task
+        let prod_again = state.arm_refresh("prod");
+        assert!(prod.load(Ordering::Relaxed));
+        assert!(!prod_again.load(Ordering::Relaxed));
+    }
 }
@@ -35,7 +35,6 @@ import { toast } from "sonner";
 import { parseOwnerReferences } from "@/lib/utils/parse-owner-references";
 import {
   getResourceYaml,
-  applyResourceYaml,
   deleteResource,
   listEvents,
   aiCheckCliAvailable,
@@ -44,6 +43,8 @@ import {
 } from "@/lib/tauri/commands";
 import { getErrorMessage } from "@/lib/types/errors";
 import { parseTemplateContainers } from "../resources/lib/utils";
+import { FieldConflictDialog } from "../resources/dialogs/FieldConflictDialog";
+import { useConflictAwareApply } from "../resources/hooks/useConflictAwareApply";
 
 import {
   ResourceDetailContext,
@@ -129,6 +130,11 @@ function DashboardContent() {
   const [scaleDialog, setScaleDialog] = useState<ScaleDialogState | null>(null);
   const [setImageDialog, setSetImageDialog] = useState<SetImageDialogState | null>(null);
   const [showShortcutsHelp, setShowShortcutsHelp] = useState(false);
+  const {
+    apply: applyResourceYaml,
+    conflicts: applyConflicts,
+    resolveConflicts,
+  } = useConflictAwareApply();
   const getFavorites = useFavoritesStore((s) => s.getFavorites);
   const removeFavorite = useFavoritesStore((s) => s.removeFavorite);
   const setSettingsOpen = useUIStore((s) => s.setSettingsOpen);
@@ -623,6 +629,7 @@ function DashboardContent() {
         <ScaleDeploymentDialog state={scaleDialog} onClose={() => setScaleDialog(null)} />
         <SetImageDialog state={setImageDialog} onClose={() => setSetImageDialog(null)} />
         <ShortcutsHelpDialog open={showShortcutsHelp} onOpenChange={setShowShortcutsHelp} />
+        <FieldConflictDialog conflicts={applyConflicts} onResolve={resolveConflicts} />
       </div>
     </ResourceDetailContext.Provider>
   );
@@ -1,5 +1,7 @@
 "use client";
 
+import { useState } from "react";
+import { listen } from "@tauri-apps/api/event";
 import { useTranslations } from "next-intl";
 import { toast } from "sonner";
 import {
@@ -12,7 +14,10 @@ import {
   AlertDialogHeader,
   AlertDialogTitle,
 } from "@/components/ui/alert-dialog";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Label } from "@/components/ui/label";
 import { uninstallHelmRelease } from "@/lib/tauri/commands";
+import type { HelmUninstallEvent } from "@/lib/types";
 
 export interface UninstallDialogState {
   open: boolean;
@@ -28,18 +33,55 @@ interface UninstallHelmDialogProps {
 
 export function UninstallHelmDialog({ state, onClose }: UninstallHelmDialogProps) {
   const t = useTranslations();
+  const [keepHistory, setKeepHistory] = useState(false);
 
   const handleUninstall = async () => {
     if (!state) return;
+    const operationId = `${state.namespace}-${state.name}-${Date.now()}`;
+    const progressToast = toast.loading(`Uninstalling ${state.name}...`);
+    let total = 0;
+    let done = 0;
+    const unlisten = await listen<HelmUninstallEvent>(
+      `helm-uninstall-${operationId}`,
+      (event) => {
+        const progress = event.payload;
+        if (progress.type === "Started") {
+          total = progress.data.total;
+        } else if (progress.type === "Resource") {
+          done += 1;
+          toast.loading(`Uninstalling ${state.name} (${done}/${total})`, {
+            id: progressToast,
+          });
+        }
+      }
+    );
     try {
-      await uninstallHelmRelease(state.name, state.namespace);
-      toast.success("Release forgotten", {
-        description: `${state.name} removed from Helm history. Deployed resources are still running.`,
+      const result = await uninstallHelmRelease(state.name, state.namespace, {
+        keep_history: keepHistory,
+        operation_id: operationId,
       });
+      const failed = result.resources.filter((r) => r.outcome === "failed");
+      const kept = result.resources.filter((r) => r.outcome === "kept");
+      if (failed.length > 0) {
+        toast.error("Uninstall incomplete", {
+          description: `${failed.length} resource(s) of ${state.name} could not be deleted. The release is marked failed; uninstall again to retry.`,
+        });
+      } else {
+        toast.success("Release uninstalled", {
+          description:
+            kept.length > 0
+              ? `${state.name} removed. ${kept.length} resource(s) kept by helm.sh/resource-policy.`
+              : `${state.name} and its resources were removed.`,
+        });
+      }
       state.onSuccess?.();
     } catch (e) {
       toast.error("Failed to uninstall", { description: String(e) });
+    } finally {
+      unlisten();
+      toast.dismiss(progressToast);
     }
+    setKeepHistory(false);
     onClose();
   };
 
@@ -47,27 +89,39 @@ export function UninstallHelmDialog({ state, onClose }: UninstallHelmDialogProps
     <AlertDialog open={state?.open} onOpenChange={(open) => !open && onClose()}>
       <AlertDialogContent>
         <AlertDialogHeader>
-          <AlertDialogTitle>Forget Helm Release?</AlertDialogTitle>
+          <AlertDialogTitle>Uninstall Helm Release?</AlertDialogTitle>
           <AlertDialogDescription>
-            This deletes the Helm release records (secrets) for{" "}
-            <strong>{state?.name}</strong>
+            This deletes every resource of <strong>{state?.name}</strong>
             {state?.namespace && (
               <>
                 {" "}
                 ({t("cluster.namespace")}: <strong>{state.namespace}</strong>)
               </>
-            )}
-            , so Helm no longer tracks it. The deployed resources stay in the
-            cluster - delete them individually if you want them gone.
+            )}{" "}
+            except those annotated <code>helm.sh/resource-policy: keep</code>,
+            then removes its release history. Hooks are not run.
           </AlertDialogDescription>
         </AlertDialogHeader>
+        <div className="flex items-center space-x-2 py-2">
+          <Checkbox
+            id="keep-history"
+            checked={keepHistory}
+            onCheckedChange={(checked) => setKeepHistory(checked === true)}
+          />
+          <Label
+            htmlFor="keep-history"
+            className="text-sm text-muted-foreground cursor-pointer"
+          >
+            Keep release history (mark as uninstalled)
+          </Label>
+        </div>
         <AlertDialogFooter>
           <AlertDialogCancel>{t("common.cancel")}</AlertDialogCancel>
           <AlertDialogAction
             onClick={handleUninstall}
             className="bg-destructive text-white hover:bg-destructive/90"
           >
-            Forget release
+            {t("flux.uninstallRelease")}
           </AlertDialogAction>
         </AlertDialogFooter>
       </AlertDialogContent>
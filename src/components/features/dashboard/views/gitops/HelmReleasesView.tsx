@@ -209,7 +209,7 @@ export function HelmReleasesView() {
       });
     } else {
       items.push({
-        label: t("flux.forgetRelease"),
+        label: t("flux.uninstallRelease"),
         icon: <Trash2 className="size-4" />,
         onClick: () => handleUninstallFromContext(release.name, release.namespace, refresh),
         variant: "destructive",
@@ -193,7 +193,7 @@ describe("HelmReleasesView Flux actions", () => {
     const items = capturedContextMenuItems!(mockData[0]);
 
     expect(items.find((i) => i.label === "flux.reconcile")).toBeUndefined();
-    expect(items.find((i) => i.label === "flux.forgetRelease")).toBeDefined();
+    expect(items.find((i) => i.label === "flux.uninstallRelease")).toBeDefined();
   });
 
   it("disables all reconcile variants while one is in flight", async () => {
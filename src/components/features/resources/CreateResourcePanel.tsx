@@ -24,9 +24,10 @@ import {
   ContextMenuItem,
 } from "@/components/ui/context-menu";
 import { DiscardChangesDialog } from "./dialogs/DiscardChangesDialog";
+import { FieldConflictDialog } from "./dialogs/FieldConflictDialog";
+import { useConflictAwareApply } from "./hooks/useConflictAwareApply";
 import { useTranslations } from "next-intl";
 import { useUIStore } from "@/lib/stores/ui-store";
-import { applyResourceYaml } from "@/lib/tauri/commands";
 import { toast } from "sonner";
 import { k8sTemplates, getTemplatesByCategory, type K8sTemplate } from "@/lib/templates/k8s-templates";
 
@@ -64,6 +65,7 @@ export function CreateResourcePanel({ onClose, onApplied }: CreateResourcePanelP
   const [error, setError] = useState<string | null>(null);
   const [showDiscardDialog, setShowDiscardDialog] = useState(false);
   const [lintErrors, setLintErrors] = useState<LintError[]>([]);
+  const { apply: applyResourceYaml, conflicts, resolveConflicts } = useConflictAwareApply();
   const [showLintPanel, setShowLintPanel] = useState(false);
 
   const hasChanges = yamlContent !== templateYaml;
@@ -133,7 +135,7 @@ export function CreateResourcePanel({ onClose, onApplied }: CreateResourcePanelP
     } finally {
       setIsApplying(false);
     }
-  }, [yamlContent, hasLintErrors, t, onApplied, onClose]);
+  }, [yamlContent, hasLintErrors, applyResourceYaml, t, onApplied, onClose]);
 
   // Ref so Monaco addCommand always sees the latest handleApply
   const handleApplyRef = useRef(handleApply);
@@ -207,6 +209,7 @@ export function CreateResourcePanel({ onClose, onApplied }: CreateResourcePanelP
         const target = e.target as HTMLElement;
         if (target.closest("[data-radix-select-content]")) return;
         if (target.closest(".monaco-editor")) return;
+        if (target.closest("[role='alertdialog']")) return;
         requestCloseRef.current();
       }
     };
@@ -424,6 +427,7 @@ export function CreateResourcePanel({ onClose, onApplied }: CreateResourcePanelP
         onOpenChange={setShowDiscardDialog}
         onConfirm={handleConfirmDiscard}
       />
+      <FieldConflictDialog conflicts={conflicts} onResolve={resolveConflicts} />
     </div>
   );
 }
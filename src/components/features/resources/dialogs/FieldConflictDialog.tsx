@@ -0,0 +1,71 @@
+"use client";
+
+import {
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from "@/components/ui/alert-dialog";
+import { buttonVariants } from "@/components/ui/button";
+import type { FieldConflict } from "@/lib/types/errors";
+import { useTranslations } from "next-intl";
+import type { ConflictChoice } from "../hooks/useConflictAwareApply";
+
+interface FieldConflictDialogProps {
+  /** Open while set */
+  conflicts: FieldConflict[] | null;
+  /** The user's choice, or null when the apply is cancelled */
+  onResolve: (choice: ConflictChoice | null) => void;
+}
+
+export function FieldConflictDialog({ conflicts, onResolve }: FieldConflictDialogProps) {
+  const t = useTranslations();
+
+  return (
+    <AlertDialog
+      open={conflicts !== null}
+      onOpenChange={(open) => {
+        if (!open) onResolve(null);
+      }}
+    >
+      <AlertDialogContent>
+        <AlertDialogHeader>
+          <AlertDialogTitle>{t("resourceDetail.conflictsTitle")}</AlertDialogTitle>
+          <AlertDialogDescription>
+            {t("resourceDetail.conflictsDescription")}
+          </AlertDialogDescription>
+        </AlertDialogHeader>
+        <ul className="max-h-48 space-y-1 overflow-y-auto rounded-md border border-border bg-muted/50 p-2 text-xs">
+          {conflicts?.map((conflict) => (
+            <li
+              key={`${conflict.manager}:${conflict.field}`}
+              className="flex items-center justify-between gap-3 font-mono"
+            >
+              <span className="truncate">{conflict.field}</span>
+              <span className="shrink-0 text-muted-foreground">{conflict.manager}</span>
+            </li>
+          ))}
+        </ul>
+        <AlertDialogFooter>
+          <AlertDialogCancel>{t("common.cancel")}</AlertDialogCancel>
+          <AlertDialogAction
+            onClick={() => onResolve("drop")}
+            className={buttonVariants({ variant: "secondary" })}
+          >
+            {t("resourceDetail.conflictsDrop")}
+          </AlertDialogAction>
+          <AlertDialogAction
+            onClick={() => onResolve("force")}
+            className="bg-destructive text-white hover:bg-destructive/90"
+          >
+            {t("resourceDetail.conflictsForce")}
+          </AlertDialogAction>
+        </AlertDialogFooter>
+      </AlertDialogContent>
+    </AlertDialog>
+  );
+}
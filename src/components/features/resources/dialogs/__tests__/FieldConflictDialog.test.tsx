@@ -0,0 +1,116 @@
+import { useState } from "react";
+import { render, screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { getErrorMessage } from "@/lib/types/errors";
+import { FieldConflictDialog } from "../FieldConflictDialog";
+import { useConflictAwareApply } from "../../hooks/useConflictAwareApply";
+
+jest.mock("next-intl", () => ({
+  useTranslations: () => (key: string) => key,
+}));
+
+const mockApplyResourceYaml = jest.fn();
+jest.mock("@/lib/tauri/commands", () => ({
+  applyResourceYaml: (...args: unknown[]) => mockApplyResourceYaml(...args),
+}));
+
+const conflictError = {
+  kind: "Conflict",
+  code: 409,
+  message: "Apply conflicts with other field managers",
+  suggestions: ["Force the apply to take ownership of these fields"],
+  retryable: false,
+  conflicts: [
+    {
+      field: ".spec.replicas",
+      manager: "kubectl-client-side-apply",
+      message: 'conflict with "kubectl-client-side-apply": .spec.replicas',
+    },
+  ],
+};
+
+const YAML = "apiVersion: apps/v1\nkind: Deployment\nmetadata:\n  name: web\n";
+
+/** Saves the way the resource editor and create panel do */
+function Harness() {
+  const { apply, conflicts, resolveConflicts } = useConflictAwareApply();
+  const [status, setStatus] = useState("idle");
+
+  const save = async () => {
+    try {
+      await apply(YAML);
+      setStatus("saved");
+    } catch (err) {
+      setStatus(getErrorMessage(err));
+    }
+  };
+
+  return (
+    <>
+      <button onClick={save}>save</button>
+      <span data-testid="status">{status}</span>
+      <FieldConflictDialog conflicts={conflicts} onResolve={resolveConflicts} />
+    </>
+  );
+}
+
+beforeEach(() => {
+  jest.clearAllMocks();
+});
+
+describe("FieldConflictDialog", () => {
+  it.each([
+    ["resourceDetail.conflictsForce", "force"],
+    ["resourceDetail.conflictsDrop", "drop"],
+  ])("lists the conflicts and retries with %s", async (button, resolution) => {
+    const user = userEvent.setup();
+    mockApplyResourceYaml.mockRejectedValueOnce(conflictError).mockResolvedValueOnce("ok");
+    render(<Harness />);
+
+    await user.click(screen.getByRole("button", { name: "save" }));
+
+    expect(await screen.findByText(".spec.replicas")).toBeInTheDocument();
+    expect(screen.getByText("kubectl-client-side-apply")).toBeInTheDocument();
+
+    await user.click(screen.getByRole("button", { name: button }));
+
+    await waitFor(() => {
+      expect(screen.getByTestId("status")).toHaveTextContent("saved");
+    });
+    expect(mockApplyResourceYaml).toHaveBeenCalledTimes(2);
+    expect(mockApplyResourceYaml).toHaveBeenNthCalledWith(1, YAML);
+    expect(mockApplyResourceYaml).toHaveBeenNthCalledWith(2, YAML, resolution);
+  });
+
+  it("reports the conflict without retrying when cancelled", async () => {
+    const user = userEvent.setup();
+    mockApplyResourceYaml.mockRejectedValueOnce(conflictError);
+    render(<Harness />);
+
+    await user.click(screen.getByRole("button", { name: "save" }));
+    await user.click(await screen.findByRole("button", { name: "common.cancel" }));
+
+    await waitFor(() => {
+      expect(screen.getByTestId("status")).toHaveTextContent(
+        "Apply conflicts with other field managers"
+      );
+    });
+    expect(mockApplyResourceYaml).toHaveBeenCalledTimes(1);
+    expect(screen.queryByText(".spec.replicas")).not.toBeInTheDocument();
+  });
+
+  it("passes other errors through without asking", async () => {
+    const user = userEvent.setup();
+    mockApplyResourceYaml.mockRejectedValueOnce({ ...conflictError, kind: "Forbidden", conflicts: undefined });
+    render(<Harness />);
+
+    await user.click(screen.getByRole("button", { name: "save" }));
+
+    await waitFor(() => {
+      expect(screen.getByTestId("status")).toHaveTextContent(
+        "Apply conflicts with other field managers"
+      );
+    });
+    expect(screen.queryByRole("alertdialog")).not.toBeInTheDocument();
+  });
+});
@@ -1,3 +1,4 @@
 export { DeleteResourceDialog } from "./DeleteResourceDialog";
 export { BulkDeleteDialog } from "./BulkDeleteDialog";
 export { DiscardChangesDialog } from "./DiscardChangesDialog";
+export { FieldConflictDialog } from "./FieldConflictDialog";
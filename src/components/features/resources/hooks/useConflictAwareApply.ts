@@ -0,0 +1,43 @@
+import { useCallback, useEffect, useRef, useState } from "react";
+import { applyResourceYaml, type ConflictResolution } from "@/lib/tauri/commands";
+import { toKubeliError, type FieldConflict } from "@/lib/types/errors";
+
+export type ConflictChoice = Exclude<ConflictResolution, "abort">;
+
+/**
+ * Applies YAML without forcing. When other field managers (kubectl, Helm,
+ * an HPA...) own fields the YAML sets, their conflicts are exposed for
+ * `FieldConflictDialog` and the apply is retried with the user's choice.
+ * Cancelling the dialog rethrows the original conflict error.
+ */
+export function useConflictAwareApply() {
+  const [conflicts, setConflicts] = useState<FieldConflict[] | null>(null);
+  const chooseRef = useRef<((choice: ConflictChoice | null) => void) | null>(null);
+
+  // Never leave a save waiting on a dialog that is gone
+  useEffect(() => () => chooseRef.current?.(null), []);
+
+  const apply = useCallback(async (yaml: string) => {
+    try {
+      return await applyResourceYaml(yaml);
+    } catch (err) {
+      const error = toKubeliError(err);
+      if (error.kind !== "Conflict" || !error.conflicts?.length) throw err;
+
+      const choice = await new Promise<ConflictChoice | null>((resolve) => {
+        chooseRef.current = resolve;
+        setConflicts(error.conflicts ?? []);
+      });
+      chooseRef.current = null;
+      setConflicts(null);
+      if (!choice) throw err;
+      return applyResourceYaml(yaml, choice);
+    }
+  }, []);
+
+  const resolveConflicts = useCallback((choice: ConflictChoice | null) => {
+    chooseRef.current?.(choice);
+  }, []);
+
+  return { apply, conflicts, resolveConflicts };
+}
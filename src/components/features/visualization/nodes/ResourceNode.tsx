@@ -22,18 +22,27 @@ interface ResourceNodeProps {
   data: ResourceNodeData;
 }
 
-// Simplified icons for 3 node types
-const nodeIcons: Record<GraphNodeType, React.ReactNode> = {
+const nodeIcons: Partial<Record<GraphNodeType, React.ReactNode>> = {
   namespace: <Layers className="size-3.5" />,
-  deployment: <Box className="size-3.5" />,
-  pod: <Box className="size-3.5" />,
 };
 
-// Simplified labels for 3 node types
 const nodeLabels: Record<GraphNodeType, string> = {
   namespace: "NS",
   deployment: "Deploy",
+  statefulset: "STS",
+  daemonset: "DS",
+  replicaset: "RS",
+  job: "Job",
+  cronjob: "CronJob",
   pod: "Pod",
+  service: "Svc",
+  ingress: "Ing",
+  endpointslice: "EPS",
+  configmap: "CM",
+  secret: "Secret",
+  persistentvolumeclaim: "PVC",
+  persistentvolume: "PV",
+  serviceaccount: "SA",
 };
 
 const statusColors: Record<GraphNodeStatus, string> = {
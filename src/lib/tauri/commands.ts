@@ -1,9 +1,13 @@
 export * from "./commands/cluster";
 export * from "./commands/app";
 export * from "./commands/resources";
+export * from "./commands/bulk";
+export * from "./commands/rollout";
+export * from "./commands/drain";
 export * from "./commands/watch";
 export * from "./commands/logs";
 export * from "./commands/shell";
+export * from "./commands/fileCopy";
 export * from "./commands/portforward";
 export * from "./commands/metrics";
 export * from "./commands/graph";
@@ -4,7 +4,10 @@ jest.mock("../core", () => ({
 
 import * as appCommands from "../app";
 import * as ai from "../ai";
+import * as bulk from "../bulk";
 import * as cluster from "../cluster";
+import * as drain from "../drain";
+import * as fileCopy from "../fileCopy";
 import * as flux from "../flux";
 import * as graph from "../graph";
 import * as helm from "../helm";
@@ -14,6 +17,7 @@ import * as metrics from "../metrics";
 import * as network from "../network";
 import * as portforward from "../portforward";
 import * as resources from "../resources";
+import * as rollout from "../rollout";
 import * as shell from "../shell";
 import * as watch from "../watch";
 import * as argocd from "../argocd";
@@ -31,8 +35,13 @@ type TestCase = {
 
 const resourceOptions = { namespace: "kubeli-demo", limit: 10 };
 const logOptions = { namespace: "default", pod_name: "demo", tail_lines: 50 };
+const aggregatedLogOptions = { namespace: "default", workload: { kind: "Deployment", name: "web" }, tail_lines: 50 };
+const widgetQuery = { group: "example.com", version: "v1", kind: "Widget", namespace: "default" };
 const portOptions = { namespace: "default", pod_name: "demo", local_port: 8080 };
+const profile = { name: "api", context: "prod", namespace: "default", target: "api", target_type: "service", ports: [{ port: "http" }] };
 const shellOptions = { namespace: "default", pod_name: "demo", container: "app", command: ["sh"] };
+const copyOptions = { namespace: "default", pod_name: "demo", container: "app", local_path: "/tmp/app.log", remote_path: "/var/log/app.log", context: "prod" };
+const debugOptions = { namespace: "default", pod_name: "demo", image: "busybox:1.36", target_container: "app", context: "prod" };
 
 const cases: TestCase[] = [
   { name: "restartApp", run: () => appCommands.restartApp(), expectedCommand: "restart_app" },
@@ -40,10 +49,11 @@ const cases: TestCase[] = [
   { name: "quitApp", run: () => appCommands.quitApp(), expectedCommand: "quit_app" },
   { name: "listClusters", run: () => cluster.listClusters(), expectedCommand: "list_clusters" },
   { name: "connectCluster", run: () => cluster.connectCluster("ctx"), expectedCommand: "connect_cluster", expectedPayload: { context: "ctx" } },
-  { name: "disconnectCluster", run: () => cluster.disconnectCluster(), expectedCommand: "disconnect_cluster" },
+  { name: "disconnectCluster", run: () => cluster.disconnectCluster(), expectedCommand: "disconnect_cluster", expectedPayload: {} },
+  { name: "listConnectedContexts", run: () => cluster.listConnectedContexts(), expectedCommand: "list_connected_contexts" },
   { name: "getConnectionStatus", run: () => cluster.getConnectionStatus(), expectedCommand: "get_connection_status" },
   { name: "checkConnectionHealth", run: () => cluster.checkConnectionHealth(), expectedCommand: "check_connection_health" },
-  { name: "getNamespaces", run: () => cluster.getNamespaces(), expectedCommand: "get_namespaces" },
+  { name: "getNamespaces", run: () => cluster.getNamespaces(), expectedCommand: "get_namespaces", expectedPayload: {} },
   { name: "getClusterSettings", run: () => cluster.getClusterSettings("ctx"), expectedCommand: "get_cluster_settings", expectedPayload: { context: "ctx" } },
   { name: "setClusterAccessibleNamespaces", run: () => cluster.setClusterAccessibleNamespaces("ctx", ["a", "b"]), expectedCommand: "set_cluster_accessible_namespaces", expectedPayload: { context: "ctx", namespaces: ["a", "b"] } },
   { name: "setClusterPreferKubeconfigAuth", run: () => cluster.setClusterPreferKubeconfigAuth("ctx", true), expectedCommand: "set_cluster_prefer_kubeconfig_auth", expectedPayload: { context: "ctx", prefer: true } },
@@ -75,13 +85,25 @@ const cases: TestCase[] = [
   { name: "oidcStartAuth", run: () => oidc.oidcStartAuth("https://issuer", "client", ["openid"]), expectedCommand: "oidc_start_auth", expectedPayload: { issuerUrl: "https://issuer", clientId: "client", extraScopes: ["openid"] } },
   { name: "oidcHandleCallback", run: () => oidc.oidcHandleCallback("code123", "state456"), expectedCommand: "oidc_handle_callback", expectedPayload: { code: "code123", state: "state456" } },
   { name: "generateResourceGraph", run: () => graph.generateResourceGraph(["default"]), expectedCommand: "generate_resource_graph", expectedPayload: { namespaces: ["default"] } },
+  { name: "watchResourceGraph", run: () => graph.watchResourceGraph("graph-1", ["default"]), expectedCommand: "watch_resource_graph", expectedPayload: { watchId: "graph-1", namespaces: ["default"] } },
+  { name: "exportResourceGraph", run: () => graph.exportResourceGraph({ nodes: [], edges: [], errors: [] }, "mermaid"), expectedCommand: "export_resource_graph", expectedPayload: { graph: { nodes: [], edges: [], errors: [] }, format: "mermaid" } },
   { name: "listHelmReleases", run: () => helm.listHelmReleases("default"), expectedCommand: "list_helm_releases", expectedPayload: { namespace: "default" } },
   { name: "uninstallHelmRelease", run: () => helm.uninstallHelmRelease("demo", "default"), expectedCommand: "uninstall_helm_release", expectedPayload: { name: "demo", namespace: "default" } },
+  { name: "rollbackHelmRelease", run: () => helm.rollbackHelmRelease("demo", "default", 2), expectedCommand: "rollback_helm_release", expectedPayload: { name: "demo", namespace: "default", revision: 2 } },
+  { name: "diffHelmRevisions", run: () => helm.diffHelmRevisions("demo", "default", 1, 2), expectedCommand: "diff_helm_revisions", expectedPayload: { name: "demo", namespace: "default", fromRevision: 1, toRevision: 2 } },
+  { name: "installHelmChart", run: () => helm.installHelmChart("./charts/demo", "demo", "default"), expectedCommand: "install_helm_chart", expectedPayload: { chartPath: "./charts/demo", name: "demo", namespace: "default" } },
+  { name: "upgradeHelmRelease", run: () => helm.upgradeHelmRelease("demo", "default", "./charts/demo", { reuse_values: true }), expectedCommand: "upgrade_helm_release", expectedPayload: { name: "demo", namespace: "default", chartPath: "./charts/demo", options: { reuse_values: true } } },
   { name: "getPodLogs", run: () => logs.getPodLogs(logOptions as never), expectedCommand: "get_pod_logs", expectedPayload: { options: logOptions } },
   { name: "streamPodLogs", run: () => logs.streamPodLogs("stream-1", logOptions as never), expectedCommand: "stream_pod_logs", expectedPayload: { streamId: "stream-1", options: logOptions } },
+  { name: "streamAggregatedLogs", run: () => logs.streamAggregatedLogs("stream-2", aggregatedLogOptions), expectedCommand: "stream_aggregated_logs", expectedPayload: { streamId: "stream-2", options: aggregatedLogOptions } },
   { name: "stopLogStream", run: () => logs.stopLogStream("stream-1"), expectedCommand: "stop_log_stream", expectedPayload: { streamId: "stream-1" } },
   { name: "getPodContainers", run: () => logs.getPodContainers("default", "demo"), expectedCommand: "get_pod_containers", expectedPayload: { namespace: "default", podName: "demo" } },
   { name: "downloadPodLogs", run: () => logs.downloadPodLogs(logOptions as never), expectedCommand: "download_pod_logs", expectedPayload: { options: logOptions } },
+  { name: "searchLogArchive", run: () => logs.searchLogArchive({ pod: "demo", text: "timeout" }), expectedCommand: "search_log_archive", expectedPayload: { query: { pod: "demo", text: "timeout" } } },
+  { name: "listLogArchiveSources", run: () => logs.listLogArchiveSources("prod"), expectedCommand: "list_log_archive_sources", expectedPayload: { context: "prod" } },
+  { name: "getLogArchiveStats", run: () => logs.getLogArchiveStats(), expectedCommand: "get_log_archive_stats" },
+  { name: "setLogArchiveMaxSize", run: () => logs.setLogArchiveMaxSize(1048576), expectedCommand: "set_log_archive_max_size", expectedPayload: { maxBytes: 1048576 } },
+  { name: "clearLogArchive", run: () => logs.clearLogArchive("prod", "default", "demo"), expectedCommand: "clear_log_archive", expectedPayload: { context: "prod", namespace: "default", pod: "demo" } },
   { name: "mcpDetectIdes", run: () => mcp.mcpDetectIdes(), expectedCommand: "mcp_detect_ides" },
   { name: "mcpInstallIde", run: () => mcp.mcpInstallIde("cursor"), expectedCommand: "mcp_install_ide", expectedPayload: { ideId: "cursor" } },
   { name: "mcpUninstallIde", run: () => mcp.mcpUninstallIde("cursor"), expectedCommand: "mcp_uninstall_ide", expectedPayload: { ideId: "cursor" } },
@@ -89,8 +111,8 @@ const cases: TestCase[] = [
   { name: "getNodeMetrics", run: () => metrics.getNodeMetrics("minikube"), expectedCommand: "get_node_metrics", expectedPayload: { nodeName: "minikube" } },
   { name: "getPodMetrics", run: () => metrics.getPodMetrics("default", "demo"), expectedCommand: "get_pod_metrics", expectedPayload: { namespace: "default", podName: "demo" } },
   { name: "getPodMetricsDirect", run: () => metrics.getPodMetricsDirect("default"), expectedCommand: "get_pod_metrics_direct", expectedPayload: { namespace: "default" } },
-  { name: "getClusterMetricsSummary", run: () => metrics.getClusterMetricsSummary(), expectedCommand: "get_cluster_metrics_summary" },
-  { name: "checkMetricsServer", run: () => metrics.checkMetricsServer(), expectedCommand: "check_metrics_server" },
+  { name: "getClusterMetricsSummary", run: () => metrics.getClusterMetricsSummary(), expectedCommand: "get_cluster_metrics_summary", expectedPayload: {} },
+  { name: "checkMetricsServer", run: () => metrics.checkMetricsServer(), expectedCommand: "check_metrics_server", expectedPayload: {} },
   { name: "setProxyConfig", run: () => network.setProxyConfig("http", "localhost", 3128, "user", "pass"), expectedCommand: "set_proxy_config", expectedPayload: { proxyType: "http", host: "localhost", port: 3128, username: "user", password: "pass" } },
   // Regression: proxy settings UI must reach the backend (was a dead binding)
   { name: "applyProxyFromSettings", run: () => network.applyProxyFromSettings({ proxyType: "socks5", proxyHost: "127.0.0.1", proxyPort: 1080, proxyUsername: "", proxyPassword: "" }), expectedCommand: "set_proxy_config", expectedPayload: { proxyType: "socks5", host: "127.0.0.1", port: 1080, username: "", password: "" } },
@@ -98,10 +120,18 @@ const cases: TestCase[] = [
   { name: "portforwardStop", run: () => portforward.portforwardStop("forward-1"), expectedCommand: "portforward_stop", expectedPayload: { forwardId: "forward-1" } },
   { name: "portforwardList", run: () => portforward.portforwardList(), expectedCommand: "portforward_list" },
   { name: "portforwardCheckPort", run: () => portforward.portforwardCheckPort(8080), expectedCommand: "portforward_check_port", expectedPayload: { port: 8080 } },
+  { name: "portforwardProfilesList", run: () => portforward.portforwardProfilesList(), expectedCommand: "portforward_profiles_list" },
+  { name: "portforwardProfileSave", run: () => portforward.portforwardProfileSave(profile as never), expectedCommand: "portforward_profile_save", expectedPayload: { profile } },
+  { name: "portforwardProfileDelete", run: () => portforward.portforwardProfileDelete("api"), expectedCommand: "portforward_profile_delete", expectedPayload: { name: "api" } },
+  { name: "portforwardProfileStart", run: () => portforward.portforwardProfileStart("api"), expectedCommand: "portforward_profile_start", expectedPayload: { name: "api" } },
+  { name: "portforwardProfileStartGroup", run: () => portforward.portforwardProfileStartGroup("morning"), expectedCommand: "portforward_profile_start_group", expectedPayload: { group: "morning" } },
   { name: "shellStart", run: () => shell.shellStart("shell-1", shellOptions as never), expectedCommand: "shell_start", expectedPayload: { sessionId: "shell-1", options: shellOptions } },
   { name: "shellSendInput", run: () => shell.shellSendInput("shell-1", "ls\n"), expectedCommand: "shell_send_input", expectedPayload: { sessionId: "shell-1", input: "ls\n" } },
   { name: "shellResize", run: () => shell.shellResize("shell-1", 120, 40), expectedCommand: "shell_resize", expectedPayload: { sessionId: "shell-1", cols: 120, rows: 40 } },
   { name: "shellClose", run: () => shell.shellClose("shell-1"), expectedCommand: "shell_close", expectedPayload: { sessionId: "shell-1" } },
+  { name: "debugContainerStart", run: () => shell.debugContainerStart("debug-1", debugOptions), expectedCommand: "debug_container_start", expectedPayload: { sessionId: "debug-1", options: debugOptions } },
+  { name: "copyToContainer", run: () => fileCopy.copyToContainer("copy-1", copyOptions), expectedCommand: "copy_to_container", expectedPayload: { transferId: "copy-1", options: copyOptions } },
+  { name: "copyFromContainer", run: () => fileCopy.copyFromContainer("copy-1", copyOptions), expectedCommand: "copy_from_container", expectedPayload: { transferId: "copy-1", options: copyOptions } },
   { name: "watchPods", run: () => watch.watchPods("watch-1", "default"), expectedCommand: "watch_pods", expectedPayload: { watchId: "watch-1", namespace: "default" } },
   { name: "watchDeployments", run: () => watch.watchDeployments("watch-1", "default"), expectedCommand: "watch_deployments", expectedPayload: { watchId: "watch-1", namespace: "default" } },
   { name: "watchServices", run: () => watch.watchServices("watch-1", "default"), expectedCommand: "watch_services", expectedPayload: { watchId: "watch-1", namespace: "default" } },
@@ -116,7 +146,9 @@ const cases: TestCase[] = [
   { name: "watchDaemonsets with selector", run: () => watch.watchDaemonsets("watch-1", "default", "app=web"), expectedCommand: "watch_daemonsets", expectedPayload: { watchId: "watch-1", namespace: "default", labelSelector: "app=web" } },
   { name: "watchReplicasets with selector", run: () => watch.watchReplicasets("watch-1", "default", "app=web"), expectedCommand: "watch_replicasets", expectedPayload: { watchId: "watch-1", namespace: "default", labelSelector: "app=web" } },
   { name: "watchNamespaces", run: () => watch.watchNamespaces("watch-1"), expectedCommand: "watch_namespaces", expectedPayload: { watchId: "watch-1" } },
+  { name: "watchResources", run: () => watch.watchResources("watch-crd", widgetQuery, "prod"), expectedCommand: "watch_resources", expectedPayload: { watchId: "watch-crd", query: widgetQuery, context: "prod" } },
   { name: "stopWatch", run: () => watch.stopWatch("watch-1"), expectedCommand: "stop_watch", expectedPayload: { watchId: "watch-1" } },
+  { name: "getCacheStatus", run: () => watch.getCacheStatus("prod"), expectedCommand: "get_cache_status", expectedPayload: { context: "prod" } },
   { name: "aiCheckCliAvailable", run: () => ai.aiCheckCliAvailable(), expectedCommand: "ai_check_cli_available" },
   { name: "aiCheckCodexCliAvailable", run: () => ai.aiCheckCodexCliAvailable(), expectedCommand: "ai_check_codex_cli_available" },
   { name: "aiCheckOpenCodeCliAvailable", run: () => ai.aiCheckOpenCodeCliAvailable(), expectedCommand: "ai_check_opencode_cli_available" },
@@ -138,8 +170,8 @@ const cases: TestCase[] = [
   { name: "listServices", run: () => resources.listServices(resourceOptions as never), expectedCommand: "list_services", expectedPayload: { options: resourceOptions } },
   { name: "listConfigmaps", run: () => resources.listConfigmaps(resourceOptions as never), expectedCommand: "list_configmaps", expectedPayload: { options: resourceOptions } },
   { name: "listSecrets", run: () => resources.listSecrets(resourceOptions as never), expectedCommand: "list_secrets", expectedPayload: { options: resourceOptions } },
-  { name: "listNodes", run: () => resources.listNodes(), expectedCommand: "list_nodes" },
-  { name: "listNamespaces", run: () => resources.listNamespaces(), expectedCommand: "list_namespaces" },
+  { name: "listNodes", run: () => resources.listNodes(), expectedCommand: "list_nodes", expectedPayload: {} },
+  { name: "listNamespaces", run: () => resources.listNamespaces(), expectedCommand: "list_namespaces", expectedPayload: {} },
   { name: "listEvents", run: () => resources.listEvents(resourceOptions as never), expectedCommand: "list_events", expectedPayload: { options: resourceOptions } },
   { name: "listLeases", run: () => resources.listLeases(resourceOptions as never), expectedCommand: "list_leases", expectedPayload: { options: resourceOptions } },
   { name: "listReplicasets", run: () => resources.listReplicasets(resourceOptions as never), expectedCommand: "list_replicasets", expectedPayload: { options: resourceOptions } },
@@ -155,18 +187,18 @@ const cases: TestCase[] = [
   { name: "listLimitRanges", run: () => resources.listLimitRanges(resourceOptions as never), expectedCommand: "list_limit_ranges", expectedPayload: { options: resourceOptions } },
   { name: "listResourceQuotas", run: () => resources.listResourceQuotas(resourceOptions as never), expectedCommand: "list_resource_quotas", expectedPayload: { options: resourceOptions } },
   { name: "listPDBs", run: () => resources.listPDBs(resourceOptions as never), expectedCommand: "list_pdbs", expectedPayload: { options: resourceOptions } },
-  { name: "listPersistentVolumes", run: () => resources.listPersistentVolumes(), expectedCommand: "list_persistent_volumes" },
+  { name: "listPersistentVolumes", run: () => resources.listPersistentVolumes(), expectedCommand: "list_persistent_volumes", expectedPayload: {} },
   { name: "listPersistentVolumeClaims", run: () => resources.listPersistentVolumeClaims("default"), expectedCommand: "list_persistent_volume_claims", expectedPayload: { namespace: "default" } },
-  { name: "listStorageClasses", run: () => resources.listStorageClasses(), expectedCommand: "list_storage_classes" },
-  { name: "listCSIDrivers", run: () => resources.listCSIDrivers(), expectedCommand: "list_csi_drivers" },
-  { name: "listCSINodes", run: () => resources.listCSINodes(), expectedCommand: "list_csi_nodes" },
-  { name: "listVolumeAttachments", run: () => resources.listVolumeAttachments(), expectedCommand: "list_volume_attachments" },
+  { name: "listStorageClasses", run: () => resources.listStorageClasses(), expectedCommand: "list_storage_classes", expectedPayload: {} },
+  { name: "listCSIDrivers", run: () => resources.listCSIDrivers(), expectedCommand: "list_csi_drivers", expectedPayload: {} },
+  { name: "listCSINodes", run: () => resources.listCSINodes(), expectedCommand: "list_csi_nodes", expectedPayload: {} },
+  { name: "listVolumeAttachments", run: () => resources.listVolumeAttachments(), expectedCommand: "list_volume_attachments", expectedPayload: {} },
   { name: "listServiceAccounts", run: () => resources.listServiceAccounts("default"), expectedCommand: "list_service_accounts", expectedPayload: { namespace: "default" } },
   { name: "listRoles", run: () => resources.listRoles("default"), expectedCommand: "list_roles", expectedPayload: { namespace: "default" } },
   { name: "listRoleBindings", run: () => resources.listRoleBindings("default"), expectedCommand: "list_role_bindings", expectedPayload: { namespace: "default" } },
-  { name: "listClusterRoles", run: () => resources.listClusterRoles(), expectedCommand: "list_cluster_roles" },
-  { name: "listClusterRoleBindings", run: () => resources.listClusterRoleBindings(), expectedCommand: "list_cluster_role_bindings" },
-  { name: "listCRDs", run: () => resources.listCRDs(), expectedCommand: "list_crds" },
+  { name: "listClusterRoles", run: () => resources.listClusterRoles(), expectedCommand: "list_cluster_roles", expectedPayload: {} },
+  { name: "listClusterRoleBindings", run: () => resources.listClusterRoleBindings(), expectedCommand: "list_cluster_role_bindings", expectedPayload: {} },
+  { name: "listCRDs", run: () => resources.listCRDs(), expectedCommand: "list_crds", expectedPayload: {} },
   {
     name: "listCustomResources",
     run: () => resources.listCustomResources({
@@ -189,14 +221,29 @@ const cases: TestCase[] = [
       },
     },
   },
-  { name: "listPriorityClasses", run: () => resources.listPriorityClasses(), expectedCommand: "list_priority_classes" },
-  { name: "listRuntimeClasses", run: () => resources.listRuntimeClasses(), expectedCommand: "list_runtime_classes" },
-  { name: "listMutatingWebhooks", run: () => resources.listMutatingWebhooks(), expectedCommand: "list_mutating_webhooks" },
-  { name: "listValidatingWebhooks", run: () => resources.listValidatingWebhooks(), expectedCommand: "list_validating_webhooks" },
+  { name: "listPriorityClasses", run: () => resources.listPriorityClasses(), expectedCommand: "list_priority_classes", expectedPayload: {} },
+  { name: "listRuntimeClasses", run: () => resources.listRuntimeClasses(), expectedCommand: "list_runtime_classes", expectedPayload: {} },
+  { name: "listMutatingWebhooks", run: () => resources.listMutatingWebhooks(), expectedCommand: "list_mutating_webhooks", expectedPayload: {} },
+  { name: "listValidatingWebhooks", run: () => resources.listValidatingWebhooks(), expectedCommand: "list_validating_webhooks", expectedPayload: {} },
   { name: "getPod", run: () => resources.getPod("demo", "default"), expectedCommand: "get_pod", expectedPayload: { name: "demo", namespace: "default" } },
   { name: "getResourceYaml", run: () => resources.getResourceYaml("Deployment", "demo", "default"), expectedCommand: "get_resource_yaml", expectedPayload: { resourceType: "Deployment", name: "demo", namespace: "default" } },
   { name: "applyResourceYaml", run: () => resources.applyResourceYaml("kind: Pod"), expectedCommand: "apply_resource_yaml", expectedPayload: { yamlContent: "kind: Pod" } },
+  { name: "applyManifests", run: () => resources.applyManifests({ type: "path", path: "./k8s", recursive: true }, "app=demo"), expectedCommand: "apply_manifests", expectedPayload: { source: { type: "path", path: "./k8s", recursive: true }, pruneSelector: "app=demo" } },
+  { name: "dryRunResourceYaml", run: () => resources.dryRunResourceYaml("kind: Pod"), expectedCommand: "dry_run_resource_yaml", expectedPayload: { yamlContent: "kind: Pod" } },
   { name: "deleteResource", run: () => resources.deleteResource("Service", "demo", "default"), expectedCommand: "delete_resource", expectedPayload: { resourceType: "Service", name: "demo", namespace: "default" } },
+  { name: "bulkDeleteResources", run: () => bulk.bulkDeleteResources("op-1", "pods", { type: "selector", namespace: "default", label_selector: "app=demo" }, { dry_run: true }), expectedCommand: "bulk_delete_resources", expectedPayload: { operationId: "op-1", resourceType: "pods", target: { type: "selector", namespace: "default", label_selector: "app=demo" }, options: { dry_run: true } } },
+  { name: "bulkScaleResources", run: () => bulk.bulkScaleResources("op-1", "deployment", { type: "refs", refs: [{ name: "demo", namespace: "default" }] }, 3), expectedCommand: "bulk_scale_resources", expectedPayload: { operationId: "op-1", resourceType: "deployment", target: { type: "refs", refs: [{ name: "demo", namespace: "default" }] }, replicas: 3 } },
+  { name: "bulkSetContainerImage", run: () => bulk.bulkSetContainerImage("op-1", "statefulset", { type: "refs", refs: [{ name: "db", namespace: "default" }] }, "db", "postgres:17", false, true), expectedCommand: "bulk_set_container_image", expectedPayload: { operationId: "op-1", resourceType: "statefulset", target: { type: "refs", refs: [{ name: "db", namespace: "default" }] }, containerName: "db", image: "postgres:17", initContainer: false, dryRun: true } },
+  { name: "rolloutRestart", run: () => rollout.rolloutRestart("deployment", "demo", "default"), expectedCommand: "rollout_restart", expectedPayload: { target: "deployment", name: "demo", namespace: "default" } },
+  { name: "rolloutPause", run: () => rollout.rolloutPause("deployment", "demo", "default"), expectedCommand: "rollout_pause", expectedPayload: { target: "deployment", name: "demo", namespace: "default" } },
+  { name: "rolloutResume", run: () => rollout.rolloutResume("deployment", "demo", "default"), expectedCommand: "rollout_resume", expectedPayload: { target: "deployment", name: "demo", namespace: "default" } },
+  { name: "rolloutHistory", run: () => rollout.rolloutHistory("statefulset", "db", "default"), expectedCommand: "rollout_history", expectedPayload: { target: "statefulset", name: "db", namespace: "default" } },
+  { name: "rolloutUndo", run: () => rollout.rolloutUndo("deployment", "demo", "default", 2), expectedCommand: "rollout_undo", expectedPayload: { target: "deployment", name: "demo", namespace: "default", toRevision: 2 } },
+  { name: "watchRolloutStatus", run: () => rollout.watchRolloutStatus("daemonset", "agent", "kube-system", "watch-1"), expectedCommand: "watch_rollout_status", expectedPayload: { target: "daemonset", name: "agent", namespace: "kube-system", watchId: "watch-1" } },
+  { name: "cordonNode", run: () => drain.cordonNode("node-1"), expectedCommand: "cordon_node", expectedPayload: { name: "node-1" } },
+  { name: "uncordonNode", run: () => drain.uncordonNode("node-1"), expectedCommand: "uncordon_node", expectedPayload: { name: "node-1" } },
+  { name: "evictPod", run: () => drain.evictPod("default", "demo", 30), expectedCommand: "evict_pod", expectedPayload: { namespace: "default", name: "demo", gracePeriodSeconds: 30 } },
+  { name: "drainNode", run: () => drain.drainNode("drain-1", "node-1", { evict_unmanaged: true }), expectedCommand: "drain_node", expectedPayload: { drainId: "drain-1", name: "node-1", options: { evict_unmanaged: true } } },
   { name: "scaleDeployment", run: () => resources.scaleDeployment("demo", "default", 3), expectedCommand: "scale_deployment", expectedPayload: { name: "demo", namespace: "default", replicas: 3 } },
   { name: "setContainerImage", run: () => resources.setContainerImage("deployment", "demo", "default", "migrate", "busybox:1.37", true), expectedCommand: "set_container_image", expectedPayload: { resourceType: "deployment", name: "demo", namespace: "default", containerName: "migrate", image: "busybox:1.37", initContainer: true } },
   { name: "triggerCronjob", run: () => resources.triggerCronjob("demo", "default"), expectedCommand: "trigger_cronjob", expectedPayload: { name: "demo", namespace: "default" } },
@@ -205,6 +252,117 @@ const cases: TestCase[] = [
   { name: "getCronjobJobYaml", run: () => resources.getCronjobJobYaml("demo", "default"), expectedCommand: "get_cronjob_job_yaml", expectedPayload: { name: "demo", namespace: "default" } },
 ];
 
+// Wrappers of commands that can target a connected cluster other than the active one
+const contextCases: [string, () => Promise<unknown>, string][] = [
+  ["listArgoCDApplications", () => argocd.listArgoCDApplications(undefined, "prod"), "list_argocd_applications"],
+  ["refreshArgoCDApplication", () => argocd.refreshArgoCDApplication("demo", "demo", "prod"), "refresh_argocd_application"],
+  ["hardRefreshArgoCDApplication", () => argocd.hardRefreshArgoCDApplication("demo", "demo", "prod"), "hard_refresh_argocd_application"],
+  ["syncArgoCDApplication", () => argocd.syncArgoCDApplication("demo", "demo", "prod"), "sync_argocd_application"],
+  ["getArgoCDApplicationHistory", () => argocd.getArgoCDApplicationHistory("demo", "demo", "prod"), "get_argocd_application_history"],
+  ["rollbackArgoCDApplication", () => argocd.rollbackArgoCDApplication("demo", "demo", 1, "prod"), "rollback_argocd_application"],
+  ["getArgoCDOperationState", () => argocd.getArgoCDOperationState("demo", "demo", "prod"), "get_argocd_operation_state"],
+  ["disconnectCluster", () => cluster.disconnectCluster("prod"), "disconnect_cluster"],
+  ["getNamespaces", () => cluster.getNamespaces("prod"), "get_namespaces"],
+  ["listFluxKustomizations", () => flux.listFluxKustomizations(undefined, "prod"), "list_flux_kustomizations"],
+  ["reconcileFluxKustomization", () => flux.reconcileFluxKustomization("demo", "demo", "prod"), "reconcile_flux_kustomization"],
+  ["reconcileFluxKustomizationWithSource", () => flux.reconcileFluxKustomizationWithSource("demo", "demo", "prod"), "reconcile_flux_kustomization_with_source"],
+  ["suspendFluxKustomization", () => flux.suspendFluxKustomization("demo", "demo", "prod"), "suspend_flux_kustomization"],
+  ["resumeFluxKustomization", () => flux.resumeFluxKustomization("demo", "demo", "prod"), "resume_flux_kustomization"],
+  ["reconcileFluxHelmRelease", () => flux.reconcileFluxHelmRelease("demo", "demo", "prod"), "reconcile_flux_helmrelease"],
+  ["reconcileFluxHelmReleaseWithSource", () => flux.reconcileFluxHelmReleaseWithSource("demo", "demo", "prod"), "reconcile_flux_helmrelease_with_source"],
+  ["forceFluxHelmRelease", () => flux.forceFluxHelmRelease("demo", "demo", "prod"), "force_flux_helmrelease"],
+  ["resetFluxHelmRelease", () => flux.resetFluxHelmRelease("demo", "demo", "prod"), "reset_flux_helmrelease"],
+  ["suspendFluxHelmRelease", () => flux.suspendFluxHelmRelease("demo", "demo", "prod"), "suspend_flux_helmrelease"],
+  ["resumeFluxHelmRelease", () => flux.resumeFluxHelmRelease("demo", "demo", "prod"), "resume_flux_helmrelease"],
+  ["waitFluxReconcile", () => flux.waitFluxReconcile("kustomization", "demo", "demo", "demo", "prod"), "wait_flux_reconcile"],
+  ["generateResourceGraph", () => graph.generateResourceGraph(["default"], "prod"), "generate_resource_graph"],
+  ["watchResourceGraph", () => graph.watchResourceGraph("graph-1", ["default"], "prod"), "watch_resource_graph"],
+  ["listHelmReleases", () => helm.listHelmReleases(undefined, "prod"), "list_helm_releases"],
+  ["uninstallHelmRelease", () => helm.uninstallHelmRelease("demo", "demo", undefined, "prod"), "uninstall_helm_release"],
+  ["getPodContainers", () => logs.getPodContainers("demo", "demo", "prod"), "get_pod_containers"],
+  ["getNodeMetrics", () => metrics.getNodeMetrics(undefined, "prod"), "get_node_metrics"],
+  ["getPodMetrics", () => metrics.getPodMetrics(undefined, undefined, "prod"), "get_pod_metrics"],
+  ["getPodMetricsDirect", () => metrics.getPodMetricsDirect(undefined, "prod"), "get_pod_metrics_direct"],
+  ["getClusterMetricsSummary", () => metrics.getClusterMetricsSummary("prod"), "get_cluster_metrics_summary"],
+  ["checkMetricsServer", () => metrics.checkMetricsServer("prod"), "check_metrics_server"],
+  ["listPods", () => resources.listPods(undefined, "prod"), "list_pods"],
+  ["listDeployments", () => resources.listDeployments(undefined, "prod"), "list_deployments"],
+  ["listServices", () => resources.listServices(undefined, "prod"), "list_services"],
+  ["listConfigmaps", () => resources.listConfigmaps(undefined, "prod"), "list_configmaps"],
+  ["listSecrets", () => resources.listSecrets(undefined, "prod"), "list_secrets"],
+  ["listNodes", () => resources.listNodes("prod"), "list_nodes"],
+  ["listNamespaces", () => resources.listNamespaces("prod"), "list_namespaces"],
+  ["listEvents", () => resources.listEvents(undefined, "prod"), "list_events"],
+  ["listLeases", () => resources.listLeases(undefined, "prod"), "list_leases"],
+  ["listReplicasets", () => resources.listReplicasets(undefined, "prod"), "list_replicasets"],
+  ["listDaemonsets", () => resources.listDaemonsets(undefined, "prod"), "list_daemonsets"],
+  ["listStatefulsets", () => resources.listStatefulsets(undefined, "prod"), "list_statefulsets"],
+  ["listJobs", () => resources.listJobs(undefined, "prod"), "list_jobs"],
+  ["listCronjobs", () => resources.listCronjobs(undefined, "prod"), "list_cronjobs"],
+  ["listIngresses", () => resources.listIngresses(undefined, "prod"), "list_ingresses"],
+  ["listEndpointSlices", () => resources.listEndpointSlices(undefined, "prod"), "list_endpoint_slices"],
+  ["listNetworkPolicies", () => resources.listNetworkPolicies(undefined, "prod"), "list_network_policies"],
+  ["listIngressClasses", () => resources.listIngressClasses(undefined, "prod"), "list_ingress_classes"],
+  ["listHPAs", () => resources.listHPAs(undefined, "prod"), "list_hpas"],
+  ["listLimitRanges", () => resources.listLimitRanges(undefined, "prod"), "list_limit_ranges"],
+  ["listResourceQuotas", () => resources.listResourceQuotas(undefined, "prod"), "list_resource_quotas"],
+  ["listPDBs", () => resources.listPDBs(undefined, "prod"), "list_pdbs"],
+  ["listPersistentVolumes", () => resources.listPersistentVolumes("prod"), "list_persistent_volumes"],
+  ["listPersistentVolumeClaims", () => resources.listPersistentVolumeClaims(undefined, "prod"), "list_persistent_volume_claims"],
+  ["listStorageClasses", () => resources.listStorageClasses("prod"), "list_storage_classes"],
+  ["listCSIDrivers", () => resources.listCSIDrivers("prod"), "list_csi_drivers"],
+  ["listCSINodes", () => resources.listCSINodes("prod"), "list_csi_nodes"],
+  ["listVolumeAttachments", () => resources.listVolumeAttachments("prod"), "list_volume_attachments"],
+  ["listServiceAccounts", () => resources.listServiceAccounts(undefined, "prod"), "list_service_accounts"],
+  ["listRoles", () => resources.listRoles(undefined, "prod"), "list_roles"],
+  ["listRoleBindings", () => resources.listRoleBindings(undefined, "prod"), "list_role_bindings"],
+  ["listClusterRoles", () => resources.listClusterRoles("prod"), "list_cluster_roles"],
+  ["listClusterRoleBindings", () => resources.listClusterRoleBindings("prod"), "list_cluster_role_bindings"],
+  ["listCRDs", () => resources.listCRDs("prod"), "list_crds"],
+  ["listCustomResources", () => resources.listCustomResources({} as never, "prod"), "list_custom_resources"],
+  ["listPriorityClasses", () => resources.listPriorityClasses("prod"), "list_priority_classes"],
+  ["listRuntimeClasses", () => resources.listRuntimeClasses("prod"), "list_runtime_classes"],
+  ["listMutatingWebhooks", () => resources.listMutatingWebhooks("prod"), "list_mutating_webhooks"],
+  ["listValidatingWebhooks", () => resources.listValidatingWebhooks("prod"), "list_validating_webhooks"],
+  ["getPod", () => resources.getPod("demo", "demo", "prod"), "get_pod"],
+  ["revealEnvVar", () => resources.revealEnvVar("demo", "demo", "demo", "prod"), "reveal_env_var"],
+  ["getResourceYaml", () => resources.getResourceYaml("demo", "demo", undefined, "prod"), "get_resource_yaml"],
+  ["applyResourceYaml", () => resources.applyResourceYaml("demo", undefined, "prod"), "apply_resource_yaml"],
+  ["deleteResource", () => resources.deleteResource("demo", "demo", undefined, "prod"), "delete_resource"],
+  ["scaleDeployment", () => resources.scaleDeployment("demo", "demo", 1, "prod"), "scale_deployment"],
+  ["setContainerImage", () => resources.setContainerImage("deployment", "demo", "demo", "demo", "demo", false, "prod"), "set_container_image"],
+  ["triggerCronjob", () => resources.triggerCronjob("demo", "demo", "prod"), "trigger_cronjob"],
+  ["suspendCronjob", () => resources.suspendCronjob("demo", "demo", "prod"), "suspend_cronjob"],
+  ["resumeCronjob", () => resources.resumeCronjob("demo", "demo", "prod"), "resume_cronjob"],
+  ["getCronjobJobYaml", () => resources.getCronjobJobYaml("demo", "demo", "prod"), "get_cronjob_job_yaml"],
+  ["watchPods", () => watch.watchPods("demo", undefined, undefined, "prod"), "watch_pods"],
+  ["watchDeployments", () => watch.watchDeployments("demo", undefined, undefined, "prod"), "watch_deployments"],
+  ["watchServices", () => watch.watchServices("demo", undefined, undefined, "prod"), "watch_services"],
+  ["watchStatefulsets", () => watch.watchStatefulsets("demo", undefined, undefined, "prod"), "watch_statefulsets"],
+  ["watchDaemonsets", () => watch.watchDaemonsets("demo", undefined, undefined, "prod"), "watch_daemonsets"],
+  ["watchReplicasets", () => watch.watchReplicasets("demo", undefined, undefined, "prod"), "watch_replicasets"],
+  ["watchNamespaces", () => watch.watchNamespaces("demo", "prod"), "watch_namespaces"],
+  ["dryRunResourceYaml", () => resources.dryRunResourceYaml("kind: Pod", "prod"), "dry_run_resource_yaml"],
+  ["applyManifests", () => resources.applyManifests({ type: "path", path: "./k8s", recursive: false }, undefined, undefined, "prod"), "apply_manifests"],
+  ["bulkDeleteResources", () => bulk.bulkDeleteResources("op-1", "pods", { type: "refs", refs: [] }, undefined, undefined, "prod"), "bulk_delete_resources"],
+  ["bulkScaleResources", () => bulk.bulkScaleResources("op-1", "deployment", { type: "refs", refs: [] }, 1, undefined, undefined, "prod"), "bulk_scale_resources"],
+  ["bulkSetContainerImage", () => bulk.bulkSetContainerImage("op-1", "deployment", { type: "refs", refs: [] }, "app", "app:2", false, undefined, undefined, "prod"), "bulk_set_container_image"],
+  ["cordonNode", () => drain.cordonNode("node-1", "prod"), "cordon_node"],
+  ["uncordonNode", () => drain.uncordonNode("node-1", "prod"), "uncordon_node"],
+  ["evictPod", () => drain.evictPod("default", "demo", undefined, "prod"), "evict_pod"],
+  ["drainNode", () => drain.drainNode("drain-1", "node-1", undefined, "prod"), "drain_node"],
+  ["rolloutRestart", () => rollout.rolloutRestart("deployment", "demo", "default", "prod"), "rollout_restart"],
+  ["rolloutPause", () => rollout.rolloutPause("deployment", "demo", "default", "prod"), "rollout_pause"],
+  ["rolloutResume", () => rollout.rolloutResume("deployment", "demo", "default", "prod"), "rollout_resume"],
+  ["rolloutHistory", () => rollout.rolloutHistory("deployment", "demo", "default", "prod"), "rollout_history"],
+  ["rolloutUndo", () => rollout.rolloutUndo("deployment", "demo", "default", undefined, "prod"), "rollout_undo"],
+  ["watchRolloutStatus", () => rollout.watchRolloutStatus("deployment", "demo", "default", "watch-1", "prod"), "watch_rollout_status"],
+  ["upgradeHelmRelease", () => helm.upgradeHelmRelease("demo", "default", "./charts/demo", undefined, "prod"), "upgrade_helm_release"],
+  ["rollbackHelmRelease", () => helm.rollbackHelmRelease("demo", "default", undefined, "prod"), "rollback_helm_release"],
+  ["diffHelmRevisions", () => helm.diffHelmRevisions("demo", "default", undefined, undefined, "prod"), "diff_helm_revisions"],
+  ["installHelmChart", () => helm.installHelmChart("./charts/demo", "demo", "default", undefined, "prod"), "install_helm_chart"],
+];
+
 describe("tauri command wrappers", () => {
   beforeEach(() => {
     jest.clearAllMocks();
@@ -222,6 +380,13 @@ describe("tauri command wrappers", () => {
     }
   });
 
+  it.each(contextCases)("passes the cluster context through %s", async (_name, run, expectedCommand) => {
+    await run();
+    const lastCall = (invoke as jest.Mock).mock.calls.at(-1);
+    expect(lastCall?.[0]).toBe(expectedCommand);
+    expect(lastCall?.[1]).toMatchObject({ context: "prod" });
+  });
+
   it("applies default payloads for resource list helpers", async () => {
     await resources.listPods();
     expect(invoke).toHaveBeenLastCalledWith("list_pods", { options: {} });
@@ -235,5 +400,6 @@ describe("tauri command wrappers", () => {
     expect(tauriCommands.aiStartSession).toBe(ai.aiStartSession);
     expect(tauriIndex.listPods).toBe(resources.listPods);
     expect(tauriIndex.mcpDetectIdes).toBe(mcp.mcpDetectIdes);
+    expect(tauriCommands.copyToContainer).toBe(fileCopy.copyToContainer);
   });
 });
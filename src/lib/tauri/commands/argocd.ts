@@ -8,53 +8,68 @@ import { invoke } from "./core";
 
 // ArgoCD commands
 export async function listArgoCDApplications(
-  namespace?: string
+  namespace?: string,
+  context?: string
 ): Promise<ArgoCDApplicationInfo[]> {
-  return invoke<ArgoCDApplicationInfo[]>("list_argocd_applications", { namespace });
+  return invoke<ArgoCDApplicationInfo[]>("list_argocd_applications", {
+    namespace,
+    context,
+  });
 }
 
 export async function refreshArgoCDApplication(
   name: string,
-  namespace: string
+  namespace: string,
+  context?: string
 ): Promise<void> {
-  return invoke<void>("refresh_argocd_application", { name, namespace });
+  return invoke<void>("refresh_argocd_application", { name, namespace, context });
 }
 
 export async function hardRefreshArgoCDApplication(
   name: string,
-  namespace: string
+  namespace: string,
+  context?: string
 ): Promise<void> {
-  return invoke<void>("hard_refresh_argocd_application", { name, namespace });
+  return invoke<void>("hard_refresh_argocd_application", { name, namespace, context });
 }
 
 export async function syncArgoCDApplication(
   name: string,
-  namespace: string
+  namespace: string,
+  context?: string
 ): Promise<void> {
-  return invoke<void>("sync_argocd_application", { name, namespace });
+  return invoke<void>("sync_argocd_application", { name, namespace, context });
 }
 
 export async function getArgoCDApplicationHistory(
   name: string,
-  namespace: string
+  namespace: string,
+  context?: string
 ): Promise<ArgoCDHistoryEntry[]> {
-  return invoke<ArgoCDHistoryEntry[]>("get_argocd_application_history", { name, namespace });
+  return invoke<ArgoCDHistoryEntry[]>("get_argocd_application_history", {
+    name,
+    namespace,
+    context,
+  });
 }
 
 export async function rollbackArgoCDApplication(
   name: string,
   namespace: string,
-  id: number
+  id: number,
+  context?: string
 ): Promise<void> {
-  return invoke<void>("rollback_argocd_application", { name, namespace, id });
+  return invoke<void>("rollback_argocd_application", { name, namespace, id, context });
 }
 
 export async function getArgoCDOperationState(
   name: string,
-  namespace: string
+  namespace: string,
+  context?: string
 ): Promise<ArgoCDOperationState> {
   return invoke<ArgoCDOperationState>("get_argocd_operation_state", {
     name,
     namespace,
+    context,
   });
 }
@@ -0,0 +1,73 @@
+import type {
+  BulkDeleteOptions,
+  BulkItemResult,
+  BulkTarget,
+  ScaleTarget,
+} from "../../types";
+
+import { invoke } from "./core";
+import type { ImagePatchTarget } from "./resources";
+
+// Bulk commands. Progress arrives on `bulk-{operationId}`; one failed object
+// does not stop the others.
+export async function bulkDeleteResources(
+  operationId: string,
+  resourceType: string,
+  target: BulkTarget,
+  options?: BulkDeleteOptions,
+  concurrency?: number,
+  context?: string
+): Promise<BulkItemResult[]> {
+  return invoke<BulkItemResult[]>("bulk_delete_resources", {
+    operationId,
+    resourceType,
+    target,
+    options,
+    concurrency,
+    context,
+  });
+}
+
+export async function bulkScaleResources(
+  operationId: string,
+  resourceType: ScaleTarget,
+  target: BulkTarget,
+  replicas: number,
+  dryRun?: boolean,
+  concurrency?: number,
+  context?: string
+): Promise<BulkItemResult[]> {
+  return invoke<BulkItemResult[]>("bulk_scale_resources", {
+    operationId,
+    resourceType,
+    target,
+    replicas,
+    dryRun,
+    concurrency,
+    context,
+  });
+}
+
+export async function bulkSetContainerImage(
+  operationId: string,
+  resourceType: ImagePatchTarget,
+  target: BulkTarget,
+  containerName: string,
+  image: string,
+  initContainer: boolean,
+  dryRun?: boolean,
+  concurrency?: number,
+  context?: string
+): Promise<BulkItemResult[]> {
+  return invoke<BulkItemResult[]>("bulk_set_container_image", {
+    operationId,
+    resourceType,
+    target,
+    containerName,
+    image,
+    initContainer,
+    dryRun,
+    concurrency,
+    context,
+  });
+}
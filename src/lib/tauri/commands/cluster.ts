@@ -11,8 +11,13 @@ export async function connectCluster(context: string): Promise<ConnectionStatus>
   return invoke<ConnectionStatus>("connect_cluster", { context });
 }
 
-export async function disconnectCluster(): Promise<void> {
-  return invoke("disconnect_cluster");
+export async function disconnectCluster(context?: string): Promise<void> {
+  return invoke("disconnect_cluster", { context });
+}
+
+/** Contexts that currently hold a live client, the active one included */
+export async function listConnectedContexts(): Promise<string[]> {
+  return invoke<string[]>("list_connected_contexts");
 }
 
 export async function getConnectionStatus(): Promise<ConnectionStatus> {
@@ -23,8 +28,8 @@ export async function checkConnectionHealth(): Promise<HealthCheckResult> {
   return invoke<HealthCheckResult>("check_connection_health");
 }
 
-export async function getNamespaces(): Promise<NamespaceResult> {
-  return invoke<NamespaceResult>("get_namespaces");
+export async function getNamespaces(context?: string): Promise<NamespaceResult> {
+  return invoke<NamespaceResult>("get_namespaces", { context });
 }
 
 // Cluster settings commands
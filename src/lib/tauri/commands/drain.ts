@@ -0,0 +1,32 @@
+import type { DrainOptions, DrainSummary } from "../../types";
+
+import { invoke } from "./core";
+
+// Node maintenance commands
+export async function cordonNode(name: string, context?: string): Promise<void> {
+  return invoke("cordon_node", { name, context });
+}
+
+export async function uncordonNode(name: string, context?: string): Promise<void> {
+  return invoke("uncordon_node", { name, context });
+}
+
+/** Evicts through the Eviction API; a blocking PodDisruptionBudget rejects with RateLimited */
+export async function evictPod(
+  namespace: string,
+  name: string,
+  gracePeriodSeconds?: number,
+  context?: string
+): Promise<void> {
+  return invoke("evict_pod", { namespace, name, gracePeriodSeconds, context });
+}
+
+/** Cordons the node and evicts its pods; progress arrives on `drain-{drainId}` */
+export async function drainNode(
+  drainId: string,
+  name: string,
+  options?: DrainOptions,
+  context?: string
+): Promise<DrainSummary> {
+  return invoke<DrainSummary>("drain_node", { drainId, name, options, context });
+}
@@ -0,0 +1,19 @@
+import type { FileCopyOptions } from "../../types";
+
+import { invoke } from "./core";
+
+// File copy commands. Progress arrives on `file-copy-{transferId}`;
+// `stopWatch(transferId)` cancels.
+export async function copyToContainer(
+  transferId: string,
+  options: FileCopyOptions
+): Promise<void> {
+  return invoke("copy_to_container", { transferId, options });
+}
+
+export async function copyFromContainer(
+  transferId: string,
+  options: FileCopyOptions
+): Promise<void> {
+  return invoke("copy_from_container", { transferId, options });
+}
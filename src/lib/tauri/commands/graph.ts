@@ -1,8 +1,26 @@
-import type { GraphData } from "../../types";
+import type { GraphData, GraphExportFormat } from "../../types";
 
 import { invoke } from "./core";
 
 // Graph commands
-export async function generateResourceGraph(namespaces: string[]): Promise<GraphData> {
-  return invoke<GraphData>("generate_resource_graph", { namespaces });
+export async function generateResourceGraph(
+  namespaces: string[],
+  context?: string
+): Promise<GraphData> {
+  return invoke<GraphData>("generate_resource_graph", { namespaces, context });
+}
+
+export async function watchResourceGraph(
+  watchId: string,
+  namespaces: string[],
+  context?: string
+): Promise<void> {
+  return invoke("watch_resource_graph", { watchId, namespaces, context });
+}
+
+export async function exportResourceGraph(
+  graph: GraphData,
+  format: GraphExportFormat
+): Promise<string> {
+  return invoke<string>("export_resource_graph", { graph, format });
 }
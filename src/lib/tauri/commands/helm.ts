@@ -1,15 +1,96 @@
-import type { HelmReleaseInfo } from "../../types";
+import type {
+  HelmInstallOptions,
+  HelmInstallResult,
+  HelmReleaseInfo,
+  HelmRevisionDiff,
+  HelmRollbackResult,
+  HelmUninstallOptions,
+  HelmUninstallResult,
+  HelmUpgradeOptions,
+} from "../../types";
 
 import { invoke } from "./core";
 
 // Helm commands
-export async function listHelmReleases(namespace?: string): Promise<HelmReleaseInfo[]> {
-  return invoke<HelmReleaseInfo[]>("list_helm_releases", { namespace });
+export async function listHelmReleases(
+  namespace?: string,
+  context?: string
+): Promise<HelmReleaseInfo[]> {
+  return invoke<HelmReleaseInfo[]>("list_helm_releases", { namespace, context });
 }
 
 export async function uninstallHelmRelease(
   name: string,
-  namespace: string
-): Promise<void> {
-  return invoke<void>("uninstall_helm_release", { name, namespace });
+  namespace: string,
+  options?: HelmUninstallOptions,
+  context?: string
+): Promise<HelmUninstallResult> {
+  return invoke<HelmUninstallResult>("uninstall_helm_release", {
+    name,
+    namespace,
+    options,
+    context,
+  });
+}
+
+export async function rollbackHelmRelease(
+  name: string,
+  namespace: string,
+  revision?: number,
+  context?: string
+): Promise<HelmRollbackResult> {
+  return invoke<HelmRollbackResult>("rollback_helm_release", {
+    name,
+    namespace,
+    revision,
+    context,
+  });
+}
+
+export async function diffHelmRevisions(
+  name: string,
+  namespace: string,
+  fromRevision?: number,
+  toRevision?: number,
+  context?: string
+): Promise<HelmRevisionDiff> {
+  return invoke<HelmRevisionDiff>("diff_helm_revisions", {
+    name,
+    namespace,
+    fromRevision,
+    toRevision,
+    context,
+  });
+}
+
+export async function installHelmChart(
+  chartPath: string,
+  name: string,
+  namespace: string,
+  options?: HelmInstallOptions,
+  context?: string
+): Promise<HelmInstallResult> {
+  return invoke<HelmInstallResult>("install_helm_chart", {
+    chartPath,
+    name,
+    namespace,
+    options,
+    context,
+  });
+}
+
+export async function upgradeHelmRelease(
+  name: string,
+  namespace: string,
+  chartPath: string,
+  options?: HelmUpgradeOptions,
+  context?: string
+): Promise<HelmInstallResult> {
+  return invoke<HelmInstallResult>("upgrade_helm_release", {
+    name,
+    namespace,
+    chartPath,
+    options,
+    context,
+  });
 }
@@ -1,4 +1,12 @@
-import type { LogEntry, LogOptions } from "../../types";
+import type {
+  AggregatedLogOptions,
+  ArchivedLogLine,
+  ArchivedLogSource,
+  LogArchiveQuery,
+  LogArchiveStats,
+  LogEntry,
+  LogOptions,
+} from "../../types";
 
 import { invoke } from "./core";
 
@@ -14,17 +22,56 @@ export async function streamPodLogs(
   return invoke("stream_pod_logs", { streamId, options });
 }
 
+/**
+ * Follows every pod matching a label selector or workload, attaching pods as
+ * they appear. Lines arrive batched on `log-stream-{streamId}`.
+ */
+export async function streamAggregatedLogs(
+  streamId: string,
+  options: AggregatedLogOptions
+): Promise<void> {
+  return invoke("stream_aggregated_logs", { streamId, options });
+}
+
 export async function stopLogStream(streamId: string): Promise<void> {
   return invoke("stop_log_stream", { streamId });
 }
 
 export async function getPodContainers(
   namespace: string,
-  podName: string
+  podName: string,
+  context?: string
 ): Promise<string[]> {
-  return invoke<string[]>("get_pod_containers", { namespace, podName });
+  return invoke<string[]>("get_pod_containers", { namespace, podName, context });
 }
 
 export async function downloadPodLogs(options: LogOptions): Promise<string> {
   return invoke<string>("download_pod_logs", { options });
 }
+
+// Log archive commands. Streams started with `record: true` write here.
+export async function searchLogArchive(query: LogArchiveQuery): Promise<ArchivedLogLine[]> {
+  return invoke<ArchivedLogLine[]>("search_log_archive", { query });
+}
+
+export async function listLogArchiveSources(context?: string): Promise<ArchivedLogSource[]> {
+  return invoke<ArchivedLogSource[]>("list_log_archive_sources", { context });
+}
+
+export async function getLogArchiveStats(): Promise<LogArchiveStats> {
+  return invoke<LogArchiveStats>("get_log_archive_stats");
+}
+
+/** Changes the size cap; the oldest lines beyond it are dropped right away */
+export async function setLogArchiveMaxSize(maxBytes: number): Promise<LogArchiveStats> {
+  return invoke<LogArchiveStats>("set_log_archive_max_size", { maxBytes });
+}
+
+/** Deletes archived logs, optionally only those of a context, namespace or pod */
+export async function clearLogArchive(
+  context?: string,
+  namespace?: string,
+  pod?: string
+): Promise<number> {
+  return invoke<number>("clear_log_archive", { context, namespace, pod });
+}
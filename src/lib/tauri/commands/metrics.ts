@@ -3,26 +3,35 @@ import type { ClusterMetricsSummary, NodeMetrics, PodMetrics } from "../../types
 import { invoke } from "./core";
 
 // Metrics commands
-export async function getNodeMetrics(nodeName?: string): Promise<NodeMetrics[]> {
-  return invoke<NodeMetrics[]>("get_node_metrics", { nodeName });
+export async function getNodeMetrics(
+  nodeName?: string,
+  context?: string
+): Promise<NodeMetrics[]> {
+  return invoke<NodeMetrics[]>("get_node_metrics", { nodeName, context });
 }
 
 export async function getPodMetrics(
   namespace?: string,
-  podName?: string
+  podName?: string,
+  context?: string
 ): Promise<PodMetrics[]> {
-  return invoke<PodMetrics[]>("get_pod_metrics", { namespace, podName });
+  return invoke<PodMetrics[]>("get_pod_metrics", { namespace, podName, context });
 }
 
 /** Get pod metrics directly from kubelet /stats/summary (faster, ~10s updates) */
-export async function getPodMetricsDirect(namespace?: string): Promise<PodMetrics[]> {
-  return invoke<PodMetrics[]>("get_pod_metrics_direct", { namespace });
+export async function getPodMetricsDirect(
+  namespace?: string,
+  context?: string
+): Promise<PodMetrics[]> {
+  return invoke<PodMetrics[]>("get_pod_metrics_direct", { namespace, context });
 }
 
-export async function getClusterMetricsSummary(): Promise<ClusterMetricsSummary> {
-  return invoke<ClusterMetricsSummary>("get_cluster_metrics_summary");
+export async function getClusterMetricsSummary(
+  context?: string
+): Promise<ClusterMetricsSummary> {
+  return invoke<ClusterMetricsSummary>("get_cluster_metrics_summary", { context });
 }
 
-export async function checkMetricsServer(): Promise<boolean> {
-  return invoke<boolean>("check_metrics_server");
+export async function checkMetricsServer(context?: string): Promise<boolean> {
+  return invoke<boolean>("check_metrics_server", { context });
 }
@@ -1,4 +1,8 @@
-import type { PortForwardInfo, PortForwardOptions } from "../../types";
+import type {
+  PortForwardInfo,
+  PortForwardOptions,
+  PortForwardProfile,
+} from "../../types";
 
 import { invoke } from "./core";
 
@@ -21,3 +25,28 @@ export async function portforwardList(): Promise<PortForwardInfo[]> {
 export async function portforwardCheckPort(port: number): Promise<boolean> {
   return invoke<boolean>("portforward_check_port", { port });
 }
+
+// Port forward profile commands. A profile's forward runs as
+// `profile-{name}`, so its events arrive on `portforward-profile-{name}`.
+export async function portforwardProfilesList(): Promise<PortForwardProfile[]> {
+  return invoke<PortForwardProfile[]>("portforward_profiles_list");
+}
+
+/** Creates or replaces the profile with the same name */
+export async function portforwardProfileSave(profile: PortForwardProfile): Promise<void> {
+  return invoke("portforward_profile_save", { profile });
+}
+
+export async function portforwardProfileDelete(name: string): Promise<void> {
+  return invoke("portforward_profile_delete", { name });
+}
+
+/** Returns how many forwards were started (0 when it was already running) */
+export async function portforwardProfileStart(name: string): Promise<number> {
+  return invoke<number>("portforward_profile_start", { name });
+}
+
+/** Starts every profile of a group; returns how many forwards were started */
+export async function portforwardProfileStartGroup(group: string): Promise<number> {
+  return invoke<number>("portforward_profile_start_group", { group });
+}
@@ -1,4 +1,6 @@
 import type {
+  AppliedObject,
+  ApplyPreview,
   CRDInfo,
   CustomResourceInfo,
   CSIDriverInfo,
@@ -16,6 +18,7 @@ import type {
   LeaseInfo,
   LimitRangeInfo,
   ListOptions,
+  ManifestSource,
   MutatingWebhookInfo,
   NamespaceInfo,
   NetworkPolicyInfo,
@@ -45,211 +48,267 @@ import { invoke } from "./core";
 import type { CustomResourceDefinitionRef } from "@/lib/custom-resources";
 
 // Resource commands
-export async function listPods(options: ListOptions = {}): Promise<PodInfo[]> {
-  return invoke<PodInfo[]>("list_pods", { options });
+export async function listPods(
+  options: ListOptions = {},
+  context?: string
+): Promise<PodInfo[]> {
+  return invoke<PodInfo[]>("list_pods", { options, context });
 }
 
 export async function listDeployments(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<DeploymentInfo[]> {
-  return invoke<DeploymentInfo[]>("list_deployments", { options });
+  return invoke<DeploymentInfo[]>("list_deployments", { options, context });
 }
 
 export async function listServices(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<ServiceInfo[]> {
-  return invoke<ServiceInfo[]>("list_services", { options });
+  return invoke<ServiceInfo[]>("list_services", { options, context });
 }
 
 export async function listConfigmaps(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<ConfigMapInfo[]> {
-  return invoke<ConfigMapInfo[]>("list_configmaps", { options });
+  return invoke<ConfigMapInfo[]>("list_configmaps", { options, context });
 }
 
-export async function listSecrets(options: ListOptions = {}): Promise<SecretInfo[]> {
-  return invoke<SecretInfo[]>("list_secrets", { options });
+export async function listSecrets(
+  options: ListOptions = {},
+  context?: string
+): Promise<SecretInfo[]> {
+  return invoke<SecretInfo[]>("list_secrets", { options, context });
 }
 
-export async function listNodes(): Promise<NodeInfo[]> {
-  return invoke<NodeInfo[]>("list_nodes");
+export async function listNodes(context?: string): Promise<NodeInfo[]> {
+  return invoke<NodeInfo[]>("list_nodes", { context });
 }
 
-export async function listNamespaces(): Promise<NamespaceInfo[]> {
-  return invoke<NamespaceInfo[]>("list_namespaces");
+export async function listNamespaces(context?: string): Promise<NamespaceInfo[]> {
+  return invoke<NamespaceInfo[]>("list_namespaces", { context });
 }
 
-export async function listEvents(options: ListOptions = {}): Promise<EventInfo[]> {
-  return invoke<EventInfo[]>("list_events", { options });
+export async function listEvents(
+  options: ListOptions = {},
+  context?: string
+): Promise<EventInfo[]> {
+  return invoke<EventInfo[]>("list_events", { options, context });
 }
 
-export async function listLeases(options: ListOptions = {}): Promise<LeaseInfo[]> {
-  return invoke<LeaseInfo[]>("list_leases", { options });
+export async function listLeases(
+  options: ListOptions = {},
+  context?: string
+): Promise<LeaseInfo[]> {
+  return invoke<LeaseInfo[]>("list_leases", { options, context });
 }
 
 export async function listReplicasets(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<ReplicaSetInfo[]> {
-  return invoke<ReplicaSetInfo[]>("list_replicasets", { options });
+  return invoke<ReplicaSetInfo[]>("list_replicasets", { options, context });
 }
 
 export async function listDaemonsets(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<DaemonSetInfo[]> {
-  return invoke<DaemonSetInfo[]>("list_daemonsets", { options });
+  return invoke<DaemonSetInfo[]>("list_daemonsets", { options, context });
 }
 
 export async function listStatefulsets(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<StatefulSetInfo[]> {
-  return invoke<StatefulSetInfo[]>("list_statefulsets", { options });
+  return invoke<StatefulSetInfo[]>("list_statefulsets", { options, context });
 }
 
-export async function listJobs(options: ListOptions = {}): Promise<JobInfo[]> {
-  return invoke<JobInfo[]>("list_jobs", { options });
+export async function listJobs(
+  options: ListOptions = {},
+  context?: string
+): Promise<JobInfo[]> {
+  return invoke<JobInfo[]>("list_jobs", { options, context });
 }
 
 export async function listCronjobs(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<CronJobInfo[]> {
-  return invoke<CronJobInfo[]>("list_cronjobs", { options });
+  return invoke<CronJobInfo[]>("list_cronjobs", { options, context });
 }
 
 // Networking resources
 export async function listIngresses(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<IngressInfo[]> {
-  return invoke<IngressInfo[]>("list_ingresses", { options });
+  return invoke<IngressInfo[]>("list_ingresses", { options, context });
 }
 
 export async function listEndpointSlices(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<EndpointSliceInfo[]> {
-  return invoke<EndpointSliceInfo[]>("list_endpoint_slices", { options });
+  return invoke<EndpointSliceInfo[]>("list_endpoint_slices", { options, context });
 }
 
 export async function listNetworkPolicies(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<NetworkPolicyInfo[]> {
-  return invoke<NetworkPolicyInfo[]>("list_network_policies", { options });
+  return invoke<NetworkPolicyInfo[]>("list_network_policies", { options, context });
 }
 
 export async function listIngressClasses(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<IngressClassInfo[]> {
-  return invoke<IngressClassInfo[]>("list_ingress_classes", { options });
+  return invoke<IngressClassInfo[]>("list_ingress_classes", { options, context });
 }
 
 // Configuration resources
-export async function listHPAs(options: ListOptions = {}): Promise<HPAInfo[]> {
-  return invoke<HPAInfo[]>("list_hpas", { options });
+export async function listHPAs(
+  options: ListOptions = {},
+  context?: string
+): Promise<HPAInfo[]> {
+  return invoke<HPAInfo[]>("list_hpas", { options, context });
 }
 
 export async function listLimitRanges(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<LimitRangeInfo[]> {
-  return invoke<LimitRangeInfo[]>("list_limit_ranges", { options });
+  return invoke<LimitRangeInfo[]>("list_limit_ranges", { options, context });
 }
 
 export async function listResourceQuotas(
-  options: ListOptions = {}
+  options: ListOptions = {},
+  context?: string
 ): Promise<ResourceQuotaInfo[]> {
-  return invoke<ResourceQuotaInfo[]>("list_resource_quotas", { options });
+  return invoke<ResourceQuotaInfo[]>("list_resource_quotas", { options, context });
 }
 
-export async function listPDBs(options: ListOptions = {}): Promise<PDBInfo[]> {
-  return invoke<PDBInfo[]>("list_pdbs", { options });
+export async function listPDBs(
+  options: ListOptions = {},
+  context?: string
+): Promise<PDBInfo[]> {
+  return invoke<PDBInfo[]>("list_pdbs", { options, context });
 }
 
 // Storage resources
-export async function listPersistentVolumes(): Promise<PVInfo[]> {
-  return invoke<PVInfo[]>("list_persistent_volumes");
+export async function listPersistentVolumes(context?: string): Promise<PVInfo[]> {
+  return invoke<PVInfo[]>("list_persistent_volumes", { context });
 }
 
 export async function listPersistentVolumeClaims(
-  namespace?: string
+  namespace?: string,
+  context?: string
 ): Promise<PVCInfo[]> {
-  return invoke<PVCInfo[]>("list_persistent_volume_claims", { namespace });
+  return invoke<PVCInfo[]>("list_persistent_volume_claims", { namespace, context });
 }
 
-export async function listStorageClasses(): Promise<StorageClassInfo[]> {
-  return invoke<StorageClassInfo[]>("list_storage_classes");
+export async function listStorageClasses(context?: string): Promise<StorageClassInfo[]> {
+  return invoke<StorageClassInfo[]>("list_storage_classes", { context });
 }
 
-export async function listCSIDrivers(): Promise<CSIDriverInfo[]> {
-  return invoke<CSIDriverInfo[]>("list_csi_drivers");
+export async function listCSIDrivers(context?: string): Promise<CSIDriverInfo[]> {
+  return invoke<CSIDriverInfo[]>("list_csi_drivers", { context });
 }
 
-export async function listCSINodes(): Promise<CSINodeInfo[]> {
-  return invoke<CSINodeInfo[]>("list_csi_nodes");
+export async function listCSINodes(context?: string): Promise<CSINodeInfo[]> {
+  return invoke<CSINodeInfo[]>("list_csi_nodes", { context });
 }
 
-export async function listVolumeAttachments(): Promise<VolumeAttachmentInfo[]> {
-  return invoke<VolumeAttachmentInfo[]>("list_volume_attachments");
+export async function listVolumeAttachments(
+  context?: string
+): Promise<VolumeAttachmentInfo[]> {
+  return invoke<VolumeAttachmentInfo[]>("list_volume_attachments", { context });
 }
 
 // Access Control resources
 export async function listServiceAccounts(
-  namespace?: string
+  namespace?: string,
+  context?: string
 ): Promise<ServiceAccountInfo[]> {
-  return invoke<ServiceAccountInfo[]>("list_service_accounts", { namespace });
+  return invoke<ServiceAccountInfo[]>("list_service_accounts", { namespace, context });
 }
 
-export async function listRoles(namespace?: string): Promise<RoleInfo[]> {
-  return invoke<RoleInfo[]>("list_roles", { namespace });
+export async function listRoles(
+  namespace?: string,
+  context?: string
+): Promise<RoleInfo[]> {
+  return invoke<RoleInfo[]>("list_roles", { namespace, context });
 }
 
 export async function listRoleBindings(
-  namespace?: string
+  namespace?: string,
+  context?: string
 ): Promise<RoleBindingInfo[]> {
-  return invoke<RoleBindingInfo[]>("list_role_bindings", { namespace });
+  return invoke<RoleBindingInfo[]>("list_role_bindings", { namespace, context });
 }
 
-export async function listClusterRoles(): Promise<ClusterRoleInfo[]> {
-  return invoke<ClusterRoleInfo[]>("list_cluster_roles");
+export async function listClusterRoles(context?: string): Promise<ClusterRoleInfo[]> {
+  return invoke<ClusterRoleInfo[]>("list_cluster_roles", { context });
 }
 
-export async function listClusterRoleBindings(): Promise<ClusterRoleBindingInfo[]> {
-  return invoke<ClusterRoleBindingInfo[]>("list_cluster_role_bindings");
+export async function listClusterRoleBindings(
+  context?: string
+): Promise<ClusterRoleBindingInfo[]> {
+  return invoke<ClusterRoleBindingInfo[]>("list_cluster_role_bindings", { context });
 }
 
 // Administration resources
-export async function listCRDs(): Promise<CRDInfo[]> {
-  return invoke<CRDInfo[]>("list_crds");
+export async function listCRDs(context?: string): Promise<CRDInfo[]> {
+  return invoke<CRDInfo[]>("list_crds", { context });
 }
 
 export async function listCustomResources(
-  query: CustomResourceDefinitionRef & { namespace?: string }
+  query: CustomResourceDefinitionRef & { namespace?: string },
+  context?: string
 ): Promise<CustomResourceInfo[]> {
-  return invoke<CustomResourceInfo[]>("list_custom_resources", { query });
+  return invoke<CustomResourceInfo[]>("list_custom_resources", { query, context });
 }
 
-export async function listPriorityClasses(): Promise<PriorityClassInfo[]> {
-  return invoke<PriorityClassInfo[]>("list_priority_classes");
+export async function listPriorityClasses(
+  context?: string
+): Promise<PriorityClassInfo[]> {
+  return invoke<PriorityClassInfo[]>("list_priority_classes", { context });
 }
 
-export async function listRuntimeClasses(): Promise<RuntimeClassInfo[]> {
-  return invoke<RuntimeClassInfo[]>("list_runtime_classes");
+export async function listRuntimeClasses(context?: string): Promise<RuntimeClassInfo[]> {
+  return invoke<RuntimeClassInfo[]>("list_runtime_classes", { context });
 }
 
-export async function listMutatingWebhooks(): Promise<MutatingWebhookInfo[]> {
-  return invoke<MutatingWebhookInfo[]>("list_mutating_webhooks");
+export async function listMutatingWebhooks(
+  context?: string
+): Promise<MutatingWebhookInfo[]> {
+  return invoke<MutatingWebhookInfo[]>("list_mutating_webhooks", { context });
 }
 
-export async function listValidatingWebhooks(): Promise<ValidatingWebhookInfo[]> {
-  return invoke<ValidatingWebhookInfo[]>("list_validating_webhooks");
+export async function listValidatingWebhooks(
+  context?: string
+): Promise<ValidatingWebhookInfo[]> {
+  return invoke<ValidatingWebhookInfo[]>("list_validating_webhooks", { context });
 }
 
-export async function getPod(name: string, namespace: string): Promise<PodInfo> {
-  return invoke<PodInfo>("get_pod", { name, namespace });
+export async function getPod(
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<PodInfo> {
+  return invoke<PodInfo>("get_pod", { name, namespace, context });
 }
 
 export async function revealEnvVar(
   namespace: string,
   secretName: string,
   key: string,
+  context?: string
 ): Promise<string> {
-  return invoke<string>("reveal_env_var", { namespace, secretName, key });
+  return invoke<string>("reveal_env_var", { namespace, secretName, key, context });
 }
 
 // Resource YAML commands
@@ -268,29 +327,75 @@ export interface ResourceYaml {
 export async function getResourceYaml(
   resourceType: string,
   name: string,
-  namespace?: string
+  namespace?: string,
+  context?: string
 ): Promise<ResourceYaml> {
-  return invoke<ResourceYaml>("get_resource_yaml", { resourceType, name, namespace });
+  return invoke<ResourceYaml>("get_resource_yaml", {
+    resourceType,
+    name,
+    namespace,
+    context,
+  });
+}
+
+/** How an apply treats fields owned by other field managers */
+export type ConflictResolution = "abort" | "force" | "drop";
+
+export async function applyResourceYaml(
+  yamlContent: string,
+  conflictResolution?: ConflictResolution,
+  context?: string
+): Promise<string> {
+  return invoke<string>("apply_resource_yaml", {
+    yamlContent,
+    conflictResolution,
+    context,
+  });
+}
+
+/**
+ * Applies every object of a multi-document YAML, a directory or a kustomization,
+ * Namespaces and CRDs first. With `pruneSelector`, objects carrying that label
+ * selector which are not in the set are deleted afterwards.
+ */
+export async function applyManifests(
+  source: ManifestSource,
+  pruneSelector?: string,
+  conflictResolution?: ConflictResolution,
+  context?: string
+): Promise<AppliedObject[]> {
+  return invoke<AppliedObject[]>("apply_manifests", {
+    source,
+    pruneSelector,
+    conflictResolution,
+    context,
+  });
 }
 
-export async function applyResourceYaml(yamlContent: string): Promise<string> {
-  return invoke<string>("apply_resource_yaml", { yamlContent });
+/** Server-side dry run of an apply, showing what would change before it is applied */
+export async function dryRunResourceYaml(
+  yamlContent: string,
+  context?: string
+): Promise<ApplyPreview> {
+  return invoke<ApplyPreview>("dry_run_resource_yaml", { yamlContent, context });
 }
 
 export async function deleteResource(
   resourceType: string,
   name: string,
-  namespace?: string
+  namespace?: string,
+  context?: string
 ): Promise<void> {
-  return invoke("delete_resource", { resourceType, name, namespace });
+  return invoke("delete_resource", { resourceType, name, namespace, context });
 }
 
 export async function scaleDeployment(
   name: string,
   namespace: string,
-  replicas: number
+  replicas: number,
+  context?: string
 ): Promise<void> {
-  return invoke("scale_deployment", { name, namespace, replicas });
+  return invoke("scale_deployment", { name, namespace, replicas, context });
 }
 
 /** Workload kinds whose container images can be patched */
@@ -306,7 +411,8 @@ export async function setContainerImage(
   namespace: string,
   containerName: string,
   image: string,
-  initContainer: boolean
+  initContainer: boolean,
+  context?: string
 ): Promise<void> {
   return invoke("set_container_image", {
     resourceType,
@@ -315,22 +421,39 @@ export async function setContainerImage(
     containerName,
     image,
     initContainer,
+    context,
   });
 }
 
-export async function triggerCronjob(name: string, namespace: string): Promise<void> {
-  return invoke("trigger_cronjob", { name, namespace });
+export async function triggerCronjob(
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<void> {
+  return invoke("trigger_cronjob", { name, namespace, context });
 }
 
-export async function suspendCronjob(name: string, namespace: string): Promise<void> {
-  return invoke("suspend_cronjob", { name, namespace });
+export async function suspendCronjob(
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<void> {
+  return invoke("suspend_cronjob", { name, namespace, context });
 }
 
-export async function resumeCronjob(name: string, namespace: string): Promise<void> {
-  return invoke("resume_cronjob", { name, namespace });
+export async function resumeCronjob(
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<void> {
+  return invoke("resume_cronjob", { name, namespace, context });
 }
 
 /** Renders the manual Job for a CronJob as YAML for review before creating. */
-export async function getCronjobJobYaml(name: string, namespace: string): Promise<string> {
-  return invoke("get_cronjob_job_yaml", { name, namespace });
+export async function getCronjobJobYaml(
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<string> {
+  return invoke("get_cronjob_job_yaml", { name, namespace, context });
 }
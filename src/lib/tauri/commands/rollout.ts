@@ -0,0 +1,62 @@
+import type { RolloutRevision, RolloutTarget } from "../../types";
+
+import { invoke } from "./core";
+
+// Rollout commands
+export async function rolloutRestart(
+  target: RolloutTarget,
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<void> {
+  return invoke("rollout_restart", { target, name, namespace, context });
+}
+
+export async function rolloutPause(
+  target: RolloutTarget,
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<void> {
+  return invoke("rollout_pause", { target, name, namespace, context });
+}
+
+export async function rolloutResume(
+  target: RolloutTarget,
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<void> {
+  return invoke("rollout_resume", { target, name, namespace, context });
+}
+
+export async function rolloutHistory(
+  target: RolloutTarget,
+  name: string,
+  namespace: string,
+  context?: string
+): Promise<RolloutRevision[]> {
+  return invoke<RolloutRevision[]>("rollout_history", { target, name, namespace, context });
+}
+
+/** Rolls back to `toRevision` (the previous one when omitted); resolves to the revision restored */
+export async function rolloutUndo(
+  target: RolloutTarget,
+  name: string,
+  namespace: string,
+  toRevision?: number,
+  context?: string
+): Promise<number> {
+  return invoke<number>("rollout_undo", { target, name, namespace, toRevision, context });
+}
+
+/** Status arrives on `rollout-status-{watchId}` until the rollout settles or `stopWatch(watchId)` */
+export async function watchRolloutStatus(
+  target: RolloutTarget,
+  name: string,
+  namespace: string,
+  watchId: string,
+  context?: string
+): Promise<void> {
+  return invoke("watch_rollout_status", { target, name, namespace, watchId, context });
+}
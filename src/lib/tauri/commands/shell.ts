@@ -1,4 +1,8 @@
-import type { ShellOptions, NodeShellOptions } from "../../types";
+import type {
+  ShellOptions,
+  NodeShellOptions,
+  DebugContainerOptions,
+} from "../../types";
 
 import { invoke } from "./core";
 
@@ -40,3 +44,11 @@ export async function nodeShellStart(
 export async function nodeShellCleanup(sessionId: string): Promise<void> {
   return invoke("node_shell_cleanup", { sessionId });
 }
+
+// Ephemeral debug container commands
+export async function debugContainerStart(
+  sessionId: string,
+  options: DebugContainerOptions
+): Promise<void> {
+  return invoke("debug_container_start", { sessionId, options });
+}
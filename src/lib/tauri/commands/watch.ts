@@ -1,14 +1,18 @@
+import type { CacheStatus, ResourceWatchQuery } from "../../types";
+
 import { invoke } from "./core";
 
 // Watch commands
 export async function watchPods(
   watchId: string,
   namespace?: string,
-  labelSelector?: string
+  labelSelector?: string,
+  context?: string
 ): Promise<void> {
   return invoke("watch_pods", {
     watchId,
     namespace,
+    context,
     ...(labelSelector ? { labelSelector } : {}),
   });
 }
@@ -16,11 +20,13 @@ export async function watchPods(
 export async function watchDeployments(
   watchId: string,
   namespace?: string,
-  labelSelector?: string
+  labelSelector?: string,
+  context?: string
 ): Promise<void> {
   return invoke("watch_deployments", {
     watchId,
     namespace,
+    context,
     ...(labelSelector ? { labelSelector } : {}),
   });
 }
@@ -28,11 +34,13 @@ export async function watchDeployments(
 export async function watchServices(
   watchId: string,
   namespace?: string,
-  labelSelector?: string
+  labelSelector?: string,
+  context?: string
 ): Promise<void> {
   return invoke("watch_services", {
     watchId,
     namespace,
+    context,
     ...(labelSelector ? { labelSelector } : {}),
   });
 }
@@ -40,11 +48,13 @@ export async function watchServices(
 export async function watchStatefulsets(
   watchId: string,
   namespace?: string,
-  labelSelector?: string
+  labelSelector?: string,
+  context?: string
 ): Promise<void> {
   return invoke("watch_statefulsets", {
     watchId,
     namespace,
+    context,
     ...(labelSelector ? { labelSelector } : {}),
   });
 }
@@ -52,11 +62,13 @@ export async function watchStatefulsets(
 export async function watchDaemonsets(
   watchId: string,
   namespace?: string,
-  labelSelector?: string
+  labelSelector?: string,
+  context?: string
 ): Promise<void> {
   return invoke("watch_daemonsets", {
     watchId,
     namespace,
+    context,
     ...(labelSelector ? { labelSelector } : {}),
   });
 }
@@ -64,19 +76,38 @@ export async function watchDaemonsets(
 export async function watchReplicasets(
   watchId: string,
   namespace?: string,
-  labelSelector?: string
+  labelSelector?: string,
+  context?: string
 ): Promise<void> {
   return invoke("watch_replicasets", {
     watchId,
     namespace,
+    context,
     ...(labelSelector ? { labelSelector } : {}),
   });
 }
 
-export async function watchNamespaces(watchId: string): Promise<void> {
-  return invoke("watch_namespaces", { watchId });
+export async function watchNamespaces(watchId: string, context?: string): Promise<void> {
+  return invoke("watch_namespaces", { watchId, context });
+}
+
+/**
+ * Watches any resource type, CRDs included. Events carry the same summaries as
+ * `listCustomResources` and arrive on `resources-watch-{watchId}`.
+ */
+export async function watchResources(
+  watchId: string,
+  query: ResourceWatchQuery,
+  context?: string
+): Promise<void> {
+  return invoke("watch_resources", { watchId, query, context });
 }
 
 export async function stopWatch(watchId: string): Promise<void> {
   return invoke("stop_watch", { watchId });
 }
+
+/** Informers of a cluster and how many lists they answered; the active cluster by default */
+export async function getCacheStatus(context?: string): Promise<CacheStatus> {
+  return invoke<CacheStatus>("get_cache_status", { context });
+}
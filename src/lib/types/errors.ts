@@ -10,6 +10,13 @@ export type ErrorKind =
   | "Timeout"
   | "Unknown";
 
+/** A field owned by another field manager, from a rejected server-side apply */
+export interface FieldConflict {
+  field: string;
+  manager: string;
+  message: string;
+}
+
 /** Structured error type matching the Rust KubeliError struct */
 export interface KubeliError {
   kind: ErrorKind;
@@ -19,6 +26,7 @@ export interface KubeliError {
   resource?: string;
   suggestions: string[];
   retryable: boolean;
+  conflicts?: FieldConflict[];
 }
 
 /** Type guard to check if a value is a KubeliError */
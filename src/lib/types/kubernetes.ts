@@ -1,4 +1,4 @@
-import type { KubeliError } from "./errors";
+import type { FieldConflict, KubeliError } from "./errors";
 
 export interface Cluster {
   id: string;
@@ -119,17 +119,37 @@ export interface LogStream {
 }
 
 // Log streaming types
+export type LogSeverity = "trace" | "debug" | "info" | "warn" | "error" | "fatal";
+
 export interface LogEntry {
   timestamp: string | null;
   message: string;
   container: string;
   pod: string;
   namespace: string;
+  /** Normalized severity from a structured level field or a leading level word */
+  level?: LogSeverity | null;
+  /** Message field of a JSON or logfmt line */
+  msg?: string | null;
+  /** Top-level fields of a JSON or logfmt line; empty for plain text */
+  fields?: Record<string, unknown>;
   /** Monotonic ingest ID, stamped frontend-side (not part of the Rust payload).
    * Stable React key — timestamp+index shifts when the ring buffer trims. */
   seq?: number;
 }
 
+/** Server-side filter applied to streamed and fetched log lines */
+export interface LogFilterOptions {
+  /** Keep only lines matching this regex */
+  include?: string;
+  /** Drop lines matching this regex */
+  exclude?: string;
+  /** Drop lines below this level; lines without a recognizable level are kept */
+  min_level?: string;
+  /** Field predicates such as `level=error` or `http.status>=500`, all of which must hold */
+  fields?: string[];
+}
+
 export interface LogOptions {
   namespace: string;
   pod_name: string;
@@ -139,6 +159,78 @@ export interface LogOptions {
   since_seconds?: number;
   timestamps?: boolean;
   previous?: boolean;
+  /** Cluster context to read from; the active cluster when omitted */
+  context?: string;
+  /** Lines not matching the filter are dropped before reaching the UI */
+  filter?: LogFilterOptions;
+  /** Also write streamed lines to the local log archive */
+  record?: boolean;
+}
+
+/** Workload whose pods an aggregated log stream follows */
+export interface LogWorkloadRef {
+  /** Deployment, StatefulSet, DaemonSet, ReplicaSet or Job */
+  kind: string;
+  name: string;
+}
+
+/** Options of an aggregated log stream; either `label_selector` or `workload` must be set */
+export interface AggregatedLogOptions {
+  namespace: string;
+  label_selector?: string;
+  workload?: LogWorkloadRef;
+  /** Only follow containers with this name; every container when omitted */
+  container?: string;
+  tail_lines?: number;
+  since_seconds?: number;
+  timestamps?: boolean;
+  /** Cluster context to read from; the active cluster when omitted */
+  context?: string;
+  /** Lines not matching the filter are dropped before reaching the UI */
+  filter?: LogFilterOptions;
+  /** Also write streamed lines to the local log archive */
+  record?: boolean;
+}
+
+/** One archived line, as returned by searches */
+export interface ArchivedLogLine extends LogEntry {
+  id: number;
+  context: string;
+}
+
+/** Search over the log archive; every set field narrows the result */
+export interface LogArchiveQuery {
+  context?: string;
+  namespace?: string;
+  pod?: string;
+  container?: string;
+  /** Words or phrase to find in the message (full-text, case-insensitive) */
+  text?: string;
+  /** RFC3339 lower bound, inclusive */
+  since?: string;
+  /** RFC3339 upper bound, exclusive */
+  until?: string;
+  /** Maximum lines returned (default 1000, at most 10000) */
+  limit?: number;
+  /** Newest lines first instead of chronological order */
+  newest_first?: boolean;
+}
+
+/** A pod/container with archived lines */
+export interface ArchivedLogSource {
+  context: string;
+  namespace: string;
+  pod: string;
+  container: string;
+  line_count: number;
+  first_timestamp: string;
+  last_timestamp: string;
+}
+
+export interface LogArchiveStats {
+  line_count: number;
+  size_bytes: number;
+  max_bytes: number;
 }
 
 export type LogEventType = "Line" | "Lines" | "Error" | "Ended" | "Started" | "Stopped";
@@ -164,13 +256,54 @@ export interface ShellOptions {
   pod_name: string;
   container?: string;
   command?: string[];
+  /** Cluster context to exec in; the active cluster when omitted */
+  context?: string;
 }
 
 export interface NodeShellOptions {
   node_name: string;
   image?: string;
+  /** Cluster context of the node; the active cluster when omitted */
+  context?: string;
 }
 
+export interface DebugContainerOptions {
+  namespace: string;
+  pod_name: string;
+  image?: string;
+  target_container?: string;
+  command?: string[];
+  /** Cluster context of the pod; the active cluster when omitted */
+  context?: string;
+}
+
+export interface FileCopyOptions {
+  namespace: string;
+  pod_name: string;
+  container?: string;
+  local_path: string;
+  remote_path: string;
+  /** Cluster context of the pod; the active cluster when omitted */
+  context?: string;
+}
+
+export type FileCopyEvent =
+  | { type: "Started"; data: { total_bytes: number | null } }
+  | {
+      type: "Progress";
+      data: {
+        transferred_bytes: number;
+        files: number;
+        total_bytes: number | null;
+      };
+    }
+  | {
+      type: "Finished";
+      data: { transferred_bytes: number; files: number; destination: string };
+    }
+  | { type: "Cancelled" }
+  | { type: "Failed"; data: KubeliError };
+
 export type ShellEventType = "Output" | "Error" | "Started" | "Closed";
 
 export type ShellEvent =
@@ -194,12 +327,39 @@ export type PortForwardTargetType = "pod" | "service";
 
 export type PortForwardStatus = "connecting" | "connected" | "reconnecting" | "disconnected" | "error";
 
+/** A port requested for a forward */
+export interface PortForwardPortSpec {
+  /** Pod port, or service port for services: a number or a port name */
+  port: number | string;
+  /** Random free port when unset */
+  local_port?: number;
+}
+
+/** One port of a running forward */
+export interface ForwardedPort {
+  /** Port as requested (e.g. the service port name "grpc") */
+  requested: number | string;
+  local_port: number;
+  /** Resolved container port on the pod */
+  target_port: number;
+}
+
 export interface PortForwardOptions {
   namespace: string;
   name: string;
   target_type: PortForwardTargetType;
-  target_port: number;
+  /** Single-port shorthand, used when `ports` is empty */
+  target_port?: number;
   local_port?: number;
+  /** Ports to forward, named or numbered; one listener each */
+  ports?: PortForwardPortSpec[];
+  /** Local address to listen on; loopback when unset */
+  bind_address?: string;
+  /**
+   * Allow a non-loopback `bind_address`. It exposes the forwarded port to the
+   * network, so the backend rejects such addresses without it.
+   */
+  allow_external_bind?: boolean;
   /**
    * Cluster this forward is meant for. Set it when the cluster was decided
    * before the call and may have changed since (restarting after an OIDC
@@ -216,8 +376,13 @@ export interface PortForwardInfo {
   namespace: string;
   name: string;
   target_type: PortForwardTargetType;
+  /** Primary (first) port */
   target_port: number;
   local_port: number;
+  /** Every forwarded port; set by the backend, absent on local placeholders */
+  ports?: ForwardedPort[];
+  /** Address the local listeners are bound to; set by the backend */
+  bind_address?: string;
   status: PortForwardStatus;
   pod_name?: string;
   pod_uid?: string;
@@ -230,7 +395,7 @@ export interface PortForwardInfo {
 export type PortForwardEventType = "Started" | "Connected" | "Reconnecting" | "Reconnected" | "PodDied" | "Disconnected" | "Error" | "Stopped";
 
 export type PortForwardEvent =
-  | { type: "Started"; data: { forward_id: string; local_port: number } }
+  | { type: "Started"; data: { forward_id: string; local_port: number; ports: ForwardedPort[] } }
   | { type: "Connected"; data: { forward_id: string } }
   | { type: "Reconnecting"; data: { forward_id: string; reason: string } }
   | { type: "Reconnected"; data: { forward_id: string; new_pod: string } }
@@ -239,6 +404,26 @@ export type PortForwardEvent =
   | { type: "Error"; data: { forward_id: string; message: string } }
   | { type: "Stopped"; data: { forward_id: string } };
 
+/** Named port-forward profile, persisted by the backend */
+export interface PortForwardProfile {
+  name: string;
+  context: string;
+  namespace: string;
+  /** Pod or service name */
+  target: string;
+  target_type: PortForwardTargetType;
+  /** Forwarded together as one multi-port forward */
+  ports: PortForwardPortSpec[];
+  /** Local listen address; loopback when unset */
+  bind_address?: string;
+  /** Allow a non-loopback `bind_address` */
+  allow_external_bind?: boolean;
+  /** Start when the profile's cluster connects */
+  auto_start?: boolean;
+  /** Profiles sharing a group are started together */
+  group?: string;
+}
+
 export type PortForwardHistoryStatus =
   | "active"
   | "inactive"
@@ -416,6 +601,44 @@ export interface NodeInfo {
 // Watch event types
 export type WatchEventType = "Added" | "Modified" | "Deleted" | "Restarted" | "Error";
 
+/** Cache state of one shared informer */
+export interface CacheEntryInfo {
+  context: string;
+  api_version: string;
+  kind: string;
+  namespace: string | null;
+  label_selector: string | null;
+  subscribers: number;
+  object_count: number;
+  synced: boolean;
+  /** True while the informer relists or its watch is failing; lists then go to the API server */
+  stale: boolean;
+  last_error: string | null;
+  last_event_at: string | null;
+  hits: number;
+}
+
+/** Informer cache statistics of one cluster */
+export interface CacheStatus {
+  entries: CacheEntryInfo[];
+  /** List calls answered from memory */
+  hits: number;
+  /** List calls that had to go to the API server */
+  misses: number;
+}
+
+/** Any resource type to watch, identified by group/version/kind */
+export interface ResourceWatchQuery {
+  /** API group; empty for the core group */
+  group?: string;
+  version: string;
+  kind: string;
+  /** Ignored for cluster-scoped kinds; all namespaces when omitted */
+  namespace?: string;
+  label_selector?: string;
+  field_selector?: string;
+}
+
 export interface WatchEvent<T> {
   type: WatchEventType;
   data: T | T[] | string;
@@ -509,12 +732,28 @@ export interface ClusterMemorySummary {
   percentage: number;
 }
 
-// Graph visualization types (simplified: only core workload hierarchy)
-export type GraphNodeType = "namespace" | "deployment" | "pod";
+// Graph visualization types
+export type GraphNodeType =
+  | "namespace"
+  | "deployment"
+  | "statefulset"
+  | "daemonset"
+  | "replicaset"
+  | "job"
+  | "cronjob"
+  | "pod"
+  | "service"
+  | "ingress"
+  | "endpointslice"
+  | "configmap"
+  | "secret"
+  | "persistentvolumeclaim"
+  | "persistentvolume"
+  | "serviceaccount";
 
 export type GraphNodeStatus = "healthy" | "warning" | "error" | "unknown";
 
-export type GraphEdgeType = "owns" | "contains";
+export type GraphEdgeType = "owns" | "contains" | "routes" | "mounts" | "binds" | "uses";
 
 export interface GraphNode {
   id: string;
@@ -546,6 +785,18 @@ export interface GraphData {
   errors: string[];
 }
 
+// Batched on `graph-watch-{watchId}` by watch_resource_graph
+export type GraphEvent =
+  | { type: "NodeAdded"; data: GraphNode }
+  | { type: "NodeUpdated"; data: GraphNode }
+  | { type: "NodeRemoved"; data: string }
+  | { type: "EdgeAdded"; data: GraphEdge }
+  | { type: "EdgeUpdated"; data: GraphEdge }
+  | { type: "EdgeRemoved"; data: string }
+  | { type: "Error"; data: string };
+
+export type GraphExportFormat = "dot" | "mermaid" | "json";
+
 // Namespace info
 export interface NamespaceInfo {
   name: string;
@@ -1243,6 +1494,237 @@ export interface HelmReleaseDetail {
   managed_by: HelmManagedBy;
 }
 
+// Apply types
+export type ChangeKind = "added" | "removed" | "changed";
+
+export interface FieldChange {
+  /** e.g. `spec.template.spec.containers[name=web].image` */
+  path: string;
+  change: ChangeKind;
+  live: unknown;
+  result: unknown;
+  /** Set by the submitted YAML rather than defaulting or webhooks */
+  from_manifest: boolean;
+}
+
+/** Server dry-run of applying one manifest */
+export interface ApplyPreview {
+  kind: string;
+  name: string;
+  namespace: string | null;
+  /** False when the apply would create the object */
+  exists: boolean;
+  live: string | null;
+  submitted: string;
+  result: string;
+  changes: FieldChange[];
+  /** Fields other managers own; a plain apply would be rejected */
+  conflicts: FieldConflict[];
+}
+
+export type ApplyOutcome =
+  | "created"
+  | "configured"
+  | "unchanged"
+  | "pruned"
+  | "failed";
+
+// Rollout types
+export type RolloutTarget = "deployment" | "statefulset" | "daemonset";
+
+export interface RolloutRevision {
+  revision: number;
+  /** ReplicaSet (Deployment) or ControllerRevision holding the revision */
+  name: string;
+  created_at: string | null;
+  change_cause: string | null;
+  images: string[];
+  /** The revision the workload runs now */
+  current: boolean;
+  /** Pod template of the revision as YAML */
+  template: string;
+  /** Pod template changes from the previous revision */
+  changes: FieldChange[];
+}
+
+export type RolloutPhase = "progressing" | "complete" | "failed";
+
+export interface RolloutStatus {
+  phase: RolloutPhase;
+  message: string;
+}
+
+/** Rollout status events, emitted on `rollout-status-{watch_id}` */
+export type RolloutStatusEvent =
+  | { type: "Status"; data: RolloutStatus }
+  | { type: "Error"; data: KubeliError };
+
+// Node drain types
+export interface DrainOptions {
+  /** Overrides each pod's terminationGracePeriodSeconds */
+  grace_period_seconds?: number;
+  /** Give up on pods still running after this long; 300s when unset */
+  timeout_seconds?: number;
+  /** Also evict pods no controller will recreate. They are gone for good. */
+  evict_unmanaged?: boolean;
+}
+
+export interface DrainSummary {
+  evicted: number;
+  skipped: number;
+  failed: number;
+  /** Pods still on the node when the timeout hit */
+  timed_out: number;
+}
+
+/** Drain progress, emitted on `drain-{drain_id}` */
+export type DrainEvent =
+  | { type: "Cordoned"; data: { node: string } }
+  | { type: "Skipped"; data: { namespace: string; pod: string; reason: string } }
+  | { type: "Evicting"; data: { namespace: string; pod: string } }
+  /** A PodDisruptionBudget refused the eviction; it is retried */
+  | { type: "Blocked"; data: { namespace: string; pod: string; reason: string } }
+  | { type: "Evicted"; data: { namespace: string; pod: string } }
+  | { type: "Failed"; data: { namespace: string; pod: string; error: KubeliError } }
+  | { type: "TimedOut"; data: { namespace: string; pod: string } }
+  | { type: "Finished"; data: DrainSummary };
+
+// Bulk operation types
+export interface ResourceRef {
+  name: string;
+  namespace?: string | null;
+}
+
+/** Objects of one resource type a bulk operation acts on */
+export type BulkTarget =
+  | { type: "refs"; refs: ResourceRef[] }
+  /** A non-empty label selector, in one namespace or all of them */
+  | { type: "selector"; namespace: string | null; label_selector: string };
+
+export type DeletePropagation = "Foreground" | "Background" | "Orphan";
+
+export interface BulkDeleteOptions {
+  /** Overrides each object's grace period; 0 deletes immediately */
+  grace_period_seconds?: number;
+  propagation_policy?: DeletePropagation;
+  /** Validate every delete server-side without deleting */
+  dry_run?: boolean;
+}
+
+/** Workload kinds `bulkScaleResources` can scale */
+export type ScaleTarget = "deployment" | "statefulset" | "replicaset";
+
+export interface BulkItemResult {
+  name: string;
+  namespace: string | null;
+  success: boolean;
+  error: KubeliError | null;
+}
+
+/** Progress of a bulk operation, emitted on `bulk-{operation_id}` */
+export type BulkEvent =
+  | { type: "Started"; data: { total: number; dry_run: boolean } }
+  | { type: "Item"; data: { result: BulkItemResult; completed: number; total: number } }
+  | { type: "Finished"; data: { succeeded: number; failed: number } };
+
+/** Where `applyManifests` reads manifests from */
+export type ManifestSource =
+  /** YAML text, possibly several `---`-separated documents */
+  | { type: "yaml"; content: string }
+  /** A manifest file, a directory of manifests, or a kustomization directory */
+  | { type: "path"; path: string; recursive?: boolean };
+
+export interface AppliedObject {
+  kind: string;
+  name: string;
+  namespace: string | null;
+  outcome: ApplyOutcome;
+  error: KubeliError | null;
+}
+
+export interface HelmResourceDiff {
+  kind: string;
+  name: string;
+  namespace: string | null;
+  change: ChangeKind;
+  from: string | null;
+  to: string | null;
+  changes: FieldChange[];
+}
+
+export interface HelmRevisionDiff {
+  from: HelmReleaseHistoryEntry;
+  to: HelmReleaseHistoryEntry;
+  resources: HelmResourceDiff[];
+  values: FieldChange[];
+}
+
+export type HelmResourceOutcome = "deleted" | "kept" | "missing" | "failed";
+
+export interface HelmUninstalledResource {
+  kind: string;
+  name: string;
+  namespace: string | null;
+  outcome: HelmResourceOutcome;
+  error: KubeliError | null;
+}
+
+export interface HelmUninstallOptions {
+  /** Mark the release uninstalled instead of deleting its history */
+  keep_history?: boolean;
+  /** Progress arrives on `helm-uninstall-{operation_id}` when set */
+  operation_id?: string;
+}
+
+export type HelmUninstallEvent =
+  | { type: "Started"; data: { total: number } }
+  | { type: "Resource"; data: HelmUninstalledResource }
+  | { type: "Finished"; data: { deleted: number; kept: number; failed: number } };
+
+export interface HelmUninstallResult {
+  resources: HelmUninstalledResource[];
+  history_kept: boolean;
+}
+
+export interface HelmRollbackResult {
+  revision: number;
+  status: HelmReleaseStatus;
+  objects: AppliedObject[];
+}
+
+export interface HelmInstallOptions {
+  /** Values merged over the chart's defaults */
+  values?: Record<string, unknown>;
+  /** Create the release namespace when missing */
+  create_namespace?: boolean;
+  /** Reuse the name of an uninstalled release that kept its history */
+  replace?: boolean;
+}
+
+export interface HelmUpgradeOptions {
+  /** Values merged over the chart's defaults */
+  values?: Record<string, unknown>;
+  /** Merge `values` over the current revision's values */
+  reuse_values?: boolean;
+}
+
+export interface HelmSkippedHook {
+  name: string;
+  kind: string;
+  /** The operation's hook events it is registered for, e.g. `pre-install` */
+  events: string[];
+}
+
+export interface HelmInstallResult {
+  revision: number;
+  status: HelmReleaseStatus;
+  /** Chart CRDs first on install, then the manifest objects; an upgrade also lists deleted objects */
+  objects: AppliedObject[];
+  notes: string | null;
+  /** Chart hooks for this operation that were not run */
+  skipped_hooks: HelmSkippedHook[];
+}
+
 // Flux Kustomization types
 export type FluxKustomizationStatus =
   | "ready"